@@ -1 +1,5 @@
 pub mod nist_strd;
+
+mod types;
+pub use self::types::UnsupervisedDataset;
+pub use self::types::SupervisedDataset;
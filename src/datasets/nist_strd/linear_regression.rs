@@ -1,41 +1,527 @@
-use rulinalg::vector::Vector;
+use datasets::SupervisedDataset;
+use super::CertifiedValues;
+use super::dataset;
 
 // https://www.itl.nist.gov/div898/strd/lls/data/Norris.shtml
-pub fn norris() -> Vec<(Vector<f64>, f64)> {
-    vec!((vector!(  0.2),   0.1),
-         (vector!(337.4), 338.8),
-         (vector!(118.2), 118.1),
-         (vector!(884.6), 888.0),
-         (vector!( 10.1),   9.2),
-         (vector!(226.5), 228.1),
-         (vector!(666.3), 668.5),
-         (vector!(996.3), 998.5),
-         (vector!(448.6), 449.1),
-         (vector!(777.0), 778.9),
-         (vector!(558.2), 559.2),
-         (vector!(  0.4),   0.3),
-         (vector!(  0.6),   0.1),
-         (vector!(775.5), 778.1),
-         (vector!(666.9), 668.8),
-         (vector!(338.0), 339.3),
-         (vector!(447.5), 448.9),
-         (vector!( 11.6),  10.8),
-         (vector!(556.0), 557.7),
-         (vector!(228.1), 228.3),
-         (vector!(995.8), 998.0),
-         (vector!(887.6), 888.8),
-         (vector!(120.2), 119.6),
-         (vector!(  0.3),   0.3),
-         (vector!(  0.3),   0.6),
-         (vector!(556.8), 557.6),
-         (vector!(339.1), 339.3),
-         (vector!(887.2), 888.0),
-         (vector!(999.0), 998.5),
-         (vector!(779.0), 778.9),
-         (vector!( 11.1),  10.2),
-         (vector!(118.3), 117.6),
-         (vector!(229.2), 228.9),
-         (vector!(669.1), 668.4),
-         (vector!(448.9), 449.2),
-         (vector!(  0.5),  0.2))
+pub fn norris() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(  0.2),   0.1),
+                 (vector!(337.4), 338.8),
+                 (vector!(118.2), 118.1),
+                 (vector!(884.6), 888.0),
+                 (vector!( 10.1),   9.2),
+                 (vector!(226.5), 228.1),
+                 (vector!(666.3), 668.5),
+                 (vector!(996.3), 998.5),
+                 (vector!(448.6), 449.1),
+                 (vector!(777.0), 778.9),
+                 (vector!(558.2), 559.2),
+                 (vector!(  0.4),   0.3),
+                 (vector!(  0.6),   0.1),
+                 (vector!(775.5), 778.1),
+                 (vector!(666.9), 668.8),
+                 (vector!(338.0), 339.3),
+                 (vector!(447.5), 448.9),
+                 (vector!( 11.6),  10.8),
+                 (vector!(556.0), 557.7),
+                 (vector!(228.1), 228.3),
+                 (vector!(995.8), 998.0),
+                 (vector!(887.6), 888.8),
+                 (vector!(120.2), 119.6),
+                 (vector!(  0.3),   0.3),
+                 (vector!(  0.3),   0.6),
+                 (vector!(556.8), 557.6),
+                 (vector!(339.1), 339.3),
+                 (vector!(887.2), 888.0),
+                 (vector!(999.0), 998.5),
+                 (vector!(779.0), 778.9),
+                 (vector!( 11.1),  10.2),
+                 (vector!(118.3), 117.6),
+                 (vector!(229.2), 228.9),
+                 (vector!(669.1), 668.4),
+                 (vector!(448.9), 449.2),
+                 (vector!(  0.5),  0.2)))
+}
+
+pub fn norris_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(-0.262323073774029,
+                         1.00211681802045),
+        standard_deviations: vec!(0.232818234301152,
+                                  0.429796848199937e-3),
+        residual_standard_deviation: 0.884796396144373,
+        r_squared: Some(0.999993745883712)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Pontius.shtml
+pub fn pontius() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 150000.0), 0.11019),
+                 (vector!( 300000.0), 0.21956),
+                 (vector!( 450000.0), 0.32949),
+                 (vector!( 600000.0), 0.43899),
+                 (vector!( 750000.0), 0.54803),
+                 (vector!( 900000.0), 0.65694),
+                 (vector!(1050000.0), 0.76562),
+                 (vector!(1200000.0), 0.87487),
+                 (vector!(1350000.0), 0.98292),
+                 (vector!(1500000.0), 1.09146),
+                 (vector!(1650000.0), 1.20001),
+                 (vector!(1800000.0), 1.30822),
+                 (vector!(1950000.0), 1.41599),
+                 (vector!(2100000.0), 1.52399),
+                 (vector!(2250000.0), 1.63194),
+                 (vector!(2400000.0), 1.73947),
+                 (vector!(2550000.0), 1.84646),
+                 (vector!(2700000.0), 1.95392),
+                 (vector!(2850000.0), 2.06128),
+                 (vector!(3000000.0), 2.16844),
+                 (vector!( 150000.0), 0.11052),
+                 (vector!( 300000.0), 0.22018),
+                 (vector!( 450000.0), 0.32939),
+                 (vector!( 600000.0), 0.43886),
+                 (vector!( 750000.0), 0.54798),
+                 (vector!( 900000.0), 0.65739),
+                 (vector!(1050000.0), 0.76596),
+                 (vector!(1200000.0), 0.87474),
+                 (vector!(1350000.0), 0.98300),
+                 (vector!(1500000.0), 1.09150),
+                 (vector!(1650000.0), 1.20004),
+                 (vector!(1800000.0), 1.30818),
+                 (vector!(1950000.0), 1.41613),
+                 (vector!(2100000.0), 1.52408),
+                 (vector!(2250000.0), 1.63159),
+                 (vector!(2400000.0), 1.73965),
+                 (vector!(2550000.0), 1.84696),
+                 (vector!(2700000.0), 1.95445),
+                 (vector!(2850000.0), 2.06177),
+                 (vector!(3000000.0), 2.16829)))
+}
+
+pub fn pontius_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(0.673565789473684e-3,
+                         0.732059160401003e-6,
+                         -0.316081871345029e-14),
+        standard_deviations: vec!(0.107938612033077e-3,
+                                  0.157817399981659e-9,
+                                  0.486652849992036e-16),
+        residual_standard_deviation: 0.205177424076185e-3,
+        r_squared: Some(0.999999900178537)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/NoInt1.shtml
+pub fn no_int1() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(60.0), 130.0),
+                 (vector!(61.0), 131.0),
+                 (vector!(62.0), 132.0),
+                 (vector!(63.0), 133.0),
+                 (vector!(64.0), 134.0),
+                 (vector!(65.0), 135.0),
+                 (vector!(66.0), 136.0),
+                 (vector!(67.0), 137.0),
+                 (vector!(68.0), 138.0),
+                 (vector!(69.0), 139.0),
+                 (vector!(70.0), 140.0)))
+}
+
+pub fn no_int1_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(2.07438016528926),
+        standard_deviations: vec!(0.165289256198347e-1),
+        residual_standard_deviation: 3.56753034006338,
+        r_squared: Some(0.999365492298663)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/NoInt2.shtml
+pub fn no_int2() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(4.0), 3.0),
+                 (vector!(5.0), 4.0),
+                 (vector!(6.0), 4.0)))
+}
+
+pub fn no_int2_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(0.727272727272727),
+        standard_deviations: vec!(0.420827318078432e-1),
+        residual_standard_deviation: 0.369274472937998,
+        r_squared: Some(0.993348115299335)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Filip.shtml
+pub fn filip() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(-6.860120914), 0.8116),
+                 (vector!(-4.324130045), 0.9072),
+                 (vector!(-4.358625055), 0.9052),
+                 (vector!(-4.358426747), 0.9039),
+                 (vector!(-6.955852379), 0.8053),
+                 (vector!(-6.661145254), 0.8377),
+                 (vector!(-6.355462942), 0.8667),
+                 (vector!(-6.118102026), 0.8809),
+                 (vector!(-7.115148017), 0.7975),
+                 (vector!(-6.815308569), 0.8162),
+                 (vector!(-6.519993057), 0.8515),
+                 (vector!(-6.204119983), 0.8766),
+                 (vector!(-5.853871964), 0.8885),
+                 (vector!(-6.109523091), 0.8859),
+                 (vector!( -5.79832982), 0.8959),
+                 (vector!(-5.482672118), 0.8913),
+                 (vector!(-5.171791386), 0.8959),
+                 (vector!(-4.851705903), 0.8971),
+                 (vector!(-4.517126416), 0.9021),
+                 (vector!(-4.143573228),  0.909),
+                 (vector!(-3.709075441), 0.9139),
+                 (vector!(-3.499489089), 0.9199),
+                 (vector!(-6.300769497), 0.8692),
+                 (vector!(-5.953504836), 0.8872),
+                 (vector!(-5.642065153),   0.89),
+                 (vector!(-5.031376979),  0.891),
+                 (vector!(-4.680685696), 0.8977),
+                 (vector!(-4.329846955), 0.9035),
+                 (vector!(-3.928486195), 0.9078),
+                 (vector!( -8.56735134), 0.7675),
+                 (vector!(-8.363211311), 0.7705),
+                 (vector!(-8.107682739), 0.7713),
+                 (vector!(-7.823908741), 0.7736),
+                 (vector!(-7.522878745), 0.7775),
+                 (vector!(-7.218819279), 0.7841),
+                 (vector!(-6.920818754), 0.7971),
+                 (vector!(-6.628932138), 0.8329),
+                 (vector!(-6.323946875), 0.8641),
+                 (vector!(-5.991399828), 0.8804),
+                 (vector!(-8.781464495), 0.7668),
+                 (vector!(-8.663140179), 0.7633),
+                 (vector!(-8.473531488), 0.7678),
+                 (vector!(-8.247337057), 0.7697),
+                 (vector!(-7.971428747),   0.77),
+                 (vector!(-7.676129393), 0.7749),
+                 (vector!(-7.352812702), 0.7796),
+                 (vector!(-7.072065318), 0.7897),
+                 (vector!(-6.774174009), 0.8131),
+                 (vector!(-6.478861916), 0.8498),
+                 (vector!(-6.159517513), 0.8741),
+                 (vector!(-6.835647144), 0.8061),
+                 (vector!( -6.53165267),  0.846),
+                 (vector!(-6.224098421), 0.8751),
+                 (vector!(-5.910094889), 0.8856),
+                 (vector!(-5.598599459), 0.8919),
+                 (vector!(-5.290645224), 0.8934),
+                 (vector!(-4.974284616),  0.894),
+                 (vector!( -4.64454848), 0.8957),
+                 (vector!(-4.290560426), 0.9047),
+                 (vector!(-3.885055584), 0.9129),
+                 (vector!(-3.408378962), 0.9209),
+                 (vector!( -3.13200249), 0.9219),
+                 (vector!(-8.726767166), 0.7739),
+                 (vector!( -8.66695597), 0.7681),
+                 (vector!(-8.511026475), 0.7665),
+                 (vector!(-8.165388579), 0.7703),
+                 (vector!(-7.886056648), 0.7702),
+                 (vector!(-7.588043762), 0.7761),
+                 (vector!(-7.283412422), 0.7809),
+                 (vector!(-6.995678626), 0.7961),
+                 (vector!(-6.691862621), 0.8253),
+                 (vector!(-6.392544977), 0.8602),
+                 (vector!(-6.067374056), 0.8809),
+                 (vector!(-6.684029655), 0.8301),
+                 (vector!(-6.378719832), 0.8664),
+                 (vector!(-6.065855188), 0.8834),
+                 (vector!(-5.752272167), 0.8898),
+                 (vector!(-5.132414673), 0.8964),
+                 (vector!(-4.811352704), 0.8963),
+                 (vector!(-4.098269308), 0.9074),
+                 (vector!( -3.66174277), 0.9119),
+                 (vector!(  -3.2644011), 0.9228)))
+}
+
+pub fn filip_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(-1467.48961422980,
+                         -2772.17959193342,
+                         -2316.37108160893,
+                         -1127.97394098372,
+                         -354.478233703349,
+                         -75.1242017393757,
+                         -10.8753180355343,
+                         -1.06221498588947,
+                         -0.670191154593408e-1,
+                         -0.246781078275479e-2,
+                         -0.402962525080404e-4),
+        standard_deviations: vec!(298.084530995537,
+                                  559.779865474950,
+                                  466.477572127796,
+                                  227.204274477751,
+                                  71.6478660875927,
+                                  15.2897178747400,
+                                  2.23691159816033,
+                                  0.221624321934227,
+                                  0.142363763154724e-1,
+                                  0.535617408889821e-3,
+                                  0.896632837373868e-5),
+        residual_standard_deviation: 0.334801051324544e-2,
+        r_squared: Some(0.996727416185620)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Longley.shtml
+pub fn longley() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 83.0, 234289.0, 2356.0, 1590.0, 107608.0, 1947.0), 60323.0),
+                 (vector!( 88.5, 259426.0, 2325.0, 1456.0, 108632.0, 1948.0), 61122.0),
+                 (vector!( 88.2, 258054.0, 3682.0, 1616.0, 109773.0, 1949.0), 60171.0),
+                 (vector!( 89.5, 284599.0, 3351.0, 1650.0, 110929.0, 1950.0), 61187.0),
+                 (vector!( 96.2, 328975.0, 2099.0, 3099.0, 112075.0, 1951.0), 63221.0),
+                 (vector!( 98.1, 346999.0, 1932.0, 3594.0, 113270.0, 1952.0), 63639.0),
+                 (vector!( 99.0, 365385.0, 1870.0, 3547.0, 115094.0, 1953.0), 64989.0),
+                 (vector!(100.0, 363112.0, 3578.0, 3350.0, 116219.0, 1954.0), 63761.0),
+                 (vector!(101.2, 397469.0, 2904.0, 3048.0, 117388.0, 1955.0), 66019.0),
+                 (vector!(104.6, 419180.0, 2822.0, 2857.0, 118734.0, 1956.0), 67857.0),
+                 (vector!(108.4, 442769.0, 2936.0, 2798.0, 120445.0, 1957.0), 68169.0),
+                 (vector!(110.8, 444546.0, 4681.0, 2637.0, 121950.0, 1958.0), 66513.0),
+                 (vector!(112.6, 482704.0, 3813.0, 2552.0, 123366.0, 1959.0), 68655.0),
+                 (vector!(114.2, 502601.0, 3931.0, 2514.0, 125368.0, 1960.0), 69564.0),
+                 (vector!(115.7, 518173.0, 4806.0, 2572.0, 127852.0, 1961.0), 69331.0),
+                 (vector!(116.9, 554894.0, 4007.0, 2827.0, 130081.0, 1962.0), 70551.0)))
+}
+
+pub fn longley_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(-3482258.63459582,
+                         15.0618722713733,
+                         -0.358191792925910e-1,
+                         -2.02022980381683,
+                         -1.03322686717359,
+                         -0.511041056535807e-1,
+                         1829.15146461355),
+        standard_deviations: vec!(890420.383607373,
+                                  84.9149257747669,
+                                  0.334910077722432e-1,
+                                  0.488399681651699,
+                                  0.214274163161675,
+                                  0.226073200069370,
+                                  455.478499142212),
+        residual_standard_deviation: 304.854073561965,
+        r_squared: Some(0.995479004577296)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Wampler1.shtml
+pub fn wampler1() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 0.0),       1.0),
+                 (vector!( 1.0),       6.0),
+                 (vector!( 2.0),      63.0),
+                 (vector!( 3.0),     364.0),
+                 (vector!( 4.0),    1365.0),
+                 (vector!( 5.0),    3906.0),
+                 (vector!( 6.0),    9331.0),
+                 (vector!( 7.0),   19608.0),
+                 (vector!( 8.0),   37449.0),
+                 (vector!( 9.0),   66430.0),
+                 (vector!(10.0),  111111.0),
+                 (vector!(11.0),  177156.0),
+                 (vector!(12.0),  271453.0),
+                 (vector!(13.0),  402234.0),
+                 (vector!(14.0),  579195.0),
+                 (vector!(15.0),  813616.0),
+                 (vector!(16.0), 1118481.0),
+                 (vector!(17.0), 1508598.0),
+                 (vector!(18.0), 2000719.0),
+                 (vector!(19.0), 2613660.0),
+                 (vector!(20.0), 3368421.0)))
+}
+
+pub fn wampler1_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0),
+        standard_deviations: vec!(0.0,
+                                  0.0,
+                                  0.0,
+                                  0.0,
+                                  0.0,
+                                  0.0),
+        residual_standard_deviation: 0.0,
+        r_squared: Some(1.0)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Wampler2.shtml
+pub fn wampler2() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 0.0),      1.0),
+                 (vector!( 1.0),  1.11111),
+                 (vector!( 2.0),  1.24992),
+                 (vector!( 3.0),  1.42753),
+                 (vector!( 4.0),  1.65984),
+                 (vector!( 5.0),  1.96875),
+                 (vector!( 6.0),  2.38336),
+                 (vector!( 7.0),  2.94117),
+                 (vector!( 8.0),  3.68928),
+                 (vector!( 9.0),  4.68559),
+                 (vector!(10.0),      6.0),
+                 (vector!(11.0),  7.71561),
+                 (vector!(12.0),  9.92992),
+                 (vector!(13.0), 12.75603),
+                 (vector!(14.0), 16.32384),
+                 (vector!(15.0), 20.78125),
+                 (vector!(16.0), 26.29536),
+                 (vector!(17.0), 33.05367),
+                 (vector!(18.0), 41.26528),
+                 (vector!(19.0), 51.16209),
+                 (vector!(20.0),     63.0)))
+}
+
+pub fn wampler2_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.0,
+                         0.1,
+                         0.01,
+                         0.001,
+                         0.0001,
+                         0.00001),
+        standard_deviations: vec!(0.0,
+                                  0.0,
+                                  0.0,
+                                  0.0,
+                                  0.0,
+                                  0.0),
+        residual_standard_deviation: 0.0,
+        r_squared: Some(1.0)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Wampler3.shtml
+pub fn wampler3() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 0.0),     760.0),
+                 (vector!( 1.0),   -2042.0),
+                 (vector!( 2.0),    2111.0),
+                 (vector!( 3.0),   -1684.0),
+                 (vector!( 4.0),    3888.0),
+                 (vector!( 5.0),    1858.0),
+                 (vector!( 6.0),   11379.0),
+                 (vector!( 7.0),   17560.0),
+                 (vector!( 8.0),   39287.0),
+                 (vector!( 9.0),   64382.0),
+                 (vector!(10.0),  113159.0),
+                 (vector!(11.0),  175108.0),
+                 (vector!(12.0),  273291.0),
+                 (vector!(13.0),  400186.0),
+                 (vector!(14.0),  581243.0),
+                 (vector!(15.0),  811568.0),
+                 (vector!(16.0), 1121004.0),
+                 (vector!(17.0), 1506550.0),
+                 (vector!(18.0), 2002767.0),
+                 (vector!(19.0), 2611612.0),
+                 (vector!(20.0), 3369180.0)))
+}
+
+pub fn wampler3_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0),
+        standard_deviations: vec!(2152.32624678170,
+                                  2363.55173469681,
+                                  779.343524331583,
+                                  101.475507550350,
+                                  5.64566512170752,
+                                  0.112324854679312),
+        residual_standard_deviation: 2360.14502379268,
+        r_squared: Some(0.999995559025820)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Wampler4.shtml
+pub fn wampler4() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 0.0),   75901.0),
+                 (vector!( 1.0), -204794.0),
+                 (vector!( 2.0),  204863.0),
+                 (vector!( 3.0), -204436.0),
+                 (vector!( 4.0),  253665.0),
+                 (vector!( 5.0), -200894.0),
+                 (vector!( 6.0),  214131.0),
+                 (vector!( 7.0), -185192.0),
+                 (vector!( 8.0),  221249.0),
+                 (vector!( 9.0), -138370.0),
+                 (vector!(10.0),  315911.0),
+                 (vector!(11.0),  -27644.0),
+                 (vector!(12.0),  455253.0),
+                 (vector!(13.0),  197434.0),
+                 (vector!(14.0),  783995.0),
+                 (vector!(15.0),  608816.0),
+                 (vector!(16.0), 1370781.0),
+                 (vector!(17.0), 1303798.0),
+                 (vector!(18.0), 2205519.0),
+                 (vector!(19.0), 2408860.0),
+                 (vector!(20.0), 3444321.0)))
+}
+
+pub fn wampler4_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0),
+        standard_deviations: vec!(215232.624678170,
+                                  236355.173469681,
+                                  77934.3524331583,
+                                  10147.5507550350,
+                                  564.566512170752,
+                                  11.2324854679312),
+        residual_standard_deviation: 236014.502379268,
+        r_squared: Some(0.957478440825662)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/lls/data/Wampler5.shtml
+pub fn wampler5() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 0.0),   7590001.0),
+                 (vector!( 1.0), -20479994.0),
+                 (vector!( 2.0),  20480063.0),
+                 (vector!( 3.0), -20479636.0),
+                 (vector!( 4.0),  25231365.0),
+                 (vector!( 5.0), -20476094.0),
+                 (vector!( 6.0),  20489331.0),
+                 (vector!( 7.0), -20460392.0),
+                 (vector!( 8.0),  18417449.0),
+                 (vector!( 9.0), -20413570.0),
+                 (vector!(10.0),  20591111.0),
+                 (vector!(11.0), -20302844.0),
+                 (vector!(12.0),  18651453.0),
+                 (vector!(13.0), -20077766.0),
+                 (vector!(14.0),  21059195.0),
+                 (vector!(15.0), -19666384.0),
+                 (vector!(16.0),  26348481.0),
+                 (vector!(17.0), -18971402.0),
+                 (vector!(18.0),  22480719.0),
+                 (vector!(19.0), -17866340.0),
+                 (vector!(20.0),  10958421.0)))
+}
+
+pub fn wampler5_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0,
+                         1.0),
+        standard_deviations: vec!(21523262.4678170,
+                                  23635517.3469681,
+                                  7793435.24331583,
+                                  1014755.07550350,
+                                  56456.6512170752,
+                                  1123.24854679312),
+        residual_standard_deviation: 23601450.2379268,
+        r_squared: Some(0.224668921574940e-2)
+    }
 }
@@ -1 +1,19 @@
+use rulinalg::vector::Vector;
+
+use datasets::SupervisedDataset;
+
 pub mod linear_regression;
+pub mod nonlinear_regression;
+
+// Values certified by NIST for a dataset, listed in the same order as the model parameters.
+// R-squared is only certified for the linear regression datasets.
+pub struct CertifiedValues {
+    pub parameters: Vec<f64>,
+    pub standard_deviations: Vec<f64>,
+    pub residual_standard_deviation: f64,
+    pub r_squared: Option<f64>
+}
+
+fn dataset(samples: Vec<(Vector<f64>, f64)>) -> SupervisedDataset<f64> {
+    SupervisedDataset::from_samples(samples).expect("NIST StRD: every input of a dataset has the same number of features.")
+}
@@ -0,0 +1,809 @@
+use std::marker::PhantomData;
+
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use optimization::ParametricFunction;
+use optimization::FunctionParameters;
+use datasets::SupervisedDataset;
+use super::CertifiedValues;
+use super::dataset;
+
+// Model of a NIST nonlinear regression problem, y = f(b, x), with a single input variable x.
+pub trait NonlinearModel {
+    fn parameters_size() -> usize;
+    fn f(b: &[f64], x: f64) -> f64;
+    fn df(b: &[f64], x: f64) -> f64;
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64>;
+}
+
+pub struct NonlinearFunction<M: NonlinearModel> {
+    parameters: FunctionParameters,
+    model: PhantomData<M>
+}
+
+impl<M: NonlinearModel> NonlinearFunction<M> {
+    pub fn new() -> NonlinearFunction<M> {
+        NonlinearFunction {
+            parameters: FunctionParameters::new(vector![0.0; M::parameters_size()]),
+            model: PhantomData
+        }
+    }
+
+    fn input(input: &Vector<f64>) -> Result<f64> {
+        if input.size() != 1 {
+            return Err(Error::DimensionMismatch { expected: 1, actual: input.size() });
+        }
+
+        Ok(input[0])
+    }
+}
+
+impl<M: NonlinearModel> Default for NonlinearFunction<M> {
+    fn default() -> NonlinearFunction<M> {
+        NonlinearFunction::new()
+    }
+}
+
+impl<M: NonlinearModel> ParametricFunction for NonlinearFunction<M> {
+    fn parameters(&self) -> &Vector<f64> {
+        self.parameters.vector()
+    }
+
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
+        self.parameters.set_vector(new_parameters)
+    }
+
+    fn f(&self, input: &Vector<f64>) -> Result<f64> {
+        Ok(M::f(self.parameters.vector().data(), Self::input(input)?))
+    }
+
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        Ok(vector!(M::df(self.parameters.vector().data(), Self::input(input)?)))
+    }
+
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        Ok(Vector::new(M::parameter_gradients(self.parameters.vector().data(), Self::input(input)?)))
+    }
+}
+
+// y = b1 * (1 - exp(-b2 * x))
+pub struct Misra1a;
+
+impl NonlinearModel for Misra1a {
+    fn parameters_size() -> usize {
+        2
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] * (1.0 - (-b[1] * x).exp())
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        b[0] * b[1] * (-b[1] * x).exp()
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let e = (-b[1] * x).exp();
+
+        vec!(1.0 - e, b[0] * x * e)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/misra1a.shtml
+pub fn misra1a() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 77.6), 10.07),
+                 (vector!(114.9), 14.73),
+                 (vector!(141.1), 17.94),
+                 (vector!(190.8), 23.93),
+                 (vector!(239.9), 29.61),
+                 (vector!(289.0), 35.18),
+                 (vector!(332.8), 40.02),
+                 (vector!(378.4), 44.82),
+                 (vector!(434.8), 50.76),
+                 (vector!(477.3), 55.05),
+                 (vector!(536.8), 61.01),
+                 (vector!(593.1), 66.40),
+                 (vector!(689.1), 75.47),
+                 (vector!(760.0), 81.78)))
+}
+
+pub fn misra1a_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(500.0, 1e-4),
+         vector!(250.0, 5e-4))
+}
+
+pub fn misra1a_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(2.3894212918e2,
+                         5.5015643181e-4),
+        standard_deviations: vec!(2.7070075241,
+                                  7.2668688436e-6),
+        residual_standard_deviation: 1.0187876330e-1,
+        r_squared: None
+    }
+}
+
+// y = b1 * (1 - (1 + b2 * x / 2)^-2)
+pub struct Misra1b;
+
+impl NonlinearModel for Misra1b {
+    fn parameters_size() -> usize {
+        2
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] * (1.0 - (1.0 + b[1] * x / 2.0).powi(-2))
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        b[0] * b[1] * (1.0 + b[1] * x / 2.0).powi(-3)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let u = 1.0 + b[1] * x / 2.0;
+
+        vec!(1.0 - u.powi(-2), b[0] * x * u.powi(-3))
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/misra1b.shtml
+pub fn misra1b() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 77.6), 10.07),
+                 (vector!(114.9), 14.73),
+                 (vector!(141.1), 17.94),
+                 (vector!(190.8), 23.93),
+                 (vector!(239.9), 29.61),
+                 (vector!(289.0), 35.18),
+                 (vector!(332.8), 40.02),
+                 (vector!(378.4), 44.82),
+                 (vector!(434.8), 50.76),
+                 (vector!(477.3), 55.05),
+                 (vector!(536.8), 61.01),
+                 (vector!(593.1), 66.40),
+                 (vector!(689.1), 75.47),
+                 (vector!(760.0), 81.78)))
+}
+
+pub fn misra1b_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(500.0, 1e-4),
+         vector!(300.0, 2e-4))
+}
+
+pub fn misra1b_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(3.3799746163e2,
+                         3.9039091287e-4),
+        standard_deviations: vec!(3.1643950207,
+                                  4.2547321834e-6),
+        residual_standard_deviation: 7.9301471998e-2,
+        r_squared: None
+    }
+}
+
+// y = exp(-b1 * x) / (b2 + b3 * x)
+pub struct Chwirut2;
+
+impl NonlinearModel for Chwirut2 {
+    fn parameters_size() -> usize {
+        3
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        (-b[0] * x).exp() / (b[1] + b[2] * x)
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        let y = Self::f(b, x);
+
+        -b[0] * y - b[2] * y / (b[1] + b[2] * x)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let y = Self::f(b, x);
+        let d = b[1] + b[2] * x;
+
+        vec!(-x * y, -y / d, -x * y / d)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/chwirut2.shtml
+pub fn chwirut2() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(0.500), 92.9000),
+                 (vector!(1.000), 57.1000),
+                 (vector!(1.750), 31.0500),
+                 (vector!(3.750), 11.5875),
+                 (vector!(5.750),  8.0250),
+                 (vector!(0.875), 63.6000),
+                 (vector!(2.250), 21.4000),
+                 (vector!(3.250), 14.2500),
+                 (vector!(5.250),  8.4750),
+                 (vector!(0.750), 63.8000),
+                 (vector!(1.750), 26.8000),
+                 (vector!(2.750), 16.4625),
+                 (vector!(4.750),  7.1250),
+                 (vector!(0.625), 67.3000),
+                 (vector!(1.250), 41.0000),
+                 (vector!(2.250), 21.1500),
+                 (vector!(4.250),  8.1750),
+                 (vector!(0.500), 81.5000),
+                 (vector!(3.000), 13.1200),
+                 (vector!(0.750), 59.9000),
+                 (vector!(3.000), 14.6200),
+                 (vector!(1.500), 32.9000),
+                 (vector!(6.000),  5.4400),
+                 (vector!(3.000), 12.5600),
+                 (vector!(6.000),  5.4400),
+                 (vector!(1.500), 32.0000),
+                 (vector!(3.000), 13.9500),
+                 (vector!(0.500), 75.8000),
+                 (vector!(2.000), 20.0000),
+                 (vector!(4.000), 10.4200),
+                 (vector!(0.750), 59.5000),
+                 (vector!(2.000), 21.6700),
+                 (vector!(5.000),  8.5500),
+                 (vector!(0.750), 62.0000),
+                 (vector!(2.250), 20.2000),
+                 (vector!(3.750),  7.7600),
+                 (vector!(5.750),  3.7500),
+                 (vector!(3.000), 11.8100),
+                 (vector!(0.750), 54.7000),
+                 (vector!(2.500), 23.7000),
+                 (vector!(4.000), 11.5500),
+                 (vector!(0.750), 61.3000),
+                 (vector!(2.500), 17.7000),
+                 (vector!(4.000),  8.7400),
+                 (vector!(0.750), 59.2000),
+                 (vector!(2.500), 16.3000),
+                 (vector!(4.000),  8.6200),
+                 (vector!(0.500), 81.0000),
+                 (vector!(6.000),  4.8700),
+                 (vector!(3.000), 14.6200),
+                 (vector!(0.500), 81.7000),
+                 (vector!(2.750), 17.1700),
+                 (vector!(0.500), 81.3000),
+                 (vector!(1.750), 28.9000)))
+}
+
+pub fn chwirut2_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(0.1, 0.01, 0.02),
+         vector!(0.15, 0.008, 0.010))
+}
+
+pub fn chwirut2_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.6657666537e-1,
+                         5.1653291286e-3,
+                         1.2150007096e-2),
+        standard_deviations: vec!(3.8303286810e-2,
+                                  6.6621605126e-4,
+                                  1.5304234767e-3),
+        residual_standard_deviation: 3.1717133040,
+        r_squared: None
+    }
+}
+
+// y = b1 * x^b2
+pub struct DanWood;
+
+impl NonlinearModel for DanWood {
+    fn parameters_size() -> usize {
+        2
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] * x.powf(b[1])
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        b[0] * b[1] * x.powf(b[1] - 1.0)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let p = x.powf(b[1]);
+
+        vec!(p, b[0] * p * x.ln())
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/danwood.shtml
+pub fn dan_wood() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(1.309), 2.138),
+                 (vector!(1.471), 3.421),
+                 (vector!(1.490), 3.597),
+                 (vector!(1.565), 4.340),
+                 (vector!(1.611), 4.882),
+                 (vector!(1.680), 5.660)))
+}
+
+pub fn dan_wood_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(1.0, 5.0),
+         vector!(0.7, 4.0))
+}
+
+pub fn dan_wood_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(7.6886226176e-1,
+                         3.8604055871),
+        standard_deviations: vec!(1.8281973860e-2,
+                                  5.1726610913e-2),
+        residual_standard_deviation: 3.2853114039e-2,
+        r_squared: None
+    }
+}
+
+// y = b1 * (x^2 + x * b2) / (x^2 + x * b3 + b4)
+pub struct Mgh09;
+
+impl NonlinearModel for Mgh09 {
+    fn parameters_size() -> usize {
+        4
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] * (x.powi(2) + x * b[1]) / (x.powi(2) + x * b[2] + b[3])
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        let n = x.powi(2) + x * b[1];
+        let d = x.powi(2) + x * b[2] + b[3];
+
+        b[0] * ((2.0 * x + b[1]) * d - n * (2.0 * x + b[2])) / d.powi(2)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let n = x.powi(2) + x * b[1];
+        let d = x.powi(2) + x * b[2] + b[3];
+
+        vec!(n / d, b[0] * x / d, -b[0] * n * x / d.powi(2), -b[0] * n / d.powi(2))
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/mgh09.shtml
+pub fn mgh09() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(   4.0), 0.1957),
+                 (vector!(   2.0), 0.1947),
+                 (vector!(   1.0), 0.1735),
+                 (vector!(   0.5),   0.16),
+                 (vector!(  0.25), 0.0844),
+                 (vector!( 0.167), 0.0627),
+                 (vector!( 0.125), 0.0456),
+                 (vector!(   0.1), 0.0342),
+                 (vector!(0.0833), 0.0323),
+                 (vector!(0.0714), 0.0235),
+                 (vector!(0.0625), 0.0246)))
+}
+
+pub fn mgh09_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(25.0, 39.0, 41.5, 39.0),
+         vector!(0.25, 0.39, 0.415, 0.39))
+}
+
+pub fn mgh09_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.9280693458e-1,
+                         1.9128232873e-1,
+                         1.2305650693e-1,
+                         1.3606233068e-1),
+        standard_deviations: vec!(1.1435312227e-2,
+                                  1.9633220911e-1,
+                                  8.0842031232e-2,
+                                  9.0025542308e-2),
+        residual_standard_deviation: 6.6279236551e-3,
+        r_squared: None
+    }
+}
+
+// y = (b1 + b2 * x + b3 * x^2 + b4 * x^3) / (1 + b5 * x + b6 * x^2 + b7 * x^3)
+pub struct Thurber;
+
+impl NonlinearModel for Thurber {
+    fn parameters_size() -> usize {
+        7
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        let n = b[0] + b[1] * x + b[2] * x.powi(2) + b[3] * x.powi(3);
+        let d = 1.0 + b[4] * x + b[5] * x.powi(2) + b[6] * x.powi(3);
+
+        n / d
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        let n = b[0] + b[1] * x + b[2] * x.powi(2) + b[3] * x.powi(3);
+        let d = 1.0 + b[4] * x + b[5] * x.powi(2) + b[6] * x.powi(3);
+        let dn = b[1] + 2.0 * b[2] * x + 3.0 * b[3] * x.powi(2);
+        let dd = b[4] + 2.0 * b[5] * x + 3.0 * b[6] * x.powi(2);
+
+        (dn * d - n * dd) / d.powi(2)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let n = b[0] + b[1] * x + b[2] * x.powi(2) + b[3] * x.powi(3);
+        let d = 1.0 + b[4] * x + b[5] * x.powi(2) + b[6] * x.powi(3);
+        let q = -n / d.powi(2);
+
+        vec!(1.0 / d, x / d, x.powi(2) / d, x.powi(3) / d, q * x, q * x.powi(2), q * x.powi(3))
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/thurber.shtml
+pub fn thurber() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(-3.067),   80.574),
+                 (vector!(-2.981),   84.248),
+                 (vector!(-2.921),   87.264),
+                 (vector!(-2.912),   87.195),
+                 (vector!(-2.840),   89.076),
+                 (vector!(-2.797),   89.608),
+                 (vector!(-2.702),   89.868),
+                 (vector!(-2.699),   90.101),
+                 (vector!(-2.633),   92.405),
+                 (vector!(-2.481),   95.854),
+                 (vector!(-2.363),  100.696),
+                 (vector!(-2.322),  101.060),
+                 (vector!(-1.501),  401.672),
+                 (vector!(-1.460),  390.724),
+                 (vector!(-1.274),  567.534),
+                 (vector!(-1.212),  635.316),
+                 (vector!(-1.100),  733.054),
+                 (vector!(-1.046),  759.087),
+                 (vector!(-0.915),  894.206),
+                 (vector!(-0.714),  990.785),
+                 (vector!(-0.566), 1090.109),
+                 (vector!(-0.545), 1080.914),
+                 (vector!(-0.400), 1122.643),
+                 (vector!(-0.309), 1178.351),
+                 (vector!(-0.109), 1260.531),
+                 (vector!(-0.103), 1273.514),
+                 (vector!( 0.010), 1288.339),
+                 (vector!( 0.119), 1327.543),
+                 (vector!( 0.377), 1353.863),
+                 (vector!( 0.790), 1414.509),
+                 (vector!( 0.963), 1425.208),
+                 (vector!( 1.006), 1421.384),
+                 (vector!( 1.115), 1442.962),
+                 (vector!( 1.572), 1464.350),
+                 (vector!( 1.841), 1468.705),
+                 (vector!( 2.047), 1447.894),
+                 (vector!( 2.200), 1457.628)))
+}
+
+pub fn thurber_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(1000.0, 1000.0, 400.0, 40.0, 0.7, 0.3, 0.03),
+         vector!(1300.0, 1500.0, 500.0, 75.0, 1.0, 0.4, 0.05))
+}
+
+pub fn thurber_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.2881396800e3,
+                         1.4910792535e3,
+                         5.8323836877e2,
+                         7.5416644291e1,
+                         9.6629502864e-1,
+                         3.9797285797e-1,
+                         4.9727297349e-2),
+        standard_deviations: vec!(4.6647963344,
+                                  3.9571156086e1,
+                                  2.8698696102e1,
+                                  5.5675370270,
+                                  3.1333340687e-2,
+                                  1.4984928198e-2,
+                                  6.5842344623e-3),
+        residual_standard_deviation: 1.3714600784e1,
+        r_squared: None
+    }
+}
+
+// y = (b1 / b2) * exp(-0.5 * ((x - b3) / b2)^2)
+pub struct Eckerle4;
+
+impl NonlinearModel for Eckerle4 {
+    fn parameters_size() -> usize {
+        3
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        (b[0] / b[1]) * (-0.5 * ((x - b[2]) / b[1]).powi(2)).exp()
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        -Self::f(b, x) * (x - b[2]) / b[1].powi(2)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let y = Self::f(b, x);
+        let u = (x - b[2]) / b[1];
+
+        vec!(y / b[0], y * (u.powi(2) - 1.0) / b[1], y * u / b[1])
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/eckerle4.shtml
+pub fn eckerle4() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!(400.0), 0.0001575),
+                 (vector!(405.0), 0.0001699),
+                 (vector!(410.0), 0.0002350),
+                 (vector!(415.0), 0.0003102),
+                 (vector!(420.0), 0.0004917),
+                 (vector!(425.0), 0.0008710),
+                 (vector!(430.0), 0.0017418),
+                 (vector!(435.0), 0.0046400),
+                 (vector!(436.5), 0.0065895),
+                 (vector!(438.0), 0.0097302),
+                 (vector!(439.5), 0.0149002),
+                 (vector!(441.0), 0.0237310),
+                 (vector!(442.5), 0.0401683),
+                 (vector!(444.0), 0.0712559),
+                 (vector!(445.5), 0.1264458),
+                 (vector!(447.0), 0.2073413),
+                 (vector!(448.5), 0.2902366),
+                 (vector!(450.0), 0.3445623),
+                 (vector!(451.5), 0.3698049),
+                 (vector!(453.0), 0.3668534),
+                 (vector!(454.5), 0.3106727),
+                 (vector!(456.0), 0.2078154),
+                 (vector!(457.5), 0.1164354),
+                 (vector!(459.0), 0.0616764),
+                 (vector!(460.5), 0.0337200),
+                 (vector!(462.0), 0.0194023),
+                 (vector!(463.5), 0.0117831),
+                 (vector!(465.0), 0.0074357),
+                 (vector!(470.0), 0.0022732),
+                 (vector!(475.0), 0.0008800),
+                 (vector!(480.0), 0.0004579),
+                 (vector!(485.0), 0.0002345),
+                 (vector!(490.0), 0.0001586),
+                 (vector!(495.0), 0.0001143),
+                 (vector!(500.0), 0.0000710)))
+}
+
+pub fn eckerle4_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(1.0, 10.0, 500.0),
+         vector!(1.5, 5.0, 450.0))
+}
+
+pub fn eckerle4_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(1.5543827178,
+                         4.0888321754,
+                         4.5154121844e2),
+        standard_deviations: vec!(1.5408051163e-2,
+                                  4.6803020753e-2,
+                                  4.6800518816e-2),
+        residual_standard_deviation: 6.7629245447e-3,
+        r_squared: None
+    }
+}
+
+// y = b1 * (1 - exp(-b2 * x))
+pub struct BoxBod;
+
+impl NonlinearModel for BoxBod {
+    fn parameters_size() -> usize {
+        2
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] * (1.0 - (-b[1] * x).exp())
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        b[0] * b[1] * (-b[1] * x).exp()
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let e = (-b[1] * x).exp();
+
+        vec!(1.0 - e, b[0] * x * e)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/boxbod.shtml
+pub fn box_bod() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 1.0), 109.0),
+                 (vector!( 2.0), 149.0),
+                 (vector!( 3.0), 149.0),
+                 (vector!( 5.0), 191.0),
+                 (vector!( 7.0), 213.0),
+                 (vector!(10.0), 224.0)))
+}
+
+pub fn box_bod_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(1.0, 1.0),
+         vector!(100.0, 0.75))
+}
+
+pub fn box_bod_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(2.1380940889e2,
+                         5.4723748542e-1),
+        standard_deviations: vec!(1.2354515176e1,
+                                  1.0455993237e-1),
+        residual_standard_deviation: 1.7088072423e1,
+        r_squared: None
+    }
+}
+
+// y = b1 / (1 + exp(b2 - b3 * x))
+pub struct Rat42;
+
+impl NonlinearModel for Rat42 {
+    fn parameters_size() -> usize {
+        3
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] / (1.0 + (b[1] - b[2] * x).exp())
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        let e = (b[1] - b[2] * x).exp();
+
+        b[0] * b[2] * e / (1.0 + e).powi(2)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let e = (b[1] - b[2] * x).exp();
+        let q = b[0] * e / (1.0 + e).powi(2);
+
+        vec!(1.0 / (1.0 + e), -q, q * x)
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/rat42.shtml
+pub fn rat42() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 9.000),  8.930),
+                 (vector!(14.000), 10.800),
+                 (vector!(21.000), 18.590),
+                 (vector!(28.000), 22.330),
+                 (vector!(42.000), 39.350),
+                 (vector!(57.000), 56.110),
+                 (vector!(63.000), 61.730),
+                 (vector!(70.000), 64.620),
+                 (vector!(79.000), 67.080)))
+}
+
+pub fn rat42_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(100.0, 1.0, 0.1),
+         vector!(75.0, 2.5, 0.07))
+}
+
+pub fn rat42_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(7.2462237576e1,
+                         2.6180768402,
+                         6.7359200066e-2),
+        standard_deviations: vec!(1.7340283401,
+                                  8.8295217536e-2,
+                                  3.4465663377e-3),
+        residual_standard_deviation: 1.1587725499,
+        r_squared: None
+    }
+}
+
+// y = b1 / (1 + exp(b2 - b3 * x))^(1 / b4)
+pub struct Rat43;
+
+impl NonlinearModel for Rat43 {
+    fn parameters_size() -> usize {
+        4
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] / (1.0 + (b[1] - b[2] * x).exp()).powf(1.0 / b[3])
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        let e = (b[1] - b[2] * x).exp();
+
+        b[0] * (b[2] / b[3]) * e * (1.0 + e).powf(-1.0 / b[3] - 1.0)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let e = (b[1] - b[2] * x).exp();
+        let p = (1.0 + e).powf(-1.0 / b[3]);
+        let q = b[0] * e * (1.0 + e).powf(-1.0 / b[3] - 1.0) / b[3];
+
+        vec!(p, -q, q * x, b[0] * p * (1.0 + e).ln() / b[3].powi(2))
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/rat43.shtml
+pub fn rat43() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 1.0),  16.08),
+                 (vector!( 2.0),  33.83),
+                 (vector!( 3.0),  65.80),
+                 (vector!( 4.0),  97.20),
+                 (vector!( 5.0), 191.55),
+                 (vector!( 6.0), 326.20),
+                 (vector!( 7.0), 386.87),
+                 (vector!( 8.0), 520.53),
+                 (vector!( 9.0), 590.03),
+                 (vector!(10.0), 651.92),
+                 (vector!(11.0), 724.93),
+                 (vector!(12.0), 699.56),
+                 (vector!(13.0), 689.96),
+                 (vector!(14.0), 637.56),
+                 (vector!(15.0), 717.41)))
+}
+
+pub fn rat43_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(100.0, 10.0, 1.0, 1.0),
+         vector!(700.0, 5.0, 0.75, 1.3))
+}
+
+pub fn rat43_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(6.9964151270e2,
+                         5.2771253025,
+                         7.5962938329e-1,
+                         1.2792483859),
+        standard_deviations: vec!(1.6302297817e1,
+                                  2.0828735829,
+                                  1.9566123451e-1,
+                                  6.8761936385e-1),
+        residual_standard_deviation: 2.8262414662e1,
+        r_squared: None
+    }
+}
+
+// y = b1 * exp(b2 / (x + b3))
+pub struct Mgh10;
+
+impl NonlinearModel for Mgh10 {
+    fn parameters_size() -> usize {
+        3
+    }
+
+    fn f(b: &[f64], x: f64) -> f64 {
+        b[0] * (b[1] / (x + b[2])).exp()
+    }
+
+    fn df(b: &[f64], x: f64) -> f64 {
+        -Self::f(b, x) * b[1] / (x + b[2]).powi(2)
+    }
+
+    fn parameter_gradients(b: &[f64], x: f64) -> Vec<f64> {
+        let y = Self::f(b, x);
+
+        vec!(y / b[0], y / (x + b[2]), -y * b[1] / (x + b[2]).powi(2))
+    }
+}
+
+// https://www.itl.nist.gov/div898/strd/nls/data/mgh10.shtml
+pub fn mgh10() -> SupervisedDataset<f64> {
+    dataset(vec!((vector!( 50.0), 34780.0),
+                 (vector!( 55.0), 28610.0),
+                 (vector!( 60.0), 23650.0),
+                 (vector!( 65.0), 19630.0),
+                 (vector!( 70.0), 16370.0),
+                 (vector!( 75.0), 13720.0),
+                 (vector!( 80.0), 11540.0),
+                 (vector!( 85.0),  9744.0),
+                 (vector!( 90.0),  8261.0),
+                 (vector!( 95.0),  7030.0),
+                 (vector!(100.0),  6005.0),
+                 (vector!(105.0),  5147.0),
+                 (vector!(110.0),  4427.0),
+                 (vector!(115.0),  3820.0),
+                 (vector!(120.0),  3307.0),
+                 (vector!(125.0),  2872.0)))
+}
+
+pub fn mgh10_starting_values() -> Vec<Vector<f64>> {
+    vec!(vector!(2.0, 400000.0, 25000.0),
+         vector!(0.02, 4000.0, 250.0))
+}
+
+pub fn mgh10_certified_values() -> CertifiedValues {
+    CertifiedValues {
+        parameters: vec!(5.6096364710e-3,
+                         6.1813463463e3,
+                         3.4522363462e2),
+        standard_deviations: vec!(1.5687892471e-4,
+                                  2.3309021107e1,
+                                  7.8486103508e-1),
+        residual_standard_deviation: 2.6009740065,
+        r_squared: None
+    }
+}
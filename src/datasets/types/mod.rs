@@ -0,0 +1,5 @@
+mod unsupervised_dataset;
+mod supervised_dataset;
+
+pub use self::unsupervised_dataset::UnsupervisedDataset;
+pub use self::supervised_dataset::SupervisedDataset;
@@ -0,0 +1,247 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use super::UnsupervisedDataset;
+
+// Inputs with one label each, and optionally one weight each (e.g. the inverse variance of the
+// label noise, or the number of aggregated samples). Without weights, every sample weighs 1.
+#[derive(Clone, Debug)]
+pub struct SupervisedDataset<L> {
+    inputs: UnsupervisedDataset,
+    labels: Vec<L>,
+    weights: Option<Vec<f64>>
+}
+
+impl<L> SupervisedDataset<L> {
+    pub fn new(inputs: Matrix<f64>, labels: Vec<L>) -> Result<SupervisedDataset<L>> {
+        SupervisedDataset::from_unsupervised_dataset(UnsupervisedDataset::new(inputs), labels)
+    }
+
+    pub fn from_unsupervised_dataset(inputs: UnsupervisedDataset, labels: Vec<L>) -> Result<SupervisedDataset<L>> {
+        if labels.len() != inputs.len() {
+            return Err(Error::LabelCount { expected: inputs.len(), actual: labels.len() });
+        }
+
+        Ok(SupervisedDataset { inputs, labels, weights: None })
+    }
+
+    pub fn from_samples(samples: Vec<(Vector<f64>, L)>) -> Result<SupervisedDataset<L>> {
+        let (inputs, labels): (Vec<Vector<f64>>, Vec<L>) = samples.into_iter().unzip();
+
+        SupervisedDataset::from_unsupervised_dataset(UnsupervisedDataset::from_inputs(&inputs)?, labels)
+    }
+
+    pub fn with_feature_names(self, feature_names: Vec<String>) -> Result<SupervisedDataset<L>> {
+        Ok(SupervisedDataset {
+            inputs: self.inputs.with_feature_names(feature_names)?,
+            labels: self.labels,
+            weights: self.weights
+        })
+    }
+
+    // The same labels and weights for other inputs, e.g. transformed ones.
+    pub fn with_unsupervised_dataset(self, inputs: UnsupervisedDataset) -> Result<SupervisedDataset<L>> {
+        if inputs.len() != self.labels.len() {
+            return Err(Error::LabelCount { expected: inputs.len(), actual: self.labels.len() });
+        }
+
+        Ok(SupervisedDataset {
+            inputs,
+            labels: self.labels,
+            weights: self.weights
+        })
+    }
+
+    // The same inputs and weights with other labels, e.g. encoded ones.
+    pub fn with_labels<M>(self, labels: Vec<M>) -> Result<SupervisedDataset<M>> {
+        if labels.len() != self.labels.len() {
+            return Err(Error::LabelCount { expected: self.labels.len(), actual: labels.len() });
+        }
+
+        Ok(SupervisedDataset {
+            inputs: self.inputs,
+            labels,
+            weights: self.weights
+        })
+    }
+
+    // Weights must be finite, non-negative and not all 0.
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Result<SupervisedDataset<L>> {
+        if weights.len() != self.len() {
+            return Err(Error::WeightCount { expected: self.len(), actual: weights.len() });
+        }
+        if let Some(&weight) = weights.iter().find(|weight| !weight.is_finite() || **weight < 0.0) {
+            return Err(Error::InvalidWeight(weight));
+        }
+        if !weights.is_empty() && weights.iter().all(|&weight| weight == 0.0) {
+            return Err(Error::InvalidSetting("the sample weights sum to 0".to_string()));
+        }
+
+        self.weights = Some(weights);
+
+        Ok(self)
+    }
+
+    pub fn unsupervised_dataset(&self) -> &UnsupervisedDataset {
+        &self.inputs
+    }
+
+    pub fn inputs(&self) -> &Matrix<f64> {
+        self.inputs.inputs()
+    }
+
+    pub fn input(&self, index: usize) -> Vector<f64> {
+        self.inputs.input(index)
+    }
+
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    pub fn weights(&self) -> Option<&[f64]> {
+        self.weights.as_deref()
+    }
+
+    pub fn weight(&self, index: usize) -> f64 {
+        self.weights.as_ref().map_or(1.0, |weights| weights[index])
+    }
+
+    // Sum of the weights, i.e. the number of samples without weights.
+    pub fn total_weight(&self) -> f64 {
+        self.weights.as_ref().map_or(self.len() as f64, |weights| weights.iter().sum())
+    }
+
+    // Number of samples with a positive weight, those weighing 0 taking no part in a fit.
+    pub fn positive_weight_count(&self) -> usize {
+        self.weights.as_ref().map_or(self.len(), |weights| weights.iter().filter(|&&weight| weight > 0.0).count())
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = (Vector<f64>, &'a L)> + 'a {
+        self.inputs.iter().zip(self.labels.iter())
+    }
+
+    pub fn weighted_iter<'a>(&'a self) -> impl Iterator<Item = (Vector<f64>, &'a L, f64)> + 'a {
+        self.iter().enumerate().map(move |(index, (input, label))| (input, label, self.weight(index)))
+    }
+
+    pub fn feature_count(&self) -> usize {
+        self.inputs.feature_count()
+    }
+
+    pub fn feature_names(&self) -> Option<&[String]> {
+        self.inputs.feature_names()
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl<L: Clone> SupervisedDataset<L> {
+    pub fn select(&self, indices: &[usize]) -> SupervisedDataset<L> {
+        SupervisedDataset {
+            inputs: self.inputs.select(indices),
+            labels: indices.iter().map(|&index| self.labels[index].clone()).collect(),
+            weights: self.weights.as_ref().map(|weights| indices.iter().map(|&index| weights[index]).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use super::SupervisedDataset;
+    use super::UnsupervisedDataset;
+    use Error;
+
+    #[test]
+    fn from_samples() {
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(1.0, 2.0), 3.0), (vector!(4.0, 5.0), 6.0))).unwrap();
+
+        assert_eq!(dataset.len(), 2);
+        assert_eq!(dataset.feature_count(), 2);
+        assert_eq!(dataset.labels(), &[3.0, 6.0]);
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), vec!((vector!(1.0, 2.0), &3.0), (vector!(4.0, 5.0), &6.0)));
+    }
+
+    #[test]
+    fn from_ragged_samples() {
+        assert_eq!(SupervisedDataset::from_samples(vec!((vector!(1.0, 2.0), 3.0), (vector!(4.0), 6.0))).unwrap_err(),
+                   Error::RaggedInputs { row: 1, expected: 2, actual: 1 });
+    }
+
+    #[test]
+    fn new_with_wrong_number_of_labels() {
+        assert_eq!(SupervisedDataset::new(Matrix::new(2, 1, vec!(1.0, 2.0)), vec!(1.0)).unwrap_err(),
+                   Error::LabelCount { expected: 2, actual: 1 });
+    }
+
+    #[test]
+    fn select() {
+        let dataset = SupervisedDataset::new(Matrix::new(3, 1, vec!(1.0, 2.0, 3.0)), vec!(10, 20, 30)).unwrap();
+        let selection = dataset.select(&[1, 2]);
+
+        assert_eq!(selection.inputs().data(), &vec!(2.0, 3.0));
+        assert_eq!(selection.labels(), &[20, 30]);
+    }
+
+    #[test]
+    fn with_unsupervised_dataset() {
+        let dataset = SupervisedDataset::new(Matrix::new(2, 1, vec!(1.0, 2.0)), vec!(10, 20)).unwrap()
+                                        .with_weights(vec!(1.0, 3.0)).unwrap();
+        let inputs = UnsupervisedDataset::new(Matrix::new(2, 2, vec!(1.0, 1.0, 2.0, 4.0)));
+        let transformed_dataset = dataset.clone().with_unsupervised_dataset(inputs).unwrap();
+
+        assert_eq!(transformed_dataset.input(1), vector!(2.0, 4.0));
+        assert_eq!(transformed_dataset.labels(), &[10, 20]);
+        assert_eq!(transformed_dataset.weights(), Some(&[1.0, 3.0][..]));
+        assert_eq!(dataset.with_unsupervised_dataset(UnsupervisedDataset::new(Matrix::new(1, 1, vec!(1.0)))).unwrap_err(),
+                   Error::LabelCount { expected: 1, actual: 2 });
+    }
+
+    #[test]
+    fn with_labels() {
+        let dataset = SupervisedDataset::new(Matrix::new(2, 1, vec!(1.0, 2.0)), vec!(true, false)).unwrap()
+                                        .with_weights(vec!(1.0, 3.0)).unwrap();
+        let encoded_dataset = dataset.clone().with_labels(vec!(1.0, 0.0)).unwrap();
+
+        assert_eq!(encoded_dataset.input(1), vector!(2.0));
+        assert_eq!(encoded_dataset.labels(), &[1.0, 0.0]);
+        assert_eq!(encoded_dataset.weights(), Some(&[1.0, 3.0][..]));
+        assert_eq!(dataset.with_labels(vec!(1.0)).unwrap_err(), Error::LabelCount { expected: 2, actual: 1 });
+    }
+
+    #[test]
+    fn weights() {
+        let dataset = SupervisedDataset::new(Matrix::new(3, 1, vec!(1.0, 2.0, 3.0)), vec!(10, 20, 30)).unwrap();
+
+        assert_eq!(dataset.weights(), None);
+        assert_eq!(dataset.total_weight(), 3.0);
+        assert_eq!(dataset.positive_weight_count(), 3);
+
+        let dataset = dataset.with_weights(vec!(0.5, 2.0, 0.0)).unwrap();
+        let selection = dataset.select(&[2, 1]);
+
+        assert_eq!(dataset.weights(), Some(&[0.5, 2.0, 0.0][..]));
+        assert_eq!(dataset.total_weight(), 2.5);
+        assert_eq!(dataset.positive_weight_count(), 2);
+        assert_eq!(selection.weights(), Some(&[0.0, 2.0][..]));
+        assert_eq!(selection.weighted_iter().map(|(_, &label, weight)| (label, weight)).collect::<Vec<_>>(), vec!((30, 0.0), (20, 2.0)));
+    }
+
+    #[test]
+    fn invalid_weights() {
+        let dataset = SupervisedDataset::new(Matrix::new(2, 1, vec!(1.0, 2.0)), vec!(10, 20)).unwrap();
+
+        assert_eq!(dataset.clone().with_weights(vec!(1.0)).unwrap_err(), Error::WeightCount { expected: 2, actual: 1 });
+        assert_eq!(dataset.clone().with_weights(vec!(1.0, -1.0)).unwrap_err(), Error::InvalidWeight(-1.0));
+        assert_eq!(dataset.with_weights(vec!(0.0, 0.0)).unwrap_err(),
+                   Error::InvalidSetting("the sample weights sum to 0".to_string()));
+    }
+}
@@ -0,0 +1,134 @@
+use rulinalg::matrix::{Matrix, BaseMatrix};
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+
+// Inputs stored as the rows of a matrix, so that every input has the same number of features.
+#[derive(Clone, Debug)]
+pub struct UnsupervisedDataset {
+    inputs: Matrix<f64>,
+    feature_names: Option<Vec<String>>
+}
+
+impl UnsupervisedDataset {
+    pub fn new(inputs: Matrix<f64>) -> UnsupervisedDataset {
+        UnsupervisedDataset {
+            inputs,
+            feature_names: None
+        }
+    }
+
+    pub fn from_inputs(inputs: &[Vector<f64>]) -> Result<UnsupervisedDataset> {
+        let feature_count = inputs.first().map_or(0, |input| input.size());
+        let mut data = Vec::with_capacity(inputs.len() * feature_count);
+
+        for (row, input) in inputs.iter().enumerate() {
+            if input.size() != feature_count {
+                return Err(Error::RaggedInputs { row, expected: feature_count, actual: input.size() });
+            }
+
+            data.extend_from_slice(input.data());
+        }
+
+        Ok(UnsupervisedDataset::new(Matrix::new(inputs.len(), feature_count, data)))
+    }
+
+    pub fn with_feature_names(mut self, feature_names: Vec<String>) -> Result<UnsupervisedDataset> {
+        if feature_names.len() != self.feature_count() {
+            return Err(Error::FeatureNameCount { expected: self.feature_count(), actual: feature_names.len() });
+        }
+
+        self.feature_names = Some(feature_names);
+
+        Ok(self)
+    }
+
+    pub fn inputs(&self) -> &Matrix<f64> {
+        &self.inputs
+    }
+
+    pub fn input(&self, index: usize) -> Vector<f64> {
+        Vector::new(self.inputs.row(index).raw_slice())
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = Vector<f64>> + 'a {
+        (0..self.len()).map(move |index| self.input(index))
+    }
+
+    pub fn feature_count(&self) -> usize {
+        self.inputs.cols()
+    }
+
+    pub fn feature_names(&self) -> Option<&[String]> {
+        self.feature_names.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inputs.rows()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn select(&self, indices: &[usize]) -> UnsupervisedDataset {
+        UnsupervisedDataset {
+            inputs: self.inputs.select_rows(indices),
+            feature_names: self.feature_names.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnsupervisedDataset;
+    use Error;
+
+    #[test]
+    fn from_inputs() {
+        let dataset = UnsupervisedDataset::from_inputs(&[vector!(1.0, 2.0), vector!(3.0, 4.0), vector!(5.0, 6.0)]).unwrap();
+
+        assert_eq!(dataset.len(), 3);
+        assert_eq!(dataset.feature_count(), 2);
+        assert_eq!(dataset.input(1), vector!(3.0, 4.0));
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), vec!(vector!(1.0, 2.0), vector!(3.0, 4.0), vector!(5.0, 6.0)));
+    }
+
+    #[test]
+    fn from_empty_inputs() {
+        let dataset = UnsupervisedDataset::from_inputs(&[]).unwrap();
+
+        assert!(dataset.is_empty());
+        assert_eq!(dataset.feature_count(), 0);
+    }
+
+    #[test]
+    fn from_ragged_inputs() {
+        assert_eq!(UnsupervisedDataset::from_inputs(&[vector!(1.0, 2.0), vector!(3.0), vector!(5.0, 6.0)]).unwrap_err(),
+                   Error::RaggedInputs { row: 1, expected: 2, actual: 1 });
+    }
+
+    #[test]
+    fn with_feature_names() {
+        let dataset = UnsupervisedDataset::from_inputs(&[vector!(1.0, 2.0)]).unwrap()
+                                                                           .with_feature_names(vec!("x".to_string(), "y".to_string()))
+                                                                           .unwrap();
+
+        assert_eq!(dataset.feature_names(), Some(&["x".to_string(), "y".to_string()][..]));
+    }
+
+    #[test]
+    fn with_wrong_number_of_feature_names() {
+        assert_eq!(UnsupervisedDataset::from_inputs(&[vector!(1.0, 2.0)]).unwrap()
+                                                                         .with_feature_names(vec!("x".to_string()))
+                                                                         .unwrap_err(),
+                   Error::FeatureNameCount { expected: 2, actual: 1 });
+    }
+
+    #[test]
+    fn select() {
+        let dataset = UnsupervisedDataset::from_inputs(&[vector!(1.0), vector!(2.0), vector!(3.0)]).unwrap();
+
+        assert_eq!(dataset.select(&[2, 0]).iter().collect::<Vec<_>>(), vec!(vector!(3.0), vector!(1.0)));
+    }
+}
@@ -0,0 +1,61 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    DimensionMismatch { expected: usize, actual: usize },
+    RaggedInputs { row: usize, expected: usize, actual: usize },
+    LabelCount { expected: usize, actual: usize },
+    FeatureNameCount { expected: usize, actual: usize },
+    WeightCount { expected: usize, actual: usize },
+    InvalidLabel(f64),
+    InvalidWeight(f64),
+    UnknownCategory { feature: usize, value: f64 },
+    EmptyDataset,
+    RankDeficient,
+    Divergence,
+    NotFitted,
+    Unsupported,
+    InvalidSetting(String),
+    Io(String)
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::DimensionMismatch { expected, actual } =>
+                write!(f, "dimension mismatch ({} instead of {})", actual, expected),
+            Error::RaggedInputs { row, expected, actual } =>
+                write!(f, "input {} has {} features instead of {}", row, actual, expected),
+            Error::LabelCount { expected, actual } =>
+                write!(f, "{} labels given for {} inputs", actual, expected),
+            Error::FeatureNameCount { expected, actual } =>
+                write!(f, "{} feature names given for {} features", actual, expected),
+            Error::WeightCount { expected, actual } =>
+                write!(f, "{} weights given for {} inputs", actual, expected),
+            Error::InvalidLabel(label) => write!(f, "label {} is not a class index", label),
+            Error::InvalidWeight(weight) => write!(f, "weight {} is not a finite non-negative number", weight),
+            Error::UnknownCategory { feature, value } =>
+                write!(f, "category {} of feature {} was not seen when fitting", value, feature),
+            Error::EmptyDataset => write!(f, "empty dataset"),
+            Error::RankDeficient => write!(f, "rank deficient least squares problem"),
+            Error::Divergence => write!(f, "fit diverged to a non-finite error"),
+            Error::NotFitted => write!(f, "model used before fitting"),
+            Error::Unsupported => write!(f, "unsupported operation"),
+            Error::InvalidSetting(ref message) => write!(f, "invalid setting: {}", message),
+            Error::Io(ref message) => write!(f, "I/O error: {}", message)
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error.to_string())
+    }
+}
+
+impl error::Error for Error {}
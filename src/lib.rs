@@ -1,6 +1,11 @@
 pub mod datasets;
 pub mod optimization;
 pub mod regression;
+pub mod preprocessing;
+
+mod error;
+pub use error::Error;
+pub use error::Result;
 
 mod traits;
 pub use traits::Model;
@@ -8,24 +8,29 @@ use gnuplot::{Figure, AxesCommon, Caption, Color};
 
 use omoikane::Model;
 use omoikane::regression::LinearRegressionModel;
+use omoikane::regression::LinearRegressionSolver;
+use omoikane::optimization::GradientDescent;
+use omoikane::optimization::StoppingCriteria;
+use omoikane::optimization::optimizers::Adam;
+use omoikane::optimization::observers::LossCurvePlotter;
 use omoikane::datasets::nist_strd::linear_regression::norris;
 
 fn main() {
-    let inputs: Vec<f64> = norris().into_iter().map(|(x, _)| x.data().as_slice()[0]).collect();
-    let labels: Vec<f64> = norris().into_iter().map(|(_, y)| y).collect();
-    let mut model = LinearRegressionModel::new(0.000001, 200000);
+    let dataset = norris();
+    let inputs: Vec<f64> = dataset.inputs().data().clone();
+    let labels: Vec<f64> = dataset.labels().to_vec();
+    let gradient_descent = GradientDescent::new(Adam::new(0.1), StoppingCriteria::new(5000))
+                                           .with_observer(LossCurvePlotter::new("error.png"));
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::GradientDescent(gradient_descent));
     let mut figure = Figure::new();
 
-    model.fit_supervised_dataset(&inputs.iter()
-                                        .zip(labels.iter())
-                                        .map(|(x, y)| (vector!(*x), *y))
-                                        .collect());
+    model.fit_supervised_dataset(&dataset).unwrap();
 
     figure.set_terminal("png", "result.png")
           .axes2d()
           .set_x_label("x", &[])
           .set_y_label("y", &[])
           .lines(&inputs, labels, &[Caption("sample"), Color("blue")])
-          .lines(&inputs, inputs.iter().map(|x| model.predict(&vector!(*x))), &[Caption("prediction"), Color("red")]);
+          .lines(&inputs, inputs.iter().map(|x| model.predict(&vector!(*x)).unwrap()), &[Caption("prediction"), Color("red")]);
     figure.show();
 }
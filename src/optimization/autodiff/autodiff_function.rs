@@ -0,0 +1,131 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use optimization::ParametricFunction;
+use optimization::FunctionParameters;
+use super::{Dual, DifferentiableModel, Scalar};
+
+// ParametricFunction of a DifferentiableModel, whose exact parameter gradients and input gradient
+// are computed by evaluating the model with dual numbers.
+pub struct AutodiffFunction<M: DifferentiableModel> {
+    model: M,
+    input_size: usize,
+    parameters: FunctionParameters
+}
+
+impl<M: DifferentiableModel> AutodiffFunction<M> {
+    pub fn with_model(model: M, input_size: usize) -> AutodiffFunction<M> {
+        let parameters = FunctionParameters::new(Vector::zeros(model.parameters_size()));
+
+        AutodiffFunction { model, input_size, parameters }
+    }
+
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    fn check_input_size(&self, input: &Vector<f64>) -> Result<()> {
+        if input.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: input.size() });
+        }
+
+        Ok(())
+    }
+
+    fn variables(values: &[f64]) -> Vec<Dual> {
+        values.iter().enumerate().map(|(i, &value)| Dual::variable(value, i, values.len())).collect()
+    }
+
+    fn constants(values: &[f64]) -> Vec<Dual> {
+        values.iter().map(|&value| Dual::constant(value)).collect()
+    }
+
+    fn gradient(output: &Dual, size: usize) -> Vector<f64> {
+        (0..size).map(|i| output.derivative(i)).collect()
+    }
+}
+
+impl<M: DifferentiableModel> ParametricFunction for AutodiffFunction<M> {
+    fn parameters(&self) -> &Vector<f64> {
+        self.parameters.vector()
+    }
+
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
+        self.parameters.set_vector(new_parameters)
+    }
+
+    fn f(&self, input: &Vector<f64>) -> Result<f64> {
+        self.check_input_size(input)?;
+
+        Ok(self.model.evaluate(self.parameters.vector().data(), input.data()))
+    }
+
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        let output = self.model.evaluate(&Self::constants(self.parameters.vector().data()), &Self::variables(input.data()));
+
+        Ok(Self::gradient(&output, self.input_size))
+    }
+
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        let parameters = self.parameters.vector().data();
+        let output = self.model.evaluate(&Self::variables(parameters), &Self::constants(input.data()));
+
+        Ok(Self::gradient(&output, parameters.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutodiffFunction;
+    use Error;
+    use optimization::ParametricFunction;
+    use optimization::autodiff::{DifferentiableModel, Scalar};
+
+    // b1 (1 - exp(-b2 x1)) + b3 x1 x2
+    struct TestModel;
+
+    impl DifferentiableModel for TestModel {
+        fn parameters_size(&self) -> usize {
+            3
+        }
+
+        fn evaluate<S: Scalar>(&self, b: &[S], x: &[S]) -> S {
+            b[0].clone() * (S::constant(1.0) - (-(b[1].clone() * x[0].clone())).exp()) + b[2].clone() * x[0].clone() * x[1].clone()
+        }
+    }
+
+    #[test]
+    fn exact_gradients() {
+        let mut function = AutodiffFunction::with_model(TestModel, 2);
+
+        function.set_parameters(vector!(2.0, 0.5, -1.0)).unwrap();
+
+        let e = (-1.0f64).exp();
+        let input = vector!(2.0, 3.0);
+
+        assert_relative_eq!(function.f(&input).unwrap(), 2.0 * (1.0 - e) - 6.0);
+
+        let parameter_gradients = function.parameter_gradients(&input).unwrap();
+
+        assert_relative_eq!(parameter_gradients[0], 1.0 - e);
+        assert_relative_eq!(parameter_gradients[1], 2.0 * 2.0 * e);
+        assert_relative_eq!(parameter_gradients[2], 6.0);
+
+        let input_gradient = function.input_gradient(&input).unwrap();
+
+        assert_relative_eq!(input_gradient[0], 2.0 * 0.5 * e - 3.0);
+        assert_relative_eq!(input_gradient[1], -2.0);
+    }
+
+    #[test]
+    fn wrong_input_size() {
+        let function = AutodiffFunction::with_model(TestModel, 2);
+
+        assert_eq!(function.f(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+        assert_eq!(function.parameter_gradients(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+}
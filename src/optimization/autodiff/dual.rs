@@ -0,0 +1,243 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use super::Scalar;
+
+// Dual number: a value with its derivatives with respect to several variables, which arithmetic
+// propagates with the chain rule (forward-mode automatic differentiation). Constants have no
+// derivatives, which stands for derivatives that are all 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dual {
+    value: f64,
+    derivatives: Vec<f64>
+}
+
+impl Dual {
+    // The variable of the given index among `variable_count` variables.
+    pub fn variable(value: f64, index: usize, variable_count: usize) -> Dual {
+        let mut derivatives = vec![0.0; variable_count];
+
+        derivatives[index] = 1.0;
+
+        Dual { value, derivatives }
+    }
+
+    pub fn derivatives(&self) -> &[f64] {
+        &self.derivatives
+    }
+
+    pub fn derivative(&self, index: usize) -> f64 {
+        self.derivatives.get(index).cloned().unwrap_or(0.0)
+    }
+
+    // Applies a function of derivative `derivative` at the value.
+    fn chain(self, value: f64, derivative: f64) -> Dual {
+        Dual { value, derivatives: self.derivatives.into_iter().map(|d| derivative * d).collect() }
+    }
+
+    // Derivatives a da + b db of a binary operation.
+    fn combine(a: Vec<f64>, a_factor: f64, b: Vec<f64>, b_factor: f64) -> Vec<f64> {
+        if a.is_empty() {
+            return b.into_iter().map(|db| b_factor * db).collect();
+        }
+
+        if b.is_empty() {
+            return a.into_iter().map(|da| a_factor * da).collect();
+        }
+
+        if a.len() != b.len() {
+            panic!("Dual: combining derivatives with respect to {} and {} variables.", a.len(), b.len())
+        }
+
+        a.into_iter().zip(b).map(|(da, db)| a_factor * da + b_factor * db).collect()
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual { value: self.value + other.value, derivatives: Dual::combine(self.derivatives, 1.0, other.derivatives, 1.0) }
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual { value: self.value - other.value, derivatives: Dual::combine(self.derivatives, 1.0, other.derivatives, -1.0) }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value * other.value,
+            derivatives: Dual::combine(self.derivatives, other.value, other.derivatives, self.value)
+        }
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        Dual {
+            value: self.value / other.value,
+            derivatives: Dual::combine(self.derivatives, 1.0 / other.value, other.derivatives, -self.value / other.value.powi(2))
+        }
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        let value = -self.value;
+
+        self.chain(value, -1.0)
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Dual;
+
+    fn add(self, other: f64) -> Dual {
+        Dual { value: self.value + other, derivatives: self.derivatives }
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: f64) -> Dual {
+        Dual { value: self.value - other, derivatives: self.derivatives }
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: f64) -> Dual {
+        let value = self.value * other;
+
+        self.chain(value, other)
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Dual;
+
+    fn div(self, other: f64) -> Dual {
+        let value = self.value / other;
+
+        self.chain(value, 1.0 / other)
+    }
+}
+
+impl Scalar for Dual {
+    fn constant(value: f64) -> Dual {
+        Dual { value, derivatives: vec!() }
+    }
+
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn exp(&self) -> Dual {
+        let value = self.value.exp();
+
+        self.clone().chain(value, value)
+    }
+
+    fn ln(&self) -> Dual {
+        self.clone().chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    fn sqrt(&self) -> Dual {
+        let value = self.value.sqrt();
+
+        self.clone().chain(value, 0.5 / value)
+    }
+
+    fn powi(&self, exponent: i32) -> Dual {
+        let derivative = if exponent == 0 { 0.0 } else { f64::from(exponent) * self.value.powi(exponent - 1) };
+
+        self.clone().chain(self.value.powi(exponent), derivative)
+    }
+
+    fn powf(&self, exponent: f64) -> Dual {
+        self.clone().chain(self.value.powf(exponent), exponent * self.value.powf(exponent - 1.0))
+    }
+
+    fn sin(&self) -> Dual {
+        self.clone().chain(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(&self) -> Dual {
+        self.clone().chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn tanh(&self) -> Dual {
+        let value = self.value.tanh();
+
+        self.clone().chain(value, 1.0 - value.powi(2))
+    }
+
+    fn atan(&self) -> Dual {
+        self.clone().chain(self.value.atan(), 1.0 / (1.0 + self.value.powi(2)))
+    }
+
+    // The derivative at 0 is taken as 0.
+    fn abs(&self) -> Dual {
+        let sign = if self.value > 0.0 { 1.0 } else if self.value < 0.0 { -1.0 } else { 0.0 };
+
+        self.clone().chain(self.value.abs(), sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dual;
+    use super::super::Scalar;
+
+    #[test]
+    fn arithmetic() {
+        let x = Dual::variable(2.0, 0, 2);
+        let y = Dual::variable(3.0, 1, 2);
+
+        // x y + x / y - 2 x + 1
+        let z = x.clone() * y.clone() + x.clone() / y.clone() - x * 2.0 + 1.0;
+
+        assert_relative_eq!(z.value(), 6.0 + 2.0 / 3.0 - 3.0);
+        assert_relative_eq!(z.derivative(0), 3.0 + 1.0 / 3.0 - 2.0);
+        assert_relative_eq!(z.derivative(1), 2.0 - 2.0 / 9.0);
+    }
+
+    #[test]
+    fn constants_have_null_derivatives() {
+        let x = Dual::variable(2.0, 0, 1);
+        let z = Dual::constant(5.0) - x * Dual::constant(3.0);
+
+        assert_eq!(z.value(), -1.0);
+        assert_eq!(z.derivatives(), &[-3.0]);
+        assert_eq!(Dual::constant(5.0).derivative(0), 0.0);
+    }
+
+    #[test]
+    fn elementary_functions() {
+        let x = Dual::variable(0.5, 0, 1);
+
+        assert_relative_eq!(x.exp().derivative(0), 0.5f64.exp());
+        assert_relative_eq!(x.ln().derivative(0), 2.0);
+        assert_relative_eq!(x.sqrt().derivative(0), 0.5 / 0.5f64.sqrt());
+        assert_relative_eq!(x.powi(3).derivative(0), 0.75);
+        assert_relative_eq!(x.powf(1.5).derivative(0), 1.5 * 0.5f64.sqrt());
+        assert_relative_eq!(x.sin().derivative(0), 0.5f64.cos());
+        assert_relative_eq!(x.cos().derivative(0), -0.5f64.sin());
+        assert_relative_eq!(x.tanh().derivative(0), 1.0 - 0.5f64.tanh().powi(2));
+        assert_relative_eq!(x.atan().derivative(0), 0.8);
+        assert_relative_eq!((-x).abs().derivative(0), 1.0);
+    }
+}
@@ -0,0 +1,8 @@
+mod traits;
+mod dual;
+mod autodiff_function;
+
+pub use self::traits::Scalar;
+pub use self::traits::DifferentiableModel;
+pub use self::dual::Dual;
+pub use self::autodiff_function::AutodiffFunction;
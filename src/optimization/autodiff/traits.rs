@@ -0,0 +1,76 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+// Number a DifferentiableModel is evaluated with: f64 for values, Dual for derivatives.
+pub trait Scalar: Clone
+                  + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+                  + Add<f64, Output = Self> + Sub<f64, Output = Self> + Mul<f64, Output = Self> + Div<f64, Output = Self> {
+    fn constant(value: f64) -> Self;
+    fn value(&self) -> f64;
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn sqrt(&self) -> Self;
+    fn powi(&self, exponent: i32) -> Self;
+    fn powf(&self, exponent: f64) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tanh(&self) -> Self;
+    fn atan(&self) -> Self;
+    fn abs(&self) -> Self;
+}
+
+// Function of the parameters and the inputs written once for any Scalar, so that AutodiffFunction
+// derives its gradients from it.
+pub trait DifferentiableModel {
+    fn parameters_size(&self) -> usize;
+    fn evaluate<S: Scalar>(&self, parameters: &[S], input: &[S]) -> S;
+}
+
+impl Scalar for f64 {
+    fn constant(value: f64) -> f64 {
+        value
+    }
+
+    fn value(&self) -> f64 {
+        *self
+    }
+
+    fn exp(&self) -> f64 {
+        f64::exp(*self)
+    }
+
+    fn ln(&self) -> f64 {
+        f64::ln(*self)
+    }
+
+    fn sqrt(&self) -> f64 {
+        f64::sqrt(*self)
+    }
+
+    fn powi(&self, exponent: i32) -> f64 {
+        f64::powi(*self, exponent)
+    }
+
+    fn powf(&self, exponent: f64) -> f64 {
+        f64::powf(*self, exponent)
+    }
+
+    fn sin(&self) -> f64 {
+        f64::sin(*self)
+    }
+
+    fn cos(&self) -> f64 {
+        f64::cos(*self)
+    }
+
+    fn tanh(&self) -> f64 {
+        f64::tanh(*self)
+    }
+
+    fn atan(&self) -> f64 {
+        f64::atan(*self)
+    }
+
+    fn abs(&self) -> f64 {
+        f64::abs(*self)
+    }
+}
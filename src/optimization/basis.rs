@@ -0,0 +1,173 @@
+// Common basis functions for `BasisExpansionFunction`.
+use std::f64;
+
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+
+type BasisValue = Box<dyn Fn(&Vector<f64>) -> f64>;
+type BasisGradient = Box<dyn Fn(&Vector<f64>) -> Vector<f64>>;
+
+// A function of the inputs, with its gradient with respect to the inputs when it is known.
+pub struct BasisFunction {
+    value: BasisValue,
+    gradient: Option<BasisGradient>,
+    // inputs must have the index of the input used, or the size of the center
+    min_input_size: usize,
+    exact_input_size: Option<usize>
+}
+
+impl BasisFunction {
+    pub fn new<V>(value: V) -> BasisFunction
+    where V: Fn(&Vector<f64>) -> f64 + 'static {
+        BasisFunction { value: Box::new(value), gradient: None, min_input_size: 0, exact_input_size: None }
+    }
+
+    pub fn with_gradient<G>(mut self, gradient: G) -> BasisFunction
+    where G: Fn(&Vector<f64>) -> Vector<f64> + 'static {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    pub fn value(&self, input: &Vector<f64>) -> f64 {
+        (self.value)(input)
+    }
+
+    // None when the basis function has no gradient.
+    pub fn gradient(&self, input: &Vector<f64>) -> Option<Vector<f64>> {
+        self.gradient.as_ref().map(|gradient| gradient(input))
+    }
+
+    // Fails when inputs of that size do not match the center, or miss the input of the basis function.
+    pub fn check_input_size(&self, input_size: usize) -> Result<()> {
+        if let Some(expected) = self.exact_input_size {
+            if input_size != expected {
+                return Err(Error::DimensionMismatch { expected, actual: input_size });
+            }
+        }
+        if input_size < self.min_input_size {
+            return Err(Error::DimensionMismatch { expected: self.min_input_size, actual: input_size });
+        }
+
+        Ok(())
+    }
+
+    fn with_input_index(mut self, input_index: usize) -> BasisFunction {
+        self.min_input_size = input_index + 1;
+        self
+    }
+
+    fn with_input_size(mut self, input_size: usize) -> BasisFunction {
+        self.exact_input_size = Some(input_size);
+        self
+    }
+}
+
+// Gradient that is 0 except for one input.
+fn one_input_gradient(input: &Vector<f64>, input_index: usize, derivative: f64) -> Vector<f64> {
+    let mut gradient = Vector::zeros(input.size());
+
+    gradient[input_index] = derivative;
+    gradient
+}
+
+pub fn constant() -> BasisFunction {
+    BasisFunction::new(|_| 1.0).with_gradient(|input| Vector::zeros(input.size()))
+}
+
+// Gaussian radial basis function exp(-||x - center||^2 / (2 width^2)).
+pub fn rbf(center: Vector<f64>, width: f64) -> BasisFunction {
+    let input_size = center.size();
+    let gradient_center = center.clone();
+    let value = move |input: &Vector<f64>, center: &Vector<f64>| {
+        let distance = input - center;
+
+        (-distance.dot(&distance) / (2.0 * width.powi(2))).exp()
+    };
+
+    BasisFunction::new(move |input| value(input, &center))
+        .with_gradient(move |input| (input - &gradient_center) * (-value(input, &gradient_center) / width.powi(2)))
+        .with_input_size(input_size)
+}
+
+// sin(2 pi frequency x / period) of one input.
+pub fn fourier_sine(input_index: usize, frequency: f64, period: f64) -> BasisFunction {
+    let angular_frequency = 2.0 * f64::consts::PI * frequency / period;
+
+    BasisFunction::new(move |input| (angular_frequency * input[input_index]).sin())
+        .with_gradient(move |input| {
+            one_input_gradient(input, input_index, angular_frequency * (angular_frequency * input[input_index]).cos())
+        })
+        .with_input_index(input_index)
+}
+
+// cos(2 pi frequency x / period) of one input.
+pub fn fourier_cosine(input_index: usize, frequency: f64, period: f64) -> BasisFunction {
+    let angular_frequency = 2.0 * f64::consts::PI * frequency / period;
+
+    BasisFunction::new(move |input| (angular_frequency * input[input_index]).cos())
+        .with_gradient(move |input| {
+            one_input_gradient(input, input_index, -angular_frequency * (angular_frequency * input[input_index]).sin())
+        })
+        .with_input_index(input_index)
+}
+
+// max(0, x - knot)^degree of one input. Together with 1, x, ..., x^degree, truncated powers at a
+// set of knots span the splines of that degree.
+pub fn truncated_power(input_index: usize, knot: f64, degree: i32) -> BasisFunction {
+    BasisFunction::new(move |input| (input[input_index] - knot).max(0.0).powi(degree))
+        .with_gradient(move |input| {
+            let derivative = if input[input_index] > knot && degree != 0 {
+                f64::from(degree) * (input[input_index] - knot).powi(degree - 1)
+            } else {
+                0.0
+            };
+
+            one_input_gradient(input, input_index, derivative)
+        })
+        .with_input_index(input_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::vector::Vector;
+    use std::f64::consts::PI;
+
+    use Error;
+    use super::{BasisFunction, constant, rbf, fourier_sine, fourier_cosine, truncated_power};
+
+    #[test]
+    fn basis_functions() {
+        assert_eq!(constant().value(&vector!(3.0)), 1.0);
+        assert_relative_eq!(rbf(vector!(1.0, 1.0), 2.0).value(&vector!(3.0, 1.0)), (-0.5f64).exp());
+        assert_relative_eq!(fourier_sine(1, 1.0, 4.0).value(&vector!(0.0, 1.0)), 1.0);
+        assert_relative_eq!(fourier_cosine(0, 2.0, 4.0).value(&vector!(1.0, 0.0)), -1.0);
+        assert_eq!(truncated_power(0, 1.0, 3).value(&vector!(3.0)), 8.0);
+        assert_eq!(truncated_power(0, 1.0, 3).value(&vector!(0.0)), 0.0);
+    }
+
+    #[test]
+    fn basis_function_gradients() {
+        assert_eq!(constant().gradient(&vector!(3.0, 4.0)), Some(vector!(0.0, 0.0)));
+        assert_eq!(truncated_power(1, 1.0, 3).gradient(&vector!(0.0, 3.0)), Some(vector!(0.0, 12.0)));
+        assert_eq!(truncated_power(0, 1.0, 3).gradient(&vector!(0.0)), Some(vector!(0.0)));
+        assert_eq!(BasisFunction::new(|x: &Vector<f64>| x[0]).gradient(&vector!(1.0)), None);
+
+        let gradient = rbf(vector!(1.0, 1.0), 2.0).gradient(&vector!(3.0, 1.0)).unwrap();
+
+        assert_relative_eq!(gradient[0], -0.5 * (-0.5f64).exp());
+        assert_relative_eq!(gradient[1], 0.0);
+        assert_relative_eq!(fourier_sine(1, 1.0, 4.0).gradient(&vector!(0.0, 0.0)).unwrap()[1], PI / 2.0);
+        assert_relative_eq!(fourier_cosine(0, 1.0, 4.0).gradient(&vector!(1.0)).unwrap()[0], -PI / 2.0);
+    }
+
+    #[test]
+    fn check_input_size() {
+        assert_eq!(constant().check_input_size(3), Ok(()));
+        assert_eq!(rbf(vector!(1.0, 1.0), 2.0).check_input_size(2), Ok(()));
+        assert_eq!(rbf(vector!(1.0, 1.0), 2.0).check_input_size(3), Err(Error::DimensionMismatch { expected: 2, actual: 3 }));
+        assert_eq!(fourier_sine(1, 1.0, 4.0).check_input_size(3), Ok(()));
+        assert_eq!(fourier_cosine(2, 1.0, 4.0).check_input_size(2), Err(Error::DimensionMismatch { expected: 3, actual: 2 }));
+        assert_eq!(truncated_power(0, 1.0, 3).check_input_size(0), Err(Error::DimensionMismatch { expected: 1, actual: 0 }));
+    }
+}
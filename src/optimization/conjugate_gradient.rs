@@ -0,0 +1,173 @@
+use rulinalg::vector::Vector;
+
+use Result;
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::ConjugateGradient;
+use super::ConjugateGradientMethod;
+use super::FitReport;
+use super::StoppingCriteria;
+use super::LineSearch;
+use super::TrainingObserver;
+use super::gradient_descent::norm;
+use super::line_search_fit::{SearchDirection, LineSearchSettings, line_search_fit};
+
+struct ConjugateDirection {
+    method: ConjugateGradientMethod,
+    // gradients and direction of the previous iteration
+    previous: Option<(Vector<f64>, Vector<f64>)>,
+    iterations: usize
+}
+
+impl SearchDirection for ConjugateDirection {
+    fn direction(&mut self, gradients: &Vector<f64>) -> Vector<f64> {
+        let direction = match self.previous {
+            // the first step is at most of unit length
+            None => -gradients / norm(gradients).max(1.0),
+            // restarts along the gradients every n iterations, n being the number of parameters
+            Some(_) if self.iterations.is_multiple_of(gradients.size().max(1)) => -gradients,
+            Some((ref previous_gradients, ref previous_direction)) => {
+                let previous_norm = previous_gradients.dot(previous_gradients);
+                let beta = match self.method {
+                    ConjugateGradientMethod::FletcherReeves => gradients.dot(gradients) / previous_norm,
+                    ConjugateGradientMethod::PolakRibiere => {
+                        f64::max(0.0, gradients.dot(&(gradients - previous_gradients)) / previous_norm)
+                    }
+                };
+
+                previous_direction * beta - gradients
+            }
+        };
+
+        self.previous = Some((gradients.clone(), direction.clone()));
+        self.iterations += 1;
+
+        direction
+    }
+
+    fn update(&mut self, _step: &Vector<f64>, _gradient_changes: &Vector<f64>) {}
+
+    fn reset(&mut self) {
+        self.previous = None;
+        self.iterations = 0;
+    }
+}
+
+impl LineSearchSettings for ConjugateGradient {
+    fn stopping_criteria(&self) -> &StoppingCriteria {
+        ConjugateGradient::stopping_criteria(self)
+    }
+
+    fn line_search(&self) -> LineSearch {
+        ConjugateGradient::line_search(self)
+    }
+
+    fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>] {
+        ConjugateGradient::observers_mut(self)
+    }
+}
+
+// Nonlinear conjugate gradient fit, searching along directions mixing the gradients with the previous
+// direction. Every iteration runs over the whole dataset.
+pub fn conjugate_gradient_fit<F, A, G>(dataset: &SupervisedDataset<f64>,
+                                       parametric_function: &mut F,
+                                       compute_error_average: &A,
+                                       compute_error_gradients: &G,
+                                       settings: &mut ConjugateGradient) -> Result<FitReport>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    let mut search_direction = ConjugateDirection { method: settings.method(), previous: None, iterations: 0 };
+
+    line_search_fit(dataset,
+                    parametric_function,
+                    compute_error_average,
+                    compute_error_gradients,
+                    &mut search_direction,
+                    settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::vector::Vector;
+
+    use Result;
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::ConjugateGradient;
+    use optimization::ConjugateGradientMethod;
+    use optimization::StoppingCriteria;
+    use optimization::StopReason;
+    use optimization::line_search_fit::SearchDirection;
+
+    use super::ConjugateDirection;
+    use super::conjugate_gradient_fit;
+
+    // (b0 - 1)^2 + 100 (b1 - 2)^2
+    fn loss(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<f64> {
+        let parameters = function.parameters();
+
+        Ok((parameters[0] - 1.0).powi(2) + 100.0 * (parameters[1] - 2.0).powi(2))
+    }
+
+    fn gradients(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+        let parameters = function.parameters();
+
+        Ok(vector!(2.0 * (parameters[0] - 1.0), 200.0 * (parameters[1] - 2.0)))
+    }
+
+    fn fit(method: ConjugateGradientMethod) {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let mut settings = ConjugateGradient::new(method, StoppingCriteria::new(100).with_gradient_tolerance(1e-10));
+        let report = conjugate_gradient_fit(&dataset, &mut function, &loss, &gradients, &mut settings).unwrap();
+
+        assert_eq!(report.stop_reason(), StopReason::GradientTolerance);
+        assert!(report.iterations() < 30);
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn fletcher_reeves_fit_ill_conditioned_quadratic() {
+        fit(ConjugateGradientMethod::FletcherReeves);
+    }
+
+    #[test]
+    fn polak_ribiere_fit_ill_conditioned_quadratic() {
+        fit(ConjugateGradientMethod::PolakRibiere);
+    }
+
+    #[test]
+    fn mix_previous_direction() {
+        let mut fletcher_reeves = ConjugateDirection { method: ConjugateGradientMethod::FletcherReeves, previous: None, iterations: 0 };
+        let mut polak_ribiere = ConjugateDirection { method: ConjugateGradientMethod::PolakRibiere, previous: None, iterations: 0 };
+
+        // the first direction is scaled down to unit length
+        assert_eq!(fletcher_reeves.direction(&vector!(0.0, 2.0, 0.0)), vector!(0.0, -1.0, 0.0));
+        assert_eq!(polak_ribiere.direction(&vector!(0.0, 2.0, 0.0)), vector!(0.0, -1.0, 0.0));
+
+        // beta = 8 / 4 = 2 for Fletcher-Reeves and 4 / 4 = 1 for Polak-Ribiere
+        assert_eq!(fletcher_reeves.direction(&vector!(2.0, 2.0, 0.0)), vector!(-2.0, -4.0, 0.0));
+        assert_eq!(polak_ribiere.direction(&vector!(2.0, 2.0, 0.0)), vector!(-2.0, -3.0, 0.0));
+    }
+
+    #[test]
+    fn restart_polak_ribiere_on_negative_factor() {
+        let mut polak_ribiere = ConjugateDirection { method: ConjugateGradientMethod::PolakRibiere, previous: None, iterations: 0 };
+
+        polak_ribiere.direction(&vector!(0.0, 2.0, 0.0));
+
+        // beta = -2 / 4, clamped to 0
+        assert_eq!(polak_ribiere.direction(&vector!(0.0, 1.0, 0.0)), vector!(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn direction_without_parameters() {
+        let mut fletcher_reeves = ConjugateDirection { method: ConjugateGradientMethod::FletcherReeves, previous: None, iterations: 0 };
+
+        assert_eq!(fletcher_reeves.direction(&vector!()), vector!());
+        assert_eq!(fletcher_reeves.direction(&vector!()), vector!());
+    }
+}
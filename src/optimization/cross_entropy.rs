@@ -0,0 +1,375 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::SoftmaxFunction;
+use super::Loss;
+use super::GradientDescent;
+use super::FitReport;
+use super::gradient_descent_fit;
+use super::mean_loss;
+use super::mean_loss_gradients;
+use super::losses::BinaryCrossEntropy;
+
+pub fn sigmoid(logit: f64) -> f64 {
+    if logit >= 0.0 {
+        1.0 / (1.0 + (-logit).exp())
+    } else {
+        let exponential = logit.exp();
+
+        exponential / (1.0 + exponential)
+    }
+}
+
+// log(1 + e^z), without overflowing for large logits.
+pub fn softplus(logit: f64) -> f64 {
+    logit.max(0.0) + (-logit.abs()).exp().ln_1p()
+}
+
+fn log_sum_exp(logits: &Vector<f64>) -> f64 {
+    let max = logits.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    max + logits.iter().map(|logit| (logit - max).exp()).sum::<f64>().ln()
+}
+
+fn class_index(label: f64, class_count: usize) -> Result<usize> {
+    if label < 0.0 || label.fract() != 0.0 || label as usize >= class_count {
+        return Err(Error::InvalidLabel(label));
+    }
+
+    Ok(label as usize)
+}
+
+// Weight of a class, all classes weighing 1 when no weights are given.
+fn class_weight(class_weights: &[f64], class: usize) -> f64 {
+    class_weights.get(class).cloned().unwrap_or(1.0)
+}
+
+fn check_class_weights(class_weights: &[f64], class_count: usize) -> Result<()> {
+    if !class_weights.is_empty() && class_weights.len() != class_count {
+        return Err(Error::InvalidSetting(format!("{} class weights given for {} classes", class_weights.len(), class_count)));
+    }
+
+    Ok(())
+}
+
+// Loss of each sample weighted by the class of its target, every class weighing 1 when no weights
+// are given.
+struct ClassWeighted<'a, L> {
+    loss: L,
+    class_weights: &'a [f64]
+}
+
+impl<'a, L: Loss> Loss for ClassWeighted<'a, L> {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        class_weight(self.class_weights, target as usize) * self.loss.value(prediction, target)
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        class_weight(self.class_weights, target as usize) * self.loss.gradient(prediction, target)
+    }
+}
+
+fn check_labels(dataset: &SupervisedDataset<f64>, class_count: usize) -> Result<()> {
+    for &label in dataset.labels() {
+        class_index(label, class_count)?;
+    }
+
+    Ok(())
+}
+
+// Average binary cross-entropy of a function giving the logit of the probability that the label is 1,
+// weighted by the sample and class weights.
+fn compute_cross_entropy_average<F>(function: &F, dataset: &SupervisedDataset<f64>, class_weights: &[f64]) -> Result<f64>
+where F: ParametricFunction + ?Sized {
+    check_labels(dataset, 2)?;
+
+    mean_loss(function, dataset, &ClassWeighted { loss: BinaryCrossEntropy, class_weights })
+}
+
+fn compute_cross_entropy_gradients<F>(function: &F, dataset: &SupervisedDataset<f64>, class_weights: &[f64]) -> Result<Vector<f64>>
+where F: ParametricFunction + ?Sized {
+    check_labels(dataset, 2)?;
+
+    mean_loss_gradients(function, dataset, &ClassWeighted { loss: BinaryCrossEntropy, class_weights })
+}
+
+fn compute_softmax_cross_entropy_average(function: &SoftmaxFunction,
+                                         dataset: &SupervisedDataset<f64>,
+                                         class_weights: &[f64]) -> Result<f64> {
+    let n = dataset.total_weight();
+    let mut loss_sum = 0.0;
+
+    if n == 0.0 {
+        return Ok(0.0);
+    }
+
+    for (x, &y, weight) in dataset.weighted_iter() {
+        let class = class_index(y, function.class_count())?;
+        let logits = function.logits(&x)?;
+
+        loss_sum += weight * class_weight(class_weights, class) * (log_sum_exp(&logits) - logits[class]);
+    }
+
+    Ok(loss_sum / n)
+}
+
+fn compute_softmax_cross_entropy_gradients(function: &SoftmaxFunction,
+                                           dataset: &SupervisedDataset<f64>,
+                                           class_weights: &[f64]) -> Result<Vector<f64>> {
+    let n = dataset.total_weight();
+    let block_size = function.input_size() + 1;
+    let mut gradients = vec![0.0; function.parameters().size()];
+
+    if n == 0.0 {
+        return Ok(Vector::new(gradients));
+    }
+
+    for (x, &y, sample_weight) in dataset.weighted_iter() {
+        let class = class_index(y, function.class_count())?;
+        let probabilities = function.probabilities(&x)?;
+        let weight = sample_weight * class_weight(class_weights, class) / n;
+
+        for (k, probability) in probabilities.iter().enumerate() {
+            let error = if k == class { probability - 1.0 } else { *probability };
+            let block = &mut gradients[k * block_size..(k + 1) * block_size];
+
+            block[0] += weight * error;
+
+            for (gradient, feature) in block[1..].iter_mut().zip(x.iter()) {
+                *gradient += weight * error * feature;
+            }
+        }
+    }
+
+    Ok(Vector::new(gradients))
+}
+
+// Binary logistic fit of a function giving the logit of the probability that the label is 1, the
+// labels being 0 or 1. `class_weights` holds the weights of both classes, or is empty to weigh
+// them equally.
+pub fn cross_entropy_fit<F>(function: &mut F,
+                            dataset: &SupervisedDataset<f64>,
+                            class_weights: &[f64],
+                            settings: &mut GradientDescent) -> Result<FitReport>
+where F: ParametricFunction + ?Sized {
+    check_class_weights(class_weights, 2)?;
+
+    gradient_descent_fit(dataset,
+                         function,
+                         &|function: &F, dataset: &SupervisedDataset<f64>| compute_cross_entropy_average(function, dataset, class_weights),
+                         &|function: &F, dataset: &SupervisedDataset<f64>| compute_cross_entropy_gradients(function, dataset, class_weights),
+                         settings)
+}
+
+// Multinomial logistic fit, the labels being class indices. `class_weights` holds the weight of
+// each class, or is empty to weigh them equally.
+pub fn softmax_cross_entropy_fit(function: &mut SoftmaxFunction,
+                                 dataset: &SupervisedDataset<f64>,
+                                 class_weights: &[f64],
+                                 settings: &mut GradientDescent) -> Result<FitReport> {
+    check_class_weights(class_weights, function.class_count())?;
+
+    gradient_descent_fit(dataset,
+                         function,
+                         &|function: &SoftmaxFunction, dataset: &SupervisedDataset<f64>| {
+                             compute_softmax_cross_entropy_average(function, dataset, class_weights)
+                         },
+                         &|function: &SoftmaxFunction, dataset: &SupervisedDataset<f64>| {
+                             compute_softmax_cross_entropy_gradients(function, dataset, class_weights)
+                         },
+                         settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    use rulinalg::vector::Vector;
+
+    use Error;
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::SoftmaxFunction;
+    use optimization::GradientDescent;
+    use optimization::StoppingCriteria;
+    use optimization::optimizers::Sgd;
+
+    use super::sigmoid;
+    use super::softplus;
+    use super::compute_cross_entropy_average;
+    use super::compute_cross_entropy_gradients;
+    use super::compute_softmax_cross_entropy_average;
+    use super::compute_softmax_cross_entropy_gradients;
+    use super::cross_entropy_fit;
+    use super::softmax_cross_entropy_fit;
+
+    fn build_binary_dataset() -> SupervisedDataset<f64> {
+        SupervisedDataset::from_samples(vec!((vector!(-2.0), 0.0),
+                                             (vector!(-1.0), 0.0),
+                                             (vector!(0.5), 0.0),
+                                             (vector!(-0.5), 1.0),
+                                             (vector!(1.0), 1.0),
+                                             (vector!(2.0), 1.0))).unwrap()
+    }
+
+    fn build_multiclass_dataset() -> SupervisedDataset<f64> {
+        SupervisedDataset::from_samples(vec!((vector!(-2.0), 0.0),
+                                             (vector!(-1.0), 0.0),
+                                             (vector!(0.0), 1.0),
+                                             (vector!(0.5), 0.0),
+                                             (vector!(1.0), 2.0),
+                                             (vector!(2.0), 2.0))).unwrap()
+    }
+
+    // Central finite differences of the loss.
+    fn numerical_gradients<F, L>(function: &mut F, loss: L) -> Vector<f64>
+    where F: ParametricFunction + ?Sized,
+          L: Fn(&F) -> f64 {
+        let parameters = function.parameters().clone();
+        let mut gradients = vec!();
+
+        for i in 0..parameters.size() {
+            let mut shifted = parameters.clone();
+
+            shifted[i] += 1e-6;
+            function.set_parameters(shifted.clone()).unwrap();
+
+            let forward = loss(function);
+
+            shifted[i] -= 2e-6;
+            function.set_parameters(shifted).unwrap();
+            gradients.push((forward - loss(function)) / 2e-6);
+        }
+
+        function.set_parameters(parameters).unwrap();
+
+        Vector::new(gradients)
+    }
+
+    #[test]
+    fn sigmoid_and_softplus_of_large_logits() {
+        assert_eq!(sigmoid(0.0), 0.5);
+        assert_eq!(sigmoid(1000.0), 1.0);
+        assert_eq!(sigmoid(-1000.0), 0.0);
+        assert_eq!(softplus(1000.0), 1000.0);
+        assert_eq!(softplus(-1000.0), 0.0);
+    }
+
+    #[test]
+    fn compute_cross_entropy_average_of_null_function() {
+        let function = LinearFunction::new(1);
+
+        assert_relative_eq!(compute_cross_entropy_average(&function, &build_binary_dataset(), &[]).unwrap(), f64::consts::LN_2);
+        // the first class weighs 3 times more, the second class half
+        assert_relative_eq!(compute_cross_entropy_average(&function, &build_binary_dataset(), &[3.0, 0.5]).unwrap(),
+                            1.75 * f64::consts::LN_2);
+    }
+
+    #[test]
+    fn compute_cross_entropy_gradients_match_finite_differences() {
+        let mut function = LinearFunction::new(1);
+        let dataset = build_binary_dataset();
+
+        function.set_parameters(vector!(0.3, -0.7)).unwrap();
+
+        let gradients = compute_cross_entropy_gradients(&function, &dataset, &[3.0, 0.5]).unwrap();
+        let expected = numerical_gradients(&mut function, |f| compute_cross_entropy_average(f, &dataset, &[3.0, 0.5]).unwrap());
+
+        for (gradient, expected) in gradients.iter().zip(expected.iter()) {
+            assert_relative_eq!(gradient, expected, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn compute_softmax_cross_entropy_average_of_null_function() {
+        let function = SoftmaxFunction::with_class_count(1, 3);
+
+        assert_relative_eq!(compute_softmax_cross_entropy_average(&function, &build_multiclass_dataset(), &[]).unwrap(),
+                            3.0f64.ln());
+    }
+
+    #[test]
+    fn compute_softmax_cross_entropy_gradients_match_finite_differences() {
+        let mut function = SoftmaxFunction::with_class_count(1, 3);
+        let dataset = build_multiclass_dataset();
+        let class_weights = [1.0, 2.0, 0.5];
+
+        function.set_parameters(vector!(0.1, -0.2, 0.3, 0.4, -0.5, 0.6)).unwrap();
+
+        let gradients = compute_softmax_cross_entropy_gradients(&function, &dataset, &class_weights).unwrap();
+        let expected = numerical_gradients(&mut function,
+                                           |f| compute_softmax_cross_entropy_average(f, &dataset, &class_weights).unwrap());
+
+        for (gradient, expected) in gradients.iter().zip(expected.iter()) {
+            assert_relative_eq!(gradient, expected, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn cross_entropy_fit_decreases_loss() {
+        let mut function = LinearFunction::new(1);
+        let mut settings = GradientDescent::new(Sgd::new(1.0), StoppingCriteria::new(100));
+        let report = cross_entropy_fit(&mut function, &build_binary_dataset(), &[], &mut settings).unwrap();
+
+        assert!(report.final_loss() < report.losses()[0]);
+        assert!(function.parameters()[1] > 0.0);
+    }
+
+    #[test]
+    fn cross_entropy_fit_invalid_labels() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 2.0))).unwrap();
+        let mut settings = GradientDescent::new(Sgd::new(1.0), StoppingCriteria::new(100));
+
+        assert_eq!(cross_entropy_fit(&mut function, &dataset, &[], &mut settings).err(), Some(Error::InvalidLabel(2.0)));
+    }
+
+    #[test]
+    fn softmax_cross_entropy_fit_wrong_number_of_class_weights() {
+        let mut function = SoftmaxFunction::with_class_count(1, 3);
+        let mut settings = GradientDescent::new(Sgd::new(1.0), StoppingCriteria::new(100));
+
+        assert_eq!(softmax_cross_entropy_fit(&mut function, &build_multiclass_dataset(), &[1.0, 2.0], &mut settings).err(),
+                   Some(Error::InvalidSetting("2 class weights given for 3 classes".to_string())));
+    }
+
+    #[test]
+    fn cross_entropy_weighted_as_repeated_samples() {
+        let mut function = SoftmaxFunction::with_class_count(1, 3);
+        let weighted_dataset = build_multiclass_dataset().with_weights(vec!(2.0, 1.0, 0.0, 3.0, 1.0, 1.0)).unwrap();
+        let repeated_dataset = build_multiclass_dataset().select(&[0, 0, 1, 3, 3, 3, 4, 5]);
+
+        function.set_parameters(vector!(0.1, -0.5, 0.2, 0.3, -0.1, 0.4)).unwrap();
+
+        assert_relative_eq!(compute_softmax_cross_entropy_average(&function, &weighted_dataset, &[1.0, 2.0, 1.0]).unwrap(),
+                            compute_softmax_cross_entropy_average(&function, &repeated_dataset, &[1.0, 2.0, 1.0]).unwrap(),
+                            epsilon = 1e-12);
+
+        let gradients = compute_softmax_cross_entropy_gradients(&function, &weighted_dataset, &[1.0, 2.0, 1.0]).unwrap();
+        let expected = compute_softmax_cross_entropy_gradients(&function, &repeated_dataset, &[1.0, 2.0, 1.0]).unwrap();
+
+        for (gradient, expected) in gradients.iter().zip(expected.iter()) {
+            assert_relative_eq!(gradient, expected, epsilon = 1e-12);
+        }
+
+        let mut function = LinearFunction::new(1);
+        let weighted_dataset = build_binary_dataset().with_weights(vec!(1.0, 0.0, 2.0, 1.0, 3.0, 1.0)).unwrap();
+        let repeated_dataset = build_binary_dataset().select(&[0, 2, 2, 3, 4, 4, 4, 5]);
+
+        function.set_parameters(vector!(0.2, 0.7)).unwrap();
+
+        assert_relative_eq!(compute_cross_entropy_average(&function, &weighted_dataset, &[]).unwrap(),
+                            compute_cross_entropy_average(&function, &repeated_dataset, &[]).unwrap(),
+                            epsilon = 1e-12);
+
+        let gradients = compute_cross_entropy_gradients(&function, &weighted_dataset, &[]).unwrap();
+        let expected = compute_cross_entropy_gradients(&function, &repeated_dataset, &[]).unwrap();
+
+        for (gradient, expected) in gradients.iter().zip(expected.iter()) {
+            assert_relative_eq!(gradient, expected, epsilon = 1e-12);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Instant;
+
+use rulinalg::vector::Vector;
+
+use Result;
+use super::TrainingObserver;
+use super::StoppingCriteria;
+use super::FitReport;
+use super::StopReason;
+use super::IterationEvent;
+
+// Losses of an iterative fit so far, checked against the stopping criteria before and after every
+// iteration. Every fitter notifies the observers and builds its report through it.
+pub struct FitProgress {
+    stopping_criteria: StoppingCriteria,
+    start: Instant,
+    losses: Vec<f64>
+}
+
+impl FitProgress {
+    pub fn new(stopping_criteria: &StoppingCriteria, initial_loss: f64) -> FitProgress {
+        FitProgress {
+            stopping_criteria: stopping_criteria.clone(),
+            start: Instant::now(),
+            losses: vec!(initial_loss)
+        }
+    }
+
+    pub fn iterations(&self) -> u32 {
+        (self.losses.len() - 1) as u32
+    }
+
+    // The initial loss followed by the loss after every iteration.
+    pub fn losses(&self) -> &[f64] {
+        &self.losses
+    }
+
+    pub fn loss(&self) -> f64 {
+        self.losses[self.losses.len() - 1]
+    }
+
+    // Reason to stop before running another iteration, if any.
+    pub fn stop_reason_before_iteration(&self) -> Option<StopReason> {
+        if self.stopping_criteria.loss_tolerance().is_some_and(|tolerance| self.loss() <= tolerance) {
+            return Some(StopReason::LossTolerance);
+        }
+        if self.iterations() >= self.stopping_criteria.max_iterations() {
+            return Some(StopReason::MaxIterations);
+        }
+        if self.stopping_criteria.time_budget().is_some_and(|budget| self.start.elapsed() >= budget) {
+            return Some(StopReason::TimeBudget);
+        }
+
+        None
+    }
+
+    // Records the loss after an iteration and notifies the observers.
+    pub fn record_iteration(&mut self,
+                            loss: f64,
+                            parameters: &Vector<f64>,
+                            gradient_norm: f64,
+                            observers: &mut [Box<dyn TrainingObserver>]) -> Result<()> {
+        self.losses.push(loss);
+
+        for observer in observers.iter_mut() {
+            observer.on_iteration(&IterationEvent::new(self.iterations(), loss, parameters, gradient_norm))?;
+        }
+
+        Ok(())
+    }
+
+    // Reason to stop after the last recorded iteration, given the norm of the loss gradients (when
+    // the fitter has them) and the norm of the iteration step.
+    pub fn stop_reason_after_iteration(&self, gradient_norm: Option<f64>, step_norm: f64) -> Option<StopReason> {
+        let loss = self.loss();
+        let previous_loss = self.losses[self.losses.len() - 2];
+        let gradient_tolerance_reached = match (gradient_norm, self.stopping_criteria.gradient_tolerance()) {
+            (Some(gradient_norm), Some(tolerance)) => gradient_norm <= tolerance,
+            _ => false
+        };
+
+        if gradient_tolerance_reached {
+            return Some(StopReason::GradientTolerance);
+        }
+        if self.stopping_criteria.parameter_tolerance().is_some_and(|tolerance| step_norm <= tolerance) {
+            return Some(StopReason::ParameterTolerance);
+        }
+        if self.stopping_criteria.relative_loss_tolerance()
+                                 .is_some_and(|tolerance| (previous_loss - loss).abs() <= tolerance * previous_loss.abs()) {
+            return Some(StopReason::RelativeLossTolerance);
+        }
+
+        None
+    }
+
+    // Report of the fit, which the observers are notified of.
+    pub fn finish(self, stop_reason: StopReason, observers: &mut [Box<dyn TrainingObserver>]) -> Result<FitReport> {
+        let report = FitReport::new(stop_reason, self.iterations(), self.losses);
+
+        for observer in observers.iter_mut() {
+            observer.on_finish(&report)?;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::StoppingCriteria;
+    use optimization::StopReason;
+
+    use super::FitProgress;
+
+    #[test]
+    fn stop_reasons() {
+        let stopping_criteria = StoppingCriteria::new(2).with_loss_tolerance(0.5)
+                                                        .with_gradient_tolerance(1e-3)
+                                                        .with_parameter_tolerance(1e-6)
+                                                        .with_relative_loss_tolerance(0.1);
+        let mut progress = FitProgress::new(&stopping_criteria, 4.0);
+
+        assert_eq!(progress.stop_reason_before_iteration(), None);
+        progress.record_iteration(2.0, &vector!(1.0), 1.0, &mut []).unwrap();
+        assert_eq!(progress.iterations(), 1);
+        assert_eq!(progress.stop_reason_after_iteration(Some(1.0), 1.0), None);
+        assert_eq!(progress.stop_reason_after_iteration(Some(1e-4), 1.0), Some(StopReason::GradientTolerance));
+        assert_eq!(progress.stop_reason_after_iteration(None, 1e-7), Some(StopReason::ParameterTolerance));
+        progress.record_iteration(1.9, &vector!(1.0), 1.0, &mut []).unwrap();
+        assert_eq!(progress.stop_reason_after_iteration(None, 1.0), Some(StopReason::RelativeLossTolerance));
+        assert_eq!(progress.stop_reason_before_iteration(), Some(StopReason::MaxIterations));
+
+        let report = progress.finish(StopReason::MaxIterations, &mut []).unwrap();
+
+        assert_eq!(report.losses(), &[4.0, 2.0, 1.9]);
+        assert_eq!(FitProgress::new(&stopping_criteria, 0.1).stop_reason_before_iteration(), Some(StopReason::LossTolerance));
+    }
+}
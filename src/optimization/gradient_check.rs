@@ -0,0 +1,193 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use optimization::ParametricFunction;
+use optimization::GradientCheck;
+
+// Central finite difference of g at x along the i-th coordinate, with a step relative to |x_i|.
+pub fn central_difference<G>(mut g: G, x: &Vector<f64>, i: usize, step: f64) -> Result<f64>
+where G: FnMut(&Vector<f64>) -> Result<f64> {
+    let h = step * x[i].abs().max(1.0);
+    let mut shifted = x.clone();
+
+    shifted[i] = x[i] + h;
+
+    let forward = g(&shifted)?;
+
+    shifted[i] = x[i] - h;
+
+    Ok((forward - g(&shifted)?) / (2.0 * h))
+}
+
+fn check_sizes(analytic: &Vector<f64>, expected: usize) -> Result<()> {
+    if analytic.size() != expected {
+        return Err(Error::DimensionMismatch { expected, actual: analytic.size() });
+    }
+
+    Ok(())
+}
+
+// Compares the parameter gradients of the function at the input with central finite differences
+// of f, shifting each parameter by `step` (relative to its magnitude when larger than 1). The
+// parameters of the function are restored afterwards.
+pub fn check_parameter_gradients<F>(function: &mut F, input: &Vector<f64>, step: f64) -> Result<GradientCheck>
+where F: ParametricFunction + ?Sized {
+    let parameters = function.parameters().clone();
+    let analytic = function.parameter_gradients(input)?;
+
+    check_sizes(&analytic, parameters.size())?;
+
+    let mut numerical = vec!();
+
+    for i in 0..parameters.size() {
+        let difference = central_difference(|shifted| {
+                                                function.set_parameters(shifted.clone())?;
+                                                function.f(input)
+                                            },
+                                            &parameters,
+                                            i,
+                                            step);
+
+        function.set_parameters(parameters.clone())?;
+        numerical.push(difference?);
+    }
+
+    Ok(GradientCheck::new(analytic, Vector::new(numerical)))
+}
+
+// Compares the input gradient of the function with central finite differences of f, shifting each
+// input by `step` (relative to its magnitude when larger than 1).
+pub fn check_input_gradient<F>(function: &F, input: &Vector<f64>, step: f64) -> Result<GradientCheck>
+where F: ParametricFunction + ?Sized {
+    let analytic = function.input_gradient(input)?;
+
+    check_sizes(&analytic, input.size())?;
+
+    let numerical = (0..input.size()).map(|i| central_difference(|shifted| function.f(shifted), input, i, step))
+                                     .collect::<Result<Vec<f64>>>()?;
+
+    Ok(GradientCheck::new(analytic, Vector::new(numerical)))
+}
+
+// Test helper checking the parameter gradients and, unless unsupported, the input gradient of a
+// function at each input. Panics with the failing check when a relative error exceeds the
+// tolerance. Functions with discrete inputs (e.g. the class index of a SoftmaxFunction) should
+// rather use check_parameter_gradients().
+pub fn assert_gradients<F>(function: &mut F, inputs: &[Vector<f64>], tolerance: f64)
+where F: ParametricFunction + ?Sized {
+    for input in inputs {
+        let check = check_parameter_gradients(function, input, 1e-6).unwrap_or_else(|error| {
+            panic!("assert_gradients: cannot check the parameter gradients at {:?}: {}", input.data(), error)
+        });
+
+        if !check.passes(tolerance) {
+            panic!("assert_gradients: parameter gradients at {:?} do not match finite differences (relative error {} > {}): {:?}",
+                   input.data(),
+                   check.max_relative_error(),
+                   tolerance,
+                   check)
+        }
+
+        let check = match check_input_gradient(function, input, 1e-6) {
+            Err(Error::Unsupported) => continue,
+            result => result.unwrap_or_else(|error| {
+                panic!("assert_gradients: cannot check the input gradient at {:?}: {}", input.data(), error)
+            })
+        };
+
+        if !check.passes(tolerance) {
+            panic!("assert_gradients: input gradient at {:?} does not match finite differences (relative error {} > {}): {:?}",
+                   input.data(),
+                   check.max_relative_error(),
+                   tolerance,
+                   check)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::vector::Vector;
+
+    use super::{check_parameter_gradients, check_input_gradient, assert_gradients};
+    use Result;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::PolynomialFunction;
+
+    // f(x) = b0 x^2, whose gradients are off by a factor 2.
+    struct WrongGradients {
+        parameters: Vector<f64>
+    }
+
+    impl WrongGradients {
+        fn new() -> WrongGradients {
+            WrongGradients { parameters: vector!(1.0) }
+        }
+    }
+
+    impl ParametricFunction for WrongGradients {
+        fn parameters(&self) -> &Vector<f64> {
+            &self.parameters
+        }
+
+        fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
+            self.parameters = new_parameters;
+
+            Ok(())
+        }
+
+        fn f(&self, input: &Vector<f64>) -> Result<f64> {
+            Ok(self.parameters[0] * input[0] * input[0])
+        }
+
+        fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+            Ok(vector!(self.parameters[0] * input[0]))
+        }
+
+        fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+            Ok(vector!(2.0 * input[0] * input[0]))
+        }
+    }
+
+    #[test]
+    fn check_linear_function() {
+        let mut function = LinearFunction::new(2);
+
+        function.set_parameters(vector!(1.0, -2.0, 3.0)).unwrap();
+
+        let parameter_check = check_parameter_gradients(&mut function, &vector!(4.0, 5.0), 1e-6).unwrap();
+        let input_check = check_input_gradient(&function, &vector!(4.0, 5.0), 1e-6).unwrap();
+
+        assert!(parameter_check.passes(1e-8));
+        assert_eq!(*parameter_check.analytic(), vector!(1.0, 4.0, 5.0));
+        assert!(input_check.passes(1e-8));
+        assert_eq!(*function.parameters(), vector!(1.0, -2.0, 3.0));
+    }
+
+    #[test]
+    fn check_wrong_gradients() {
+        let mut function = WrongGradients::new();
+
+        let parameter_check = check_parameter_gradients(&mut function, &vector!(3.0), 1e-6).unwrap();
+        let input_check = check_input_gradient(&function, &vector!(3.0), 1e-6).unwrap();
+
+        assert_relative_eq!(parameter_check.max_relative_error(), 0.5, epsilon = 1e-6);
+        assert_relative_eq!(input_check.max_relative_error(), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn assert_polynomial_gradients() {
+        let mut function = PolynomialFunction::with_degree(2, 3);
+
+        function.set_parameters(Vector::new((0..10).map(|i| f64::from(i) - 4.5).collect::<Vec<f64>>())).unwrap();
+
+        assert_gradients(&mut function, &[vector!(0.0, 0.0), vector!(-1.5, 2.0), vector!(30.0, 0.1)], 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "parameter gradients at [3.0] do not match finite differences")]
+    fn assert_wrong_gradients() {
+        assert_gradients(&mut WrongGradients::new(), &[vector!(3.0)], 1e-6);
+    }
+}
@@ -1,41 +1,385 @@
 use rulinalg::vector::Vector;
-use gnuplot::{Figure, AxesCommon, Color};
 
+use {Error, Result};
+use datasets::SupervisedDataset;
 use super::ParametricFunction;
+use super::GradientDescent;
+use super::FitReport;
+use super::StopReason;
+use super::fit_progress::FitProgress;
+use super::random::Random;
+use super::line_search::{LinePoint, line_search};
 
-pub fn gradient_descent_fit<F>(dataset: &Vec<(Vector<f64>, f64)>,
-                               parametric_function: &mut F,
-                               compute_error_average: &Fn(&F, &Vec<(Vector<f64>, f64)>) -> f64,
-                               compute_error_gradients: &Fn(&F, &Vec<(Vector<f64>, f64)>) -> Vector<f64>,
-                               learning_rate: f64,
-                               max_iterations: u32) -> Vec<f64>
-where F: ParametricFunction {
-    let mut errors = vec!();
-
-    for _ in 1..max_iterations {
-        let new_parameters = {
-            let parameters = parametric_function.parameters();
-            let gradients = compute_error_gradients(parametric_function, dataset);
-
-            parameters - (gradients * learning_rate)
-        };
-
-        errors.push(compute_error_average(parametric_function, dataset));
-        parametric_function.set_parameters(new_parameters);
-    }
-
-    // TODO: remove or debug mode
-    {
-        let x: Vec<u32> = (1..max_iterations).collect();
-        let ref y = errors;
-        let mut fg = Figure::new();
-        fg.set_terminal("png", "error.png")
-          .axes2d()
-          .set_x_label("Iteration", &[])
-          .set_y_label("Error", &[])
-          .lines(&x, y, &[Color("red")]);
-        fg.show();
-    }
-
-    errors
+pub fn norm(vector: &Vector<f64>) -> f64 {
+    vector.dot(vector).sqrt()
+}
+
+// Evaluates the loss, failing when it is no longer finite.
+pub fn compute_loss<F, A>(parametric_function: &F, dataset: &SupervisedDataset<f64>, compute_error_average: &A) -> Result<f64>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64> {
+    let loss = compute_error_average(parametric_function, dataset)?;
+
+    if !loss.is_finite() {
+        return Err(Error::Divergence);
+    }
+
+    Ok(loss)
+}
+
+fn descend<F, A, G>(dataset: &SupervisedDataset<f64>,
+                    parametric_function: &mut F,
+                    compute_error_average: &A,
+                    compute_error_gradients: &G,
+                    settings: &mut GradientDescent) -> Result<FitReport>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    if dataset.is_empty() {
+        return Err(Error::EmptyDataset);
+    }
+    if settings.batch_mode().batch_size() == Some(0) {
+        return Err(Error::InvalidSetting("the batch size must be positive".to_string()));
+    }
+
+    let batch_size = settings.batch_mode().batch_size().unwrap_or_else(|| dataset.len());
+    let mut random = settings.batch_mode().seed().map(Random::new);
+    let mut indices: Vec<usize> = (0..dataset.len()).collect();
+    let base_learning_rate = settings.optimizer().learning_rate();
+    let mut progress = FitProgress::new(settings.stopping_criteria(), compute_loss(parametric_function, dataset, compute_error_average)?);
+
+    settings.optimizer_mut().reset();
+
+    if let Some(schedule) = settings.schedule_mut() {
+        schedule.reset();
+    }
+
+    let stop_reason = loop {
+        if let Some(stop_reason) = progress.stop_reason_before_iteration() {
+            break stop_reason;
+        }
+
+        let learning_rate = settings.schedule_mut()
+                                    .map(|schedule| schedule.learning_rate(base_learning_rate, progress.iterations(), progress.losses()));
+
+        if let Some(learning_rate) = learning_rate {
+            settings.optimizer_mut().set_learning_rate(learning_rate);
+        }
+
+        let previous_parameters = parametric_function.parameters().clone();
+        let mut gradients_sum = Vector::zeros(previous_parameters.size());
+
+        if let Some(ref mut random) = random {
+            random.shuffle(&mut indices);
+        }
+
+        let mut line_search_failed = false;
+
+        for batch in indices.chunks(batch_size) {
+            let selection;
+            let batch_dataset = if batch.len() == dataset.len() {
+                dataset
+            } else {
+                selection = dataset.select(batch);
+                &selection
+            };
+            let gradients = compute_error_gradients(parametric_function, batch_dataset)?;
+            let update = settings.optimizer_mut().update(parametric_function.parameters(), &gradients);
+
+            gradients_sum += &gradients * batch_dataset.total_weight();
+
+            match settings.line_search() {
+                None => {
+                    let new_parameters = parametric_function.parameters() + update;
+
+                    parametric_function.set_parameters(new_parameters)?;
+                },
+                Some(method) => {
+                    let start = LinePoint {
+                        step: 0.0,
+                        loss: compute_error_average(parametric_function, batch_dataset)?,
+                        gradients
+                    };
+                    let point = line_search(method,
+                                            batch_dataset,
+                                            parametric_function,
+                                            compute_error_average,
+                                            compute_error_gradients,
+                                            &start,
+                                            &update)?;
+
+                    if point.is_none() {
+                        line_search_failed = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        // norm of the gradients averaged over the epoch
+        let gradient_norm = norm(&gradients_sum) / dataset.total_weight();
+        let step = parametric_function.parameters() - previous_parameters;
+        let loss = compute_loss(parametric_function, dataset, compute_error_average)?;
+
+        progress.record_iteration(loss, parametric_function.parameters(), gradient_norm, settings.observers_mut())?;
+
+        if line_search_failed {
+            break StopReason::LineSearchFailure;
+        }
+        if let Some(stop_reason) = progress.stop_reason_after_iteration(Some(gradient_norm), norm(&step)) {
+            break stop_reason;
+        }
+    };
+
+    progress.finish(stop_reason, settings.observers_mut())
+}
+
+// Every iteration runs over the batches of the dataset selected by the batch mode, and the report
+// holds the loss of the whole dataset after every iteration.
+pub fn gradient_descent_fit<F, A, G>(dataset: &SupervisedDataset<f64>,
+                                     parametric_function: &mut F,
+                                     compute_error_average: &A,
+                                     compute_error_gradients: &G,
+                                     settings: &mut GradientDescent) -> Result<FitReport>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    let base_learning_rate = settings.optimizer().learning_rate();
+    let report = descend(dataset, parametric_function, compute_error_average, compute_error_gradients, settings);
+
+    // schedules only change the learning rate for the duration of a fit
+    settings.optimizer_mut().set_learning_rate(base_learning_rate);
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use rulinalg::vector::Vector;
+
+    use {Error, Result};
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::TrainingObserver;
+    use optimization::LinearFunction;
+    use optimization::GradientDescent;
+    use optimization::StoppingCriteria;
+    use optimization::BatchMode;
+    use optimization::LineSearch;
+    use optimization::optimizers::Sgd;
+    use optimization::schedules::StepDecay;
+    use optimization::StopReason;
+    use optimization::IterationEvent;
+    use optimization::FitReport;
+
+    use super::gradient_descent_fit;
+
+    // Loss of f(x) = b0 + b1 x against its own parameters only, (b0 - 1)^2 + (b1 - 2)^2.
+    fn distance_to_target(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<f64> {
+        let parameters = function.parameters();
+
+        Ok((parameters[0] - 1.0).powi(2) + (parameters[1] - 2.0).powi(2))
+    }
+
+    fn distance_to_target_gradients(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+        let parameters = function.parameters();
+
+        Ok(vector!(2.0 * (parameters[0] - 1.0), 2.0 * (parameters[1] - 2.0)))
+    }
+
+    // Records the iteration, loss and gradient norm of every event, and the final number of iterations.
+    struct RecordingObserver {
+        events: Rc<RefCell<Vec<(u32, f64, f64)>>>,
+        finished_iterations: Rc<RefCell<Option<u32>>>
+    }
+
+    impl TrainingObserver for RecordingObserver {
+        fn on_iteration(&mut self, event: &IterationEvent) -> Result<()> {
+            self.events.borrow_mut().push((event.iteration(), event.loss(), event.gradient_norm()));
+
+            Ok(())
+        }
+
+        fn on_finish(&mut self, report: &FitReport) -> Result<()> {
+            *self.finished_iterations.borrow_mut() = Some(report.iterations());
+
+            Ok(())
+        }
+    }
+
+    fn fit(learning_rate: f64, stopping_criteria: StoppingCriteria) -> Result<(LinearFunction, StopReason, u32, f64)> {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let report = gradient_descent_fit(&dataset,
+                                          &mut function,
+                                          &distance_to_target,
+                                          &distance_to_target_gradients,
+                                          &mut GradientDescent::new(Sgd::new(learning_rate), stopping_criteria))?;
+
+        assert_eq!(report.losses().len() as u32, report.iterations() + 1);
+
+        Ok((function, report.stop_reason(), report.iterations(), report.final_loss()))
+    }
+
+    #[test]
+    fn stop_after_max_iterations() {
+        let (_, stop_reason, iterations, loss) = fit(0.25, StoppingCriteria::new(3)).unwrap();
+
+        // every iteration halves the distance to the target, so the loss is divided by 4
+        assert_eq!(stop_reason, StopReason::MaxIterations);
+        assert_eq!(iterations, 3);
+        assert_relative_eq!(loss, 5.0 / 64.0);
+    }
+
+    #[test]
+    fn stop_on_loss_tolerance() {
+        let (_, stop_reason, iterations, loss) = fit(0.25, StoppingCriteria::new(100).with_loss_tolerance(0.1)).unwrap();
+
+        assert_eq!(stop_reason, StopReason::LossTolerance);
+        assert_eq!(iterations, 3);
+        assert!(loss <= 0.1);
+    }
+
+    #[test]
+    fn stop_on_relative_loss_tolerance() {
+        let (_, stop_reason, iterations, _) = fit(0.001, StoppingCriteria::new(100).with_relative_loss_tolerance(0.01)).unwrap();
+
+        // the loss decreases by a factor (1 - 0.002)^2, i.e. by less than 1%
+        assert_eq!(stop_reason, StopReason::RelativeLossTolerance);
+        assert_eq!(iterations, 1);
+    }
+
+    #[test]
+    fn stop_on_gradient_tolerance() {
+        let (function, stop_reason, _, _) = fit(0.25, StoppingCriteria::new(100).with_gradient_tolerance(1e-6)).unwrap();
+
+        assert_eq!(stop_reason, StopReason::GradientTolerance);
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn stop_on_parameter_tolerance() {
+        let (function, stop_reason, _, _) = fit(0.25, StoppingCriteria::new(100).with_parameter_tolerance(1e-6)).unwrap();
+
+        assert_eq!(stop_reason, StopReason::ParameterTolerance);
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn stop_on_time_budget() {
+        let (_, stop_reason, iterations, _) = fit(0.25, StoppingCriteria::new(100).with_time_budget(Duration::from_secs(0))).unwrap();
+
+        assert_eq!(stop_reason, StopReason::TimeBudget);
+        assert_eq!(iterations, 0);
+    }
+
+    #[test]
+    fn fail_on_divergence() {
+        // a learning rate above 1 overshoots the target further at every iteration
+        assert_eq!(fit(2.0, StoppingCriteria::new(10000)).err(), Some(Error::Divergence));
+    }
+
+    #[test]
+    fn fail_on_empty_dataset() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!()).unwrap();
+
+        assert_eq!(gradient_descent_fit(&dataset,
+                                        &mut function,
+                                        &distance_to_target,
+                                        &distance_to_target_gradients,
+                                        &mut GradientDescent::new(Sgd::new(0.25), StoppingCriteria::new(10))).err(),
+                   Some(Error::EmptyDataset));
+    }
+
+    #[test]
+    fn fail_on_empty_batches() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let batch_mode = BatchMode::MiniBatch { batch_size: 0, seed: 0 };
+
+        assert_eq!(gradient_descent_fit(&dataset,
+                                        &mut function,
+                                        &distance_to_target,
+                                        &distance_to_target_gradients,
+                                        &mut GradientDescent::new(Sgd::new(0.25), StoppingCriteria::new(10)).with_batch_mode(batch_mode)).err(),
+                   Some(Error::InvalidSetting("the batch size must be positive".to_string())));
+    }
+
+    #[test]
+    fn schedule_learning_rate() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let mut settings = GradientDescent::new(Sgd::new(0.25), StoppingCriteria::new(3)).with_schedule(StepDecay::new(1, 0.0));
+        let report = gradient_descent_fit(&dataset,
+                                          &mut function,
+                                          &distance_to_target,
+                                          &distance_to_target_gradients,
+                                          &mut settings).unwrap();
+
+        // only the first iteration has a non-zero learning rate
+        assert_eq!(report.losses(), &[5.0, 5.0 / 4.0, 5.0 / 4.0, 5.0 / 4.0]);
+        assert_eq!(settings.optimizer().learning_rate(), 0.25);
+    }
+
+    #[test]
+    fn line_search_steps() {
+        for &line_search in &[LineSearch::armijo(), LineSearch::strong_wolfe()] {
+            let mut function = LinearFunction::new(1);
+            let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+            let mut settings = GradientDescent::new(Sgd::new(1.0), StoppingCriteria::new(100).with_loss_tolerance(1e-20))
+                                              .with_line_search(line_search);
+            let report = gradient_descent_fit(&dataset,
+                                              &mut function,
+                                              &distance_to_target,
+                                              &distance_to_target_gradients,
+                                              &mut settings).unwrap();
+
+            // the exact step along the gradients is 0.5, which the first trial step of 1 overshoots
+            assert_eq!(report.stop_reason(), StopReason::LossTolerance);
+            assert!(report.iterations() <= 2);
+        }
+    }
+
+    #[test]
+    fn line_search_failure() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        // the updates go up the gradients
+        let mut settings = GradientDescent::new(Sgd::new(-1.0), StoppingCriteria::new(100)).with_line_search(LineSearch::armijo());
+        let report = gradient_descent_fit(&dataset,
+                                          &mut function,
+                                          &distance_to_target,
+                                          &distance_to_target_gradients,
+                                          &mut settings).unwrap();
+
+        assert_eq!(report.stop_reason(), StopReason::LineSearchFailure);
+        assert_eq!(report.final_loss(), 5.0);
+        assert_eq!(function.parameters(), &vector!(0.0, 0.0));
+    }
+
+    #[test]
+    fn notify_observers() {
+        let events = Rc::new(RefCell::new(vec!()));
+        let finished_iterations = Rc::new(RefCell::new(None));
+        let observer = RecordingObserver { events: events.clone(), finished_iterations: finished_iterations.clone() };
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+
+        gradient_descent_fit(&dataset,
+                             &mut function,
+                             &distance_to_target,
+                             &distance_to_target_gradients,
+                             &mut GradientDescent::new(Sgd::new(0.25), StoppingCriteria::new(2)).with_observer(observer)).unwrap();
+
+        // the gradient norm is the one of the gradients at the parameters before the iteration
+        assert_eq!(*events.borrow(), vec!((1, 5.0 / 4.0, 20.0f64.sqrt()), (2, 5.0 / 16.0, 5.0f64.sqrt())));
+        assert_eq!(*finished_iterations.borrow(), Some(2));
+    }
 }
@@ -0,0 +1,202 @@
+use std::collections::VecDeque;
+
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::Lbfgs;
+use super::FitReport;
+use super::StoppingCriteria;
+use super::LineSearch;
+use super::TrainingObserver;
+use super::gradient_descent::norm;
+use super::line_search_fit::{SearchDirection, LineSearchSettings, line_search_fit};
+
+// Step and gradient change of a previous iteration, with rho = 1 / (y . s).
+struct Correction {
+    step: Vector<f64>,
+    gradient_changes: Vector<f64>,
+    rho: f64
+}
+
+struct LbfgsDirection {
+    history_size: usize,
+    history: VecDeque<Correction>
+}
+
+impl SearchDirection for LbfgsDirection {
+    // Two-loop recursion (Nocedal & Wright, Algorithm 7.4), scaling the initial inverse Hessian
+    // by (s . y) / (y . y) of the last correction.
+    fn direction(&mut self, gradients: &Vector<f64>) -> Vector<f64> {
+        let mut q = gradients.clone();
+        let mut alphas = Vec::with_capacity(self.history.len());
+
+        for correction in self.history.iter().rev() {
+            let alpha = correction.rho * correction.step.dot(&q);
+
+            q -= &correction.gradient_changes * alpha;
+            alphas.push(alpha);
+        }
+
+        // without history, the first step is at most of unit length
+        let gamma = match self.history.back() {
+            None => 1.0 / norm(gradients).max(1.0),
+            Some(correction) => 1.0 / (correction.rho * correction.gradient_changes.dot(&correction.gradient_changes))
+        };
+
+        q *= gamma;
+
+        for (correction, alpha) in self.history.iter().zip(alphas.iter().rev()) {
+            let beta = correction.rho * correction.gradient_changes.dot(&q);
+
+            q += &correction.step * (alpha - beta);
+        }
+
+        -q
+    }
+
+    fn update(&mut self, step: &Vector<f64>, gradient_changes: &Vector<f64>) {
+        let curvature = step.dot(gradient_changes);
+
+        // the inverse Hessian approximation would not stay positive definite
+        if curvature <= f64::EPSILON * gradient_changes.dot(gradient_changes) {
+            return;
+        }
+        if self.history.len() == self.history_size {
+            self.history.pop_front();
+        }
+
+        self.history.push_back(Correction {
+            step: step.clone(),
+            gradient_changes: gradient_changes.clone(),
+            rho: 1.0 / curvature
+        });
+    }
+
+    fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl LineSearchSettings for Lbfgs {
+    fn stopping_criteria(&self) -> &StoppingCriteria {
+        Lbfgs::stopping_criteria(self)
+    }
+
+    fn line_search(&self) -> LineSearch {
+        Lbfgs::line_search(self)
+    }
+
+    fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>] {
+        Lbfgs::observers_mut(self)
+    }
+}
+
+// Limited-memory BFGS fit, searching along quasi-Newton directions built from the last
+// `history_size` iterations. Every iteration runs over the whole dataset.
+pub fn lbfgs_fit<F, A, G>(dataset: &SupervisedDataset<f64>,
+                          parametric_function: &mut F,
+                          compute_error_average: &A,
+                          compute_error_gradients: &G,
+                          settings: &mut Lbfgs) -> Result<FitReport>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    if settings.history_size() == 0 {
+        return Err(Error::InvalidSetting("the history size must be positive".to_string()));
+    }
+
+    let mut search_direction = LbfgsDirection { history_size: settings.history_size(), history: VecDeque::new() };
+
+    line_search_fit(dataset,
+                    parametric_function,
+                    compute_error_average,
+                    compute_error_gradients,
+                    &mut search_direction,
+                    settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use rulinalg::vector::Vector;
+
+    use {Error, Result};
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::Lbfgs;
+    use optimization::StoppingCriteria;
+    use optimization::StopReason;
+    use optimization::line_search_fit::SearchDirection;
+
+    use super::LbfgsDirection;
+    use super::lbfgs_fit;
+
+    // (b0 - 1)^2 + 100 (b1 - 2)^2
+    fn loss(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<f64> {
+        let parameters = function.parameters();
+
+        Ok((parameters[0] - 1.0).powi(2) + 100.0 * (parameters[1] - 2.0).powi(2))
+    }
+
+    fn gradients(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+        let parameters = function.parameters();
+
+        Ok(vector!(2.0 * (parameters[0] - 1.0), 200.0 * (parameters[1] - 2.0)))
+    }
+
+    #[test]
+    fn fit_ill_conditioned_quadratic() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let mut settings = Lbfgs::new(5, StoppingCriteria::new(100).with_gradient_tolerance(1e-10));
+        let report = lbfgs_fit(&dataset, &mut function, &loss, &gradients, &mut settings).unwrap();
+
+        assert_eq!(report.stop_reason(), StopReason::GradientTolerance);
+        assert!(report.iterations() < 10);
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn fit_with_empty_history() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let mut settings = Lbfgs::new(0, StoppingCriteria::new(100));
+
+        assert_eq!(lbfgs_fit(&dataset, &mut function, &loss, &gradients, &mut settings).err(),
+                   Some(Error::InvalidSetting("the history size must be positive".to_string())));
+    }
+
+    #[test]
+    fn keep_last_corrections() {
+        let mut direction = LbfgsDirection { history_size: 2, history: VecDeque::new() };
+
+        direction.update(&vector!(1.0, 0.0), &vector!(2.0, 0.0));
+        direction.update(&vector!(0.0, 1.0), &vector!(0.0, 200.0));
+        // negative curvature, skipped
+        direction.update(&vector!(1.0, 1.0), &vector!(-1.0, -1.0));
+        direction.update(&vector!(1.0, 1.0), &vector!(2.0, 200.0));
+
+        assert_eq!(direction.history.len(), 2);
+        assert_eq!(direction.history[0].step, vector!(0.0, 1.0));
+        assert_eq!(direction.history[1].step, vector!(1.0, 1.0));
+    }
+
+    #[test]
+    fn direction_of_quadratic() {
+        let mut direction = LbfgsDirection { history_size: 2, history: VecDeque::new() };
+
+        direction.update(&vector!(1.0, 0.0), &vector!(2.0, 0.0));
+        direction.update(&vector!(0.0, 1.0), &vector!(0.0, 200.0));
+
+        // the corrections span the whole space, so the direction is the Newton step
+        let newton_direction = direction.direction(&vector!(2.0, 200.0));
+
+        assert_relative_eq!(newton_direction[0], -1.0, epsilon = 1e-12);
+        assert_relative_eq!(newton_direction[1], -1.0, epsilon = 1e-12);
+    }
+}
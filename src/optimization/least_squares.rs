@@ -1,174 +1,453 @@
+use rulinalg::matrix::{Matrix, BaseMatrix};
 use rulinalg::vector::Vector;
 
+use {Error, Result};
+use datasets::SupervisedDataset;
 use super::ParametricFunction;
+use super::GradientDescent;
+use super::Lbfgs;
+use super::ConjugateGradient;
+use super::Penalty;
+use super::StoppingCriteria;
+use super::FitReport;
 use super::gradient_descent_fit;
+use super::lbfgs_fit;
+use super::conjugate_gradient_fit;
+use super::mean_loss;
+use super::mean_loss_gradients;
+use super::fit_progress::FitProgress;
+use super::losses::SquaredError;
+use super::qr_decomposition::QrDecomposition;
+
+fn compute_error_average<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<f64>
+where F: ParametricFunction + ?Sized {
+    mean_loss(function, dataset, &SquaredError)
+}
 
-fn compute_error<F>(function: &F, input: &Vector<f64>, y: f64) -> f64
-where F: ParametricFunction {
-    (y - function.f(input))
+fn compute_error_gradients<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<Vector<f64>>
+where F: ParametricFunction + ?Sized {
+    mean_loss_gradients(function, dataset, &SquaredError)
 }
 
-fn compute_error_average<F>(function: &F, dataset: &Vec<(Vector<f64>, f64)>) -> f64
-where F: ParametricFunction {
-    let n = dataset.len() as f64;
-    let errors_sum = dataset.iter()
-                            .map(|&(ref x, y)| compute_error(function, x, y).powi(2))
-                            .sum::<f64>();
+fn compute_penalized_error_average<F>(function: &F, dataset: &SupervisedDataset<f64>, penalty: &Penalty) -> Result<f64>
+where F: ParametricFunction + ?Sized {
+    Ok(compute_error_average(function, dataset)? + penalty.value(function.parameters()))
+}
 
-    errors_sum / n
+fn compute_penalized_error_gradients<F>(function: &F, dataset: &SupervisedDataset<f64>, penalty: &Penalty) -> Result<Vector<f64>>
+where F: ParametricFunction + ?Sized {
+    Ok(compute_error_gradients(function, dataset)? + penalty.gradients(function.parameters()))
 }
 
-fn compute_error_gradients<F>(function: &F, dataset: &Vec<(Vector<f64>, f64)>) -> Vector<f64>
-where F: ParametricFunction {
-    let n = dataset.len() as f64;
-    let mut gradients = vec![0.0; function.parameters().size()];
+// Design matrix whose rows are the parameter gradients of each input.
+fn compute_design<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<Matrix<f64>>
+where F: ParametricFunction + ?Sized {
+    let parameters_size = function.parameters().size();
+    let mut design = Vec::with_capacity(dataset.len() * parameters_size);
+
+    for x in dataset.unsupervised_dataset().iter() {
+        design.extend(function.parameter_gradients(&x)?.into_vec());
+    }
+
+    Ok(Matrix::new(dataset.len(), parameters_size, design))
+}
 
-    for &(ref x, y) in dataset.iter() {
-        let previous_gradients = gradients;
-        let error = compute_error(function, x, y);
-        let parameter_gradients = function.parameter_gradients(x);
+// Design and targets with each row scaled by the square root of its sample weight, so that their
+// squared residuals are the weighted squared residuals of the dataset.
+fn compute_weighted_system<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<(Matrix<f64>, Vector<f64>)>
+where F: ParametricFunction + ?Sized {
+    let mut design = compute_design(function, dataset)?;
+    let mut targets = dataset.labels().to_vec();
 
-        gradients = previous_gradients.iter()
-                                      .zip(parameter_gradients.iter())
-                                      .map(|(acc, g)| acc + (-2.0 / n) * (error * g))
-                                      .collect()
+    if let Some(weights) = dataset.weights() {
+        for (i, weight) in weights.iter().enumerate() {
+            let scale = weight.sqrt();
+
+            for j in 0..design.cols() {
+                design[[i, j]] *= scale;
+            }
+
+            targets[i] *= scale;
+        }
     }
 
-    Vector::new(gradients)
+    Ok((design, Vector::new(targets)))
+}
+
+// Soft-thresholding operator, the proximal operator of threshold |x|.
+fn soft_threshold(value: f64, threshold: f64) -> f64 {
+    if value > threshold {
+        value - threshold
+    } else if value < -threshold {
+        value + threshold
+    } else {
+        0.0
+    }
 }
 
+// Gradient descent fit minimizing the average squared error, weighted by the sample weights of the
+// dataset when it has some (as every least squares fit).
 pub fn least_squares_fit<F>(function: &mut F,
-                            dataset: &Vec<(Vector<f64>, f64)>,
-                            learning_rate: f64,
-                            max_iterations: u32) -> Vec<f64>
-where F: ParametricFunction {
+                            dataset: &SupervisedDataset<f64>,
+                            settings: &mut GradientDescent) -> Result<FitReport>
+where F: ParametricFunction + ?Sized {
     gradient_descent_fit(dataset,
                          function,
                          &compute_error_average,
                          &compute_error_gradients,
-                         learning_rate,
-                         max_iterations)
+                         settings)
+}
+
+pub fn least_squares_lbfgs_fit<F>(function: &mut F,
+                                  dataset: &SupervisedDataset<f64>,
+                                  settings: &mut Lbfgs) -> Result<FitReport>
+where F: ParametricFunction + ?Sized {
+    lbfgs_fit(dataset,
+              function,
+              &compute_error_average,
+              &compute_error_gradients,
+              settings)
+}
+
+pub fn least_squares_conjugate_gradient_fit<F>(function: &mut F,
+                                               dataset: &SupervisedDataset<f64>,
+                                               settings: &mut ConjugateGradient) -> Result<FitReport>
+where F: ParametricFunction + ?Sized {
+    conjugate_gradient_fit(dataset,
+                           function,
+                           &compute_error_average,
+                           &compute_error_gradients,
+                           settings)
+}
+
+// Exact least squares fit through the QR decomposition of the design matrix, whose rows are the
+// parameter gradients of each input, scaled by the square root of their sample weight. The solution
+// is only exact for functions that are linear in their parameters (e.g. `LinearFunction`).
+pub fn least_squares_qr_fit<F>(function: &mut F, dataset: &SupervisedDataset<f64>) -> Result<()>
+where F: ParametricFunction + ?Sized {
+    if dataset.is_empty() {
+        return Err(Error::EmptyDataset);
+    }
+
+    let (design, targets) = compute_weighted_system(function, dataset)?;
+
+    function.set_parameters(QrDecomposition::new(design, targets)?.solve()?)
+}
+
+// Covariance matrix of the parameters fitted by least squares, s^2 (J^T W J)^-1 where J is the
+// design (the parameter gradients at every input), W the sample weights and s^2 = RSS / (n - p) the
+// residual variance, RSS being weighted and n counting the samples of positive weight. Exact for
+// functions that are linear in their parameters, asymptotic otherwise.
+pub fn least_squares_parameter_covariance<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<Matrix<f64>>
+where F: ParametricFunction + ?Sized {
+    let parameters_size = function.parameters().size();
+    let sample_count = dataset.positive_weight_count();
+
+    if sample_count <= parameters_size {
+        return Err(Error::InvalidSetting(format!("{} samples cannot estimate the covariance of {} parameters",
+                                                 sample_count,
+                                                 parameters_size)));
+    }
+
+    let (design, targets) = compute_weighted_system(function, dataset)?;
+    let residual_sum_of_squares = dataset.weighted_iter()
+                                         .map(|(x, y, weight)| function.f(&x).map(|prediction| weight * (y - prediction).powi(2)))
+                                         .sum::<Result<f64>>()?;
+    let residual_variance = residual_sum_of_squares / (sample_count - parameters_size) as f64;
+
+    Ok(QrDecomposition::new(design, targets)?.inverse_gram()? * residual_variance)
+}
+
+// Least squares fit with the penalty added to the average squared error and its gradients.
+pub fn least_squares_penalized_fit<F>(function: &mut F,
+                                      dataset: &SupervisedDataset<f64>,
+                                      penalty: &Penalty,
+                                      settings: &mut GradientDescent) -> Result<FitReport>
+where F: ParametricFunction + ?Sized {
+    gradient_descent_fit(dataset,
+                         function,
+                         &|function: &F, dataset: &SupervisedDataset<f64>| compute_penalized_error_average(function, dataset, penalty),
+                         &|function: &F, dataset: &SupervisedDataset<f64>| compute_penalized_error_gradients(function, dataset, penalty),
+                         settings)
+}
+
+// Exact Ridge fit through the QR decomposition of the design matrix augmented with sqrt(W alpha) I
+// for the penalized parameters, W being the total sample weight. Only L2 penalties have such a
+// closed form solution.
+pub fn least_squares_penalized_qr_fit<F>(function: &mut F, dataset: &SupervisedDataset<f64>, penalty: &Penalty) -> Result<()>
+where F: ParametricFunction + ?Sized {
+    if dataset.is_empty() {
+        return Err(Error::EmptyDataset);
+    }
+    if penalty.l1_weight() != 0.0 {
+        return Err(Error::InvalidSetting("the QR decomposition only solves L2 penalties".to_string()));
+    }
+
+    let (design, targets) = compute_weighted_system(function, dataset)?;
+    let penalized: Vec<usize> = (0..design.cols()).filter(|&index| penalty.is_penalized(index)).collect();
+    let weight = (dataset.total_weight() * penalty.l2_weight()).sqrt();
+    let augmented_design = Matrix::from_fn(design.rows() + penalized.len(), design.cols(), |j, i| {
+        if i < design.rows() {
+            design[[i, j]]
+        } else if penalized[i - design.rows()] == j {
+            weight
+        } else {
+            0.0
+        }
+    });
+    let mut targets = targets.into_vec();
+
+    targets.extend(vec![0.0; penalized.len()]);
+
+    function.set_parameters(QrDecomposition::new(augmented_design, Vector::new(targets))?.solve()?)
+}
+
+// Cyclic coordinate descent on the penalized average squared error, for functions that are linear
+// in their parameters. Every iteration minimizes the loss along each parameter in turn, soft
+// thresholding the penalized ones, and starts from the current parameters of the function. The
+// gradient tolerance of the stopping criteria is ignored since the L1 norm is not differentiable.
+pub fn least_squares_coordinate_descent_fit<F>(function: &mut F,
+                                               dataset: &SupervisedDataset<f64>,
+                                               penalty: &Penalty,
+                                               stopping_criteria: &StoppingCriteria) -> Result<FitReport>
+where F: ParametricFunction + ?Sized {
+    if dataset.is_empty() {
+        return Err(Error::EmptyDataset);
+    }
+
+    // weighted rows, the sums of squares being divided by the total weight
+    let (design, targets) = compute_weighted_system(function, dataset)?;
+    let n = dataset.total_weight();
+    let mut parameters = function.parameters().clone().into_vec();
+    let mut residuals: Vec<f64> = targets.iter()
+                                         .zip((&design * Vector::new(parameters.clone())).iter())
+                                         .map(|(y, prediction)| y - prediction)
+                                         .collect();
+    let squared_norms: Vec<f64> = (0..design.cols()).map(|j| (0..design.rows()).map(|i| design[[i, j]].powi(2)).sum::<f64>() / n)
+                                                    .collect();
+    let compute_loss = |residuals: &[f64], parameters: &[f64]| {
+        residuals.iter().map(|r| r.powi(2)).sum::<f64>() / n + penalty.value(&Vector::new(parameters))
+    };
+    let mut progress = FitProgress::new(stopping_criteria, compute_loss(&residuals, &parameters));
+
+    let stop_reason = loop {
+        if let Some(stop_reason) = progress.stop_reason_before_iteration() {
+            break stop_reason;
+        }
+
+        let mut squared_step = 0.0;
+
+        for (j, parameter) in parameters.iter_mut().enumerate() {
+            // correlation between the column and the residuals without the parameter contribution
+            let correlation = (0..design.rows()).map(|i| design[[i, j]] * (residuals[i] + design[[i, j]] * *parameter))
+                                                .sum::<f64>() / n;
+            let denominator = if penalty.is_penalized(j) {
+                squared_norms[j] + penalty.l2_weight()
+            } else {
+                squared_norms[j]
+            };
+            let numerator = if penalty.is_penalized(j) {
+                soft_threshold(correlation, penalty.l1_weight() / 2.0)
+            } else {
+                correlation
+            };
+            let new_parameter = if denominator == 0.0 { 0.0 } else { numerator / denominator };
+            let change = new_parameter - *parameter;
+
+            if change != 0.0 {
+                for (i, residual) in residuals.iter_mut().enumerate() {
+                    *residual -= design[[i, j]] * change;
+                }
+
+                *parameter = new_parameter;
+                squared_step += change.powi(2);
+            }
+        }
+
+        function.set_parameters(Vector::new(parameters.clone()))?;
+
+        let loss = compute_loss(&residuals, &parameters);
+
+        if !loss.is_finite() {
+            return Err(Error::Divergence);
+        }
+
+        // no gradients, and no observers to notify
+        progress.record_iteration(loss, function.parameters(), f64::NAN, &mut [])?;
+
+        if let Some(stop_reason) = progress.stop_reason_after_iteration(None, squared_step.sqrt()) {
+            break stop_reason;
+        }
+    };
+
+    progress.finish(stop_reason, &mut [])
+}
+
+// Coordinate descent fits for each alpha in turn, every fit starting from the parameters of the
+// previous one (warm start). Alphas are usually given in decreasing order, from a penalty strong
+// enough to zero every penalized parameter. Returns the parameters fitted for each alpha, the
+// function being left with the last ones.
+pub fn least_squares_regularization_path<F>(function: &mut F,
+                                            dataset: &SupervisedDataset<f64>,
+                                            penalty: &Penalty,
+                                            alphas: &[f64],
+                                            stopping_criteria: &StoppingCriteria) -> Result<Vec<Vector<f64>>>
+where F: ParametricFunction + ?Sized {
+    let mut path = Vec::with_capacity(alphas.len());
+
+    for &alpha in alphas {
+        least_squares_coordinate_descent_fit(function, dataset, &penalty.with_alpha(alpha), stopping_criteria)?;
+        path.push(function.parameters().clone());
+    }
+
+    Ok(path)
 }
 
 #[cfg(test)]
 mod tests {
     use std::f64;
 
+    use Error;
+    use datasets::SupervisedDataset;
     use optimization::ParametricFunction;
     use optimization::LinearFunction;
+    use optimization::PolynomialFunction;
+    use optimization::GradientDescent;
+    use optimization::StoppingCriteria;
+    use optimization::BatchMode;
+    use optimization::FitReport;
+    use optimization::Penalty;
+    use optimization::StopReason;
+    use optimization::optimizers::Sgd;
+    use optimization::optimizers::Adam;
 
-    use super::compute_error;
     use super::compute_error_average;
     use super::compute_error_gradients;
-
-    fn build_test_function() -> LinearFunction {
+    use super::least_squares_fit;
+    use super::least_squares_qr_fit;
+    use super::least_squares_parameter_covariance;
+    use super::least_squares_penalized_fit;
+    use super::least_squares_penalized_qr_fit;
+    use super::least_squares_coordinate_descent_fit;
+    use super::least_squares_regularization_path;
+
+    fn fit_exact_line(batch_mode: BatchMode, epochs: u32) -> (LinearFunction, FitReport) {
         let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples((0..10).map(|x| (vector!(x as f64 / 10.0), 1.0 + 0.2 * x as f64))
+                                                             .collect()).unwrap();
+        let mut settings = GradientDescent::new(Sgd::new(0.1), StoppingCriteria::new(epochs)).with_batch_mode(batch_mode);
+        let report = least_squares_fit(&mut function, &dataset, &mut settings).unwrap();
 
-        // f(x) = x
-        function.set_parameters(vector!(0.0, 1.0));
+        (function, report)
+    }
 
-        function
+    // y = 1 + 2x, with mean(x) = 0.5, var(x) = 1.25 and cov(x, y) = 2.5
+    fn build_line_dataset() -> SupervisedDataset<f64> {
+        SupervisedDataset::from_samples(vec!((vector!(-1.0), -1.0),
+                                             (vector!(0.0), 1.0),
+                                             (vector!(1.0), 3.0),
+                                             (vector!(2.0), 5.0))).unwrap()
     }
 
-    #[test]
-    fn compute_error_0() {
-        let function = build_test_function();
+    // weighted mean(x) = 2, mean(y) = 3.625, var(x) = 1.25 and cov(x, y) = 1.5 for a total weight 8,
+    // so that y = 1.225 + 1.2x
+    fn build_weighted_line_dataset() -> SupervisedDataset<f64> {
+        SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0),
+                                             (vector!(1.0), 3.0),
+                                             (vector!(2.0), 2.0),
+                                             (vector!(3.0), 5.0))).unwrap()
+                                       .with_weights(vec!(1.0, 2.0, 1.0, 4.0)).unwrap()
+    }
 
-        assert_eq!(compute_error(&function, &vector!(-3.0), -3.0), 0.0);
-        assert_eq!(compute_error(&function, &vector!(3.0), 3.0), 0.0);
+    // The weighted line dataset with each sample repeated as many times as it weighs.
+    fn build_repeated_line_dataset() -> SupervisedDataset<f64> {
+        let dataset = build_weighted_line_dataset();
+
+        dataset.select(&[0, 1, 1, 2, 3, 3, 3, 3]).with_weights(vec![1.0; 8]).unwrap()
     }
 
-    #[test]
-    fn compute_error_signed() {
-        let function = build_test_function();
+    fn coordinate_descent_criteria() -> StoppingCriteria {
+        StoppingCriteria::new(1000).with_parameter_tolerance(1e-14)
+    }
+
+    fn build_test_function() -> LinearFunction {
+        let mut function = LinearFunction::new(1);
+
+        // f(x) = x
+        function.set_parameters(vector!(0.0, 1.0)).unwrap();
 
-        assert_eq!(compute_error(&function, &vector!(-2.0), -3.0), -1.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), -1.0), 1.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), -4.0), -2.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), 0.0), 2.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), -5.0), -3.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), 1.0), 3.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), -6.0), -4.0);
-        assert_eq!(compute_error(&function, &vector!(-2.0), 2.0), 4.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), 1.0), -1.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), 3.0), 1.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), 0.0), -2.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), 4.0), 2.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), -1.0), -3.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), 5.0), 3.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), -2.0), -4.0);
-        assert_eq!(compute_error(&function, &vector!(2.0), 6.0), 4.0);
+        function
     }
 
     #[test]
     fn compute_error_average_0() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), -0.33),
-                           (vector!(1.0), 1.0),
-                           (vector!(0.0), 0.0),
-                           (vector!(4.2), 4.2),
-                           (vector!(13.36), 13.36),
-                           (vector!(3.13), 3.13),
-                           (vector!(1.33), 1.33));
-
-        assert_eq!(compute_error_average(&function, &dataset), 0.0);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), -0.33),
+                                                           (vector!(1.0), 1.0),
+                                                           (vector!(0.0), 0.0),
+                                                           (vector!(4.2), 4.2),
+                                                           (vector!(13.36), 13.36),
+                                                           (vector!(3.13), 3.13),
+                                                           (vector!(1.33), 1.33))).unwrap();
+
+        assert_eq!(compute_error_average(&function, &dataset), Ok(0.0));
     }
 
     #[test]
     fn compute_error_average_1() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), -1.33),
-                           (vector!(1.0), 2.0),
-                           (vector!(0.0), -1.0),
-                           (vector!(4.2), 5.2),
-                           (vector!(13.36), 12.36),
-                           (vector!(3.13), 2.13),
-                           (vector!(1.33), 0.33));
-
-        assert_eq!(compute_error_average(&function, &dataset), 1.0);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), -1.33),
+                                                           (vector!(1.0), 2.0),
+                                                           (vector!(0.0), -1.0),
+                                                           (vector!(4.2), 5.2),
+                                                           (vector!(13.36), 12.36),
+                                                           (vector!(3.13), 2.13),
+                                                           (vector!(1.33), 0.33))).unwrap();
+
+        assert_eq!(compute_error_average(&function, &dataset), Ok(1.0));
     }
 
     #[test]
     fn compute_error_average_of_squared_errors() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), -2.33),
-                           (vector!(1.0), 4.0),
-                           (vector!(0.0), -4.0),
-                           (vector!(4.2), 6.2),
-                           (vector!(13.36), 16.36),
-                           (vector!(3.13), 7.13),
-                           (vector!(1.33), 0.33));
-
-        assert_relative_eq!(compute_error_average(&function, &dataset),
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), -2.33),
+                                                           (vector!(1.0), 4.0),
+                                                           (vector!(0.0), -4.0),
+                                                           (vector!(4.2), 6.2),
+                                                           (vector!(13.36), 16.36),
+                                                           (vector!(3.13), 7.13),
+                                                           (vector!(1.33), 0.33))).unwrap();
+
+        assert_relative_eq!(compute_error_average(&function, &dataset).unwrap(),
                             (4.0 + 9.0 + 16.0 + 4.0 + 9.0 + 16.0 + 1.0) / 7.0);
     }
 
     #[test]
     fn compute_error_gradients_with_errors_0() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), -0.33),
-                           (vector!(1.0), 1.0),
-                           (vector!(0.0), 0.0),
-                           (vector!(4.2), 4.2),
-                           (vector!(13.36), 13.36),
-                           (vector!(3.13), 3.13),
-                           (vector!(1.33), 1.33));
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), -0.33),
+                                                           (vector!(1.0), 1.0),
+                                                           (vector!(0.0), 0.0),
+                                                           (vector!(4.2), 4.2),
+                                                           (vector!(13.36), 13.36),
+                                                           (vector!(3.13), 3.13),
+                                                           (vector!(1.33), 1.33))).unwrap();
 
         assert_eq!(compute_error_gradients(&function, &dataset),
-                   vector!(0.0, 0.0));
+                   Ok(vector!(0.0, 0.0)));
     }
 
     #[test]
     fn compute_error_gradient_with_errors_1() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), 0.67),
-                           (vector!(1.0), 2.0),
-                           (vector!(0.0), 1.0),
-                           (vector!(4.2), 5.2),
-                           (vector!(13.36), 14.36),
-                           (vector!(3.13), 4.13),
-                           (vector!(1.33), 2.33));
-        let gradients = compute_error_gradients(&function, &dataset).into_vec();
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), 0.67),
+                                                           (vector!(1.0), 2.0),
+                                                           (vector!(0.0), 1.0),
+                                                           (vector!(4.2), 5.2),
+                                                           (vector!(13.36), 14.36),
+                                                           (vector!(3.13), 4.13),
+                                                           (vector!(1.33), 2.33))).unwrap();
+        let gradients = compute_error_gradients(&function, &dataset).unwrap().into_vec();
 
         assert_eq!(2, gradients.len());
         assert_relative_eq!(gradients.as_slice()[0], -2.0, epsilon = f64::EPSILON);
@@ -180,14 +459,14 @@ mod tests {
     #[test]
     fn compute_error_gradient_with_errors_minus_1() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), -1.33),
-                           (vector!(1.0), 0.0),
-                           (vector!(0.0), -1.0),
-                           (vector!(4.2), 3.2),
-                           (vector!(13.36), 12.36),
-                           (vector!(3.13), 2.13),
-                           (vector!(1.33), 0.33));
-        let gradients = compute_error_gradients(&function, &dataset).into_vec();
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), -1.33),
+                                                           (vector!(1.0), 0.0),
+                                                           (vector!(0.0), -1.0),
+                                                           (vector!(4.2), 3.2),
+                                                           (vector!(13.36), 12.36),
+                                                           (vector!(3.13), 2.13),
+                                                           (vector!(1.33), 0.33))).unwrap();
+        let gradients = compute_error_gradients(&function, &dataset).unwrap().into_vec();
 
         assert_eq!(2, gradients.len());
         assert_relative_eq!(gradients.as_slice()[0], 2.0, epsilon = f64::EPSILON);
@@ -197,16 +476,17 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::neg_multiply)]
     fn compute_error_gradients_with_non_squared_errors() {
         let function = build_test_function();
-        let dataset = vec!((vector!(-0.33), -2.33),
-                           (vector!(1.0), 4.0),
-                           (vector!(0.0), -4.0),
-                           (vector!(4.2), 6.2),
-                           (vector!(13.36), 16.36),
-                           (vector!(3.13), 7.13),
-                           (vector!(1.33), 0.33));
-        let gradients = compute_error_gradients(&function, &dataset).into_vec();
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-0.33), -2.33),
+                                                           (vector!(1.0), 4.0),
+                                                           (vector!(0.0), -4.0),
+                                                           (vector!(4.2), 6.2),
+                                                           (vector!(13.36), 16.36),
+                                                           (vector!(3.13), 7.13),
+                                                           (vector!(1.33), 0.33))).unwrap();
+        let gradients = compute_error_gradients(&function, &dataset).unwrap().into_vec();
 
         assert_eq!(2, gradients.len());
         assert_relative_eq!(gradients.as_slice()[0],
@@ -216,4 +496,309 @@ mod tests {
                             -(2.0 / 7.0) * (-2.0 * -0.33 + 3.0 * 1.0 + -4.0 * 0.0 + 2.0 * 4.2 + 3.0 * 13.36 + 4.0 * 3.13 + -1.0 * 1.33),
                             epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn least_squares_qr_fit_exact_line() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-1.0), -1.0),
+                                                           (vector!(0.0), 1.0),
+                                                           (vector!(1.0), 3.0),
+                                                           (vector!(2.0), 5.0))).unwrap();
+
+        // f(x) = 1 + 2x
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-14);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn least_squares_qr_fit_two_input_variables() {
+        let mut function = LinearFunction::new(2);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0, 3.0), 4.0),
+                                                           (vector!(1.0, 2.0), 5.0),
+                                                           (vector!(2.0, 1.0), 6.0),
+                                                           (vector!(3.0, 1.0), 8.0))).unwrap();
+
+        // f(x, y) = 1 + 2x + y
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-13);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-13);
+        assert_relative_eq!(function.parameters()[2], 1.0, epsilon = 1e-13);
+    }
+
+    #[test]
+    fn least_squares_qr_fit_empty_dataset() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!()).unwrap();
+
+        assert_eq!(least_squares_qr_fit(&mut function, &dataset), Err(Error::EmptyDataset));
+    }
+
+    #[test]
+    fn least_squares_qr_fit_wrong_number_of_input_variables() {
+        let mut function = LinearFunction::new(2);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0), (vector!(1.0), 3.0))).unwrap();
+
+        assert_eq!(least_squares_qr_fit(&mut function, &dataset),
+                   Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+
+    #[test]
+    fn least_squares_fit_mini_batches() {
+        // f(x) = 1 + 2x
+        let (function, report) = fit_exact_line(BatchMode::MiniBatch { batch_size: 3, seed: 7 }, 2000);
+
+        assert_eq!(report.losses().len(), 2001);
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn least_squares_fit_stochastic() {
+        // f(x) = 1 + 2x
+        let (function, _) = fit_exact_line(BatchMode::Stochastic { seed: 7 }, 1000);
+
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn least_squares_fit_shuffles_from_seed() {
+        let (_, report1) = fit_exact_line(BatchMode::MiniBatch { batch_size: 3, seed: 7 }, 5);
+        let (_, report2) = fit_exact_line(BatchMode::MiniBatch { batch_size: 3, seed: 7 }, 5);
+        let (_, report3) = fit_exact_line(BatchMode::MiniBatch { batch_size: 3, seed: 8 }, 5);
+
+        assert_eq!(report1.losses(), report2.losses());
+        assert_ne!(report1.losses(), report3.losses());
+    }
+
+    #[test]
+    fn least_squares_penalized_qr_fit_ridge() {
+        let mut function = LinearFunction::new(1);
+
+        // slope cov(x, y) / (var(x) + alpha) = 2.5 / 2.5, and the unpenalized intercept 2 - 0.5
+        least_squares_penalized_qr_fit(&mut function, &build_line_dataset(), &Penalty::ridge(1.25).with_unpenalized_parameters(1)).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.5, epsilon = 1e-14);
+        assert_relative_eq!(function.parameters()[1], 1.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn least_squares_penalized_qr_fit_lasso() {
+        let mut function = LinearFunction::new(1);
+
+        assert_eq!(least_squares_penalized_qr_fit(&mut function, &build_line_dataset(), &Penalty::lasso(1.0)),
+                   Err(Error::InvalidSetting("the QR decomposition only solves L2 penalties".to_string())));
+    }
+
+    #[test]
+    fn least_squares_penalized_fit_ridge() {
+        let mut function = LinearFunction::new(1);
+        let mut settings = GradientDescent::new(Sgd::new(0.1), StoppingCriteria::new(1000));
+
+        least_squares_penalized_fit(&mut function, &build_line_dataset(), &Penalty::ridge(1.25).with_unpenalized_parameters(1), &mut settings).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.5, epsilon = 1e-10);
+        assert_relative_eq!(function.parameters()[1], 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn least_squares_coordinate_descent_fit_ridge() {
+        let mut function = LinearFunction::new(1);
+        let report = least_squares_coordinate_descent_fit(&mut function,
+                                                          &build_line_dataset(),
+                                                          &Penalty::ridge(1.25).with_unpenalized_parameters(1),
+                                                          &coordinate_descent_criteria()).unwrap();
+
+        assert_eq!(report.stop_reason(), StopReason::ParameterTolerance);
+        assert_relative_eq!(function.parameters()[0], 1.5, epsilon = 1e-12);
+        assert_relative_eq!(function.parameters()[1], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn least_squares_coordinate_descent_fit_lasso() {
+        let mut function = LinearFunction::new(1);
+
+        // slope soft_threshold(cov(x, y), alpha / 2) / var(x) = 2 / 1.25
+        least_squares_coordinate_descent_fit(&mut function,
+                                             &build_line_dataset(),
+                                             &Penalty::lasso(1.0).with_unpenalized_parameters(1),
+                                             &coordinate_descent_criteria()).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.2, epsilon = 1e-12);
+        assert_relative_eq!(function.parameters()[1], 1.6, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn least_squares_penalized_fits_without_intercept() {
+        let mut settings = GradientDescent::new(Sgd::new(0.1), StoppingCriteria::new(1000));
+        let mut qr_function = LinearFunction::without_intercept(1);
+        let mut gradient_descent_function = LinearFunction::without_intercept(1);
+        let mut coordinate_descent_function = LinearFunction::without_intercept(1);
+
+        // the only parameter is penalized: slope mean(xy) / (mean(x^2) + alpha) = 3.5 / (1.5 + 0.5)
+        least_squares_penalized_qr_fit(&mut qr_function, &build_line_dataset(), &Penalty::ridge(0.5)).unwrap();
+        least_squares_penalized_fit(&mut gradient_descent_function, &build_line_dataset(), &Penalty::ridge(0.5), &mut settings).unwrap();
+        // soft_threshold(mean(xy), alpha / 2) / mean(x^2) = 3 / 1.5
+        least_squares_coordinate_descent_fit(&mut coordinate_descent_function,
+                                             &build_line_dataset(),
+                                             &Penalty::lasso(1.0),
+                                             &coordinate_descent_criteria()).unwrap();
+
+        assert_relative_eq!(qr_function.parameters()[0], 1.75, epsilon = 1e-14);
+        assert_relative_eq!(gradient_descent_function.parameters()[0], 1.75, epsilon = 1e-10);
+        assert_relative_eq!(coordinate_descent_function.parameters()[0], 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn least_squares_coordinate_descent_fit_empty_dataset() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!()).unwrap();
+
+        assert_eq!(least_squares_coordinate_descent_fit(&mut function, &dataset, &Penalty::lasso(1.0), &coordinate_descent_criteria()).err(),
+                   Some(Error::EmptyDataset));
+    }
+
+    #[test]
+    fn least_squares_regularization_path_lasso() {
+        let mut function = LinearFunction::new(1);
+        let path = least_squares_regularization_path(&mut function,
+                                                     &build_line_dataset(),
+                                                     &Penalty::lasso(0.0).with_unpenalized_parameters(1),
+                                                     &[10.0, 1.0, 0.0],
+                                                     &coordinate_descent_criteria()).unwrap();
+
+        assert_eq!(path.len(), 3);
+        // strong enough to zero the slope
+        assert_relative_eq!(path[0][0], 2.0, epsilon = 1e-12);
+        assert_eq!(path[0][1], 0.0);
+        assert_relative_eq!(path[1][1], 1.6, epsilon = 1e-12);
+        assert_relative_eq!(path[2][0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(path[2][1], 2.0, epsilon = 1e-12);
+        assert_eq!(function.parameters(), &path[2]);
+    }
+
+    #[test]
+    fn least_squares_fit_polynomial() {
+        let mut function = PolynomialFunction::with_degree(2, 2);
+        // f(x, y) = 1 - x y + 2 y^2
+        let samples = (0..25).map(|i| {
+                                 let x = (i % 5) as f64 / 2.0 - 1.0;
+                                 let y = (i / 5) as f64 / 2.0 - 1.0;
+
+                                 (vector!(x, y), 1.0 - x * y + 2.0 * y * y)
+                             })
+                             .collect();
+        let dataset = SupervisedDataset::from_samples(samples).unwrap();
+        let mut settings = GradientDescent::new(Adam::new(0.05), StoppingCriteria::new(5000));
+
+        least_squares_fit(&mut function, &dataset, &mut settings).unwrap();
+
+        for (parameter, expected) in function.parameters().iter().zip([1.0, 0.0, 0.0, 0.0, -1.0, 2.0].iter()) {
+            assert_relative_eq!(parameter, expected, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn least_squares_parameter_covariance_of_line() {
+        let mut function = LinearFunction::new(1);
+        // y = 1 + 2x with residuals (-1, 2, -1), s^2 = 6 / 1
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0),
+                                                           (vector!(1.0), 5.0),
+                                                           (vector!(2.0), 4.0))).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        let covariance = least_squares_parameter_covariance(&function, &dataset).unwrap();
+
+        assert_relative_eq!(covariance[[0, 0]], 5.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[[0, 1]], -3.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[[1, 1]], 3.0, epsilon = 1e-12);
+
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0), (vector!(1.0), 5.0))).unwrap();
+
+        assert_eq!(least_squares_parameter_covariance(&function, &dataset).err(),
+                   Some(Error::InvalidSetting("2 samples cannot estimate the covariance of 2 parameters".to_string())));
+    }
+
+    #[test]
+    fn least_squares_qr_fit_weighted() {
+        let mut function = LinearFunction::new(1);
+
+        least_squares_qr_fit(&mut function, &build_weighted_line_dataset()).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.225, epsilon = 1e-14);
+        assert_relative_eq!(function.parameters()[1], 1.2, epsilon = 1e-14);
+
+        // a sample weighing 0 is ignored
+        let dataset = build_weighted_line_dataset().with_weights(vec!(1.0, 1.0, 0.0, 1.0)).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        let mut unweighted_function = LinearFunction::new(1);
+
+        least_squares_qr_fit(&mut unweighted_function, &build_weighted_line_dataset().select(&[0, 1, 3])
+                                                                                      .with_weights(vec![1.0; 3]).unwrap()).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], unweighted_function.parameters()[0], epsilon = 1e-14);
+        assert_relative_eq!(function.parameters()[1], unweighted_function.parameters()[1], epsilon = 1e-14);
+    }
+
+    #[test]
+    fn least_squares_fit_weighted() {
+        let mut function = LinearFunction::new(1);
+        let mut settings = GradientDescent::new(Sgd::new(0.1), StoppingCriteria::new(2000));
+
+        least_squares_fit(&mut function, &build_weighted_line_dataset(), &mut settings).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.225, epsilon = 1e-10);
+        assert_relative_eq!(function.parameters()[1], 1.2, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn least_squares_penalized_fits_weighted_as_repeated_samples() {
+        let penalties = [Penalty::ridge(0.5), Penalty::lasso(0.5), Penalty::elastic_net(0.5, 0.5)];
+
+        for penalty in penalties.iter().map(|penalty| penalty.with_unpenalized_parameters(1)) {
+            let mut weighted_function = LinearFunction::new(1);
+            let mut repeated_function = LinearFunction::new(1);
+
+            least_squares_coordinate_descent_fit(&mut weighted_function,
+                                                 &build_weighted_line_dataset(),
+                                                 &penalty,
+                                                 &coordinate_descent_criteria()).unwrap();
+            least_squares_coordinate_descent_fit(&mut repeated_function,
+                                                 &build_repeated_line_dataset(),
+                                                 &penalty,
+                                                 &coordinate_descent_criteria()).unwrap();
+
+            assert_relative_eq!(weighted_function.parameters()[0], repeated_function.parameters()[0], epsilon = 1e-12);
+            assert_relative_eq!(weighted_function.parameters()[1], repeated_function.parameters()[1], epsilon = 1e-12);
+        }
+
+        let mut weighted_function = LinearFunction::new(1);
+
+        // slope cov(x, y) / (var(x) + alpha) = 1.5 / 1.75
+        least_squares_penalized_qr_fit(&mut weighted_function, &build_weighted_line_dataset(), &Penalty::ridge(0.5).with_unpenalized_parameters(1)).unwrap();
+
+        assert_relative_eq!(weighted_function.parameters()[1], 1.5 / 1.75, epsilon = 1e-14);
+        assert_relative_eq!(weighted_function.parameters()[0], 3.625 - 2.0 * 1.5 / 1.75, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn least_squares_parameter_covariance_weighted() {
+        let mut function = LinearFunction::new(1);
+        let dataset = build_weighted_line_dataset();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        // weighted RSS 3.475 over 2 degrees of freedom, and a weighted sum of squares of x of 10
+        let covariance = least_squares_parameter_covariance(&function, &dataset).unwrap();
+
+        assert_relative_eq!(covariance[[0, 0]], 2919.0 / 3200.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[[0, 1]], -139.0 / 400.0, epsilon = 1e-12);
+        assert_relative_eq!(covariance[[1, 1]], 139.0 / 800.0, epsilon = 1e-12);
+    }
 }
@@ -0,0 +1,208 @@
+use rulinalg::matrix::{Matrix, BaseMatrix};
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::qr_decomposition::QrDecomposition;
+
+const INITIAL_DAMPING: f64 = 1e-3;
+const MAX_DAMPING: f64 = 1e20;
+const SUM_OF_SQUARES_TOLERANCE: f64 = 1e-15;
+const STEP_TOLERANCE: f64 = 1e-15;
+
+// Residuals scaled by the square root of their sample weight.
+fn compute_residuals<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<Vec<f64>>
+where F: ParametricFunction + ?Sized {
+    dataset.weighted_iter().map(|(x, y, weight)| Ok(weight.sqrt() * (y - function.f(&x)?))).collect()
+}
+
+fn sum_of_squares(values: &[f64]) -> f64 {
+    values.iter().map(|v| v.powi(2)).sum()
+}
+
+// The Jacobian of the function over the dataset, made of one parameter_gradients() row per input
+// scaled by the square root of its sample weight.
+fn compute_jacobian<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<Matrix<f64>>
+where F: ParametricFunction + ?Sized {
+    let parameters_size = function.parameters().size();
+    let mut jacobian = Vec::with_capacity(dataset.len() * parameters_size);
+
+    for (x, _, weight) in dataset.weighted_iter() {
+        jacobian.extend((function.parameter_gradients(&x)? * weight.sqrt()).into_vec());
+    }
+
+    Ok(Matrix::new(dataset.len(), parameters_size, jacobian))
+}
+
+// Solves min ||r - J step||^2 + damping * ||D step||^2 through the QR decomposition of the
+// augmented system [J D^-1; sqrt(damping) I] (D step) = [r; 0]. Working on the scaled step keeps
+// the decomposition well conditioned when the parameters have very different magnitudes.
+fn compute_step(jacobian: &Matrix<f64>, residuals: &[f64], scale: &[f64], damping: f64) -> Result<Vector<f64>> {
+    let rows = jacobian.rows();
+    let cols = jacobian.cols();
+    let design = Matrix::from_fn(rows + cols, cols, |j, i| {
+        if i < rows {
+            jacobian[[i, j]] / scale[j]
+        } else if i - rows == j {
+            damping.sqrt()
+        } else {
+            0.0
+        }
+    });
+    let mut targets = residuals.to_vec();
+
+    targets.extend(vec![0.0; cols]);
+
+    let scaled_step = QrDecomposition::new(design, Vector::new(targets))?.solve()?;
+
+    Ok(Vector::new(scaled_step.iter().zip(scale.iter()).map(|(d, s)| d / s).collect::<Vec<f64>>()))
+}
+
+// Levenberg-Marquardt fit of any ParametricFunction, starting from its current parameters.
+//
+// The damping is scaled by the Jacobian column norms (Marquardt) and updated from the ratio between
+// the actual and predicted reductions of the sum of squares (Nielsen). Returns the average squared
+// error, weighted by the sample weights, after each iteration.
+pub fn levenberg_marquardt_fit<F>(function: &mut F,
+                                  dataset: &SupervisedDataset<f64>,
+                                  max_iterations: u32) -> Result<Vec<f64>>
+where F: ParametricFunction + ?Sized {
+    if dataset.is_empty() {
+        return Err(Error::EmptyDataset);
+    }
+
+    let n = dataset.total_weight();
+    let mut errors = vec!();
+    let mut scale = vec![0.0; function.parameters().size()];
+    let mut damping = INITIAL_DAMPING;
+    let mut damping_factor = 2.0;
+    let mut residuals = compute_residuals(function, dataset)?;
+    let mut residual_sum_of_squares = sum_of_squares(&residuals);
+
+    for _ in 0..max_iterations {
+        let jacobian = compute_jacobian(function, dataset)?;
+        let parameters = function.parameters().clone();
+
+        for (j, s) in scale.iter_mut().enumerate() {
+            let column_norm = (0..jacobian.rows()).map(|i| jacobian[[i, j]].powi(2)).sum::<f64>().sqrt();
+
+            *s = f64::max(*s, column_norm);
+
+            if *s == 0.0 {
+                *s = 1.0;
+            }
+        }
+
+        loop {
+            let step = compute_step(&jacobian, &residuals, &scale, damping)?;
+            let predicted_residuals = (&jacobian * &step).into_vec();
+            let predicted_reduction = residual_sum_of_squares - residuals.iter()
+                                                                         .zip(predicted_residuals.iter())
+                                                                         .map(|(r, p)| (r - p).powi(2))
+                                                                         .sum::<f64>();
+
+            function.set_parameters(&parameters + &step)?;
+
+            let new_residuals = compute_residuals(function, dataset)?;
+            let new_residual_sum_of_squares = sum_of_squares(&new_residuals);
+            let actual_reduction = residual_sum_of_squares - new_residual_sum_of_squares;
+            let ratio = actual_reduction / predicted_reduction;
+
+            if new_residual_sum_of_squares.is_finite() && ratio > 0.0 {
+                let scaled_step = step.iter().zip(scale.iter()).map(|(d, s)| (d * s).powi(2)).sum::<f64>().sqrt();
+                let scaled_parameters = parameters.iter().zip(scale.iter()).map(|(p, s)| (p * s).powi(2)).sum::<f64>().sqrt();
+                let converged = (actual_reduction <= SUM_OF_SQUARES_TOLERANCE * residual_sum_of_squares
+                                 && predicted_reduction <= SUM_OF_SQUARES_TOLERANCE * residual_sum_of_squares)
+                                || scaled_step <= STEP_TOLERANCE * scaled_parameters;
+
+                damping *= (1.0 - (2.0 * ratio - 1.0).powi(3)).max(1.0 / 3.0);
+                damping_factor = 2.0;
+                residuals = new_residuals;
+                residual_sum_of_squares = new_residual_sum_of_squares;
+                errors.push(residual_sum_of_squares / n);
+
+                if converged {
+                    return Ok(errors);
+                }
+
+                break;
+            }
+
+            function.set_parameters(parameters.clone())?;
+            damping *= damping_factor;
+            damping_factor *= 2.0;
+
+            // no step reduces the sum of squares anymore
+            if damping > MAX_DAMPING {
+                return Ok(errors);
+            }
+        }
+    }
+
+    Ok(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+
+    use super::compute_residuals;
+    use super::compute_jacobian;
+    use super::levenberg_marquardt_fit;
+
+    #[test]
+    fn compute_residuals_of_dataset() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0), (vector!(1.0), 1.0), (vector!(2.0), 5.0))).unwrap();
+
+        // f(x) = x
+        function.set_parameters(vector!(0.0, 1.0)).unwrap();
+
+        assert_eq!(compute_residuals(&function, &dataset), Ok(vec!(1.0, 0.0, 3.0)));
+    }
+
+    #[test]
+    fn compute_jacobian_of_linear_function() {
+        let function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0), (vector!(1.0), 1.0), (vector!(2.0), 5.0))).unwrap();
+        let jacobian = compute_jacobian(&function, &dataset).unwrap();
+
+        assert_eq!(jacobian.data(), &vec!(1.0, 0.0, 1.0, 1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn levenberg_marquardt_fit_exact_line() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(-1.0), -1.0),
+                                                           (vector!(0.0), 1.0),
+                                                           (vector!(1.0), 3.0),
+                                                           (vector!(2.0), 5.0))).unwrap();
+
+        // f(x) = 1 + 2x
+        let errors = levenberg_marquardt_fit(&mut function, &dataset, 100).unwrap();
+
+        assert!(errors.len() < 100);
+        assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn levenberg_marquardt_fit_weighted_line() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0),
+                                                           (vector!(1.0), 3.0),
+                                                           (vector!(2.0), 2.0),
+                                                           (vector!(3.0), 5.0))).unwrap()
+                                         .with_weights(vec!(1.0, 2.0, 1.0, 4.0)).unwrap();
+
+        // f(x) = 1.225 + 1.2x, with a weighted residual sum of squares of 3.475
+        let errors = levenberg_marquardt_fit(&mut function, &dataset, 100).unwrap();
+
+        assert_relative_eq!(function.parameters()[0], 1.225, epsilon = 1e-10);
+        assert_relative_eq!(function.parameters()[1], 1.2, epsilon = 1e-10);
+        assert_relative_eq!(errors[errors.len() - 1], 3.475 / 8.0, epsilon = 1e-10);
+    }
+}
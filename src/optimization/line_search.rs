@@ -0,0 +1,293 @@
+use rulinalg::vector::Vector;
+
+use Result;
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::LineSearch;
+
+const MAX_EVALUATIONS: u32 = 50;
+
+// Loss and gradients at a trial step along the search direction.
+struct Trial {
+    step: f64,
+    loss: f64,
+    gradients: Vector<f64>,
+    slope: f64
+}
+
+// Point of a line search where the loss and its gradients are known: the starting point, then the
+// accepted step, where the function parameters are left.
+pub struct LinePoint {
+    pub step: f64,
+    pub loss: f64,
+    pub gradients: Vector<f64>
+}
+
+struct Line<'a, F: 'a + ?Sized, A: 'a, G: 'a> {
+    dataset: &'a SupervisedDataset<f64>,
+    parametric_function: &'a mut F,
+    compute_error_average: &'a A,
+    compute_error_gradients: &'a G,
+    origin: Vector<f64>,
+    direction: &'a Vector<f64>
+}
+
+impl<'a, F, A, G> Line<'a, F, A, G>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    fn move_to(&mut self, step: f64) -> Result<()> {
+        self.parametric_function.set_parameters(&self.origin + self.direction * step)
+    }
+
+    fn loss(&mut self, step: f64) -> Result<f64> {
+        self.move_to(step)?;
+
+        (self.compute_error_average)(self.parametric_function, self.dataset)
+    }
+
+    fn trial(&mut self, step: f64) -> Result<Trial> {
+        let loss = self.loss(step)?;
+        let gradients = (self.compute_error_gradients)(self.parametric_function, self.dataset)?;
+        let slope = gradients.dot(self.direction);
+
+        Ok(Trial { step, loss, gradients, slope })
+    }
+}
+
+// Searches a step along `direction` from the current parameters of the function, whose loss and
+// gradients are given by `start`. Returns the accepted point, or None when no step decreases the
+// loss sufficiently, in which case the parameters are left unchanged.
+pub fn line_search<F, A, G>(line_search: LineSearch,
+                            dataset: &SupervisedDataset<f64>,
+                            parametric_function: &mut F,
+                            compute_error_average: &A,
+                            compute_error_gradients: &G,
+                            start: &LinePoint,
+                            direction: &Vector<f64>) -> Result<Option<LinePoint>>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    let origin = parametric_function.parameters().clone();
+    let mut line = Line { dataset, parametric_function, compute_error_average, compute_error_gradients, origin, direction };
+    let slope = start.gradients.dot(direction);
+    let point = if slope >= 0.0 {
+        // not a descent direction
+        None
+    } else {
+        match line_search {
+            LineSearch::Armijo { initial_step, contraction, sufficient_decrease } => {
+                armijo(&mut line, start.loss, slope, initial_step, contraction, sufficient_decrease)?
+            },
+            LineSearch::StrongWolfe { initial_step, sufficient_decrease, curvature } => {
+                strong_wolfe(&mut line, start.loss, slope, initial_step, sufficient_decrease, curvature)?
+            }
+        }
+    };
+
+    match point {
+        None => line.move_to(0.0)?,
+        Some(ref point) => line.move_to(point.step)?
+    }
+
+    Ok(point)
+}
+
+// Armijo condition, which a NaN loss fails.
+fn decreases_sufficiently(trial_loss: f64, loss: f64, slope: f64, step: f64, sufficient_decrease: f64) -> bool {
+    trial_loss <= loss + sufficient_decrease * step * slope
+}
+
+fn armijo<F, A, G>(line: &mut Line<F, A, G>,
+                   loss: f64,
+                   slope: f64,
+                   initial_step: f64,
+                   contraction: f64,
+                   sufficient_decrease: f64) -> Result<Option<LinePoint>>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    let mut step = initial_step;
+
+    for _ in 0..MAX_EVALUATIONS {
+        let trial_loss = line.loss(step)?;
+
+        if decreases_sufficiently(trial_loss, loss, slope, step, sufficient_decrease) {
+            let gradients = (line.compute_error_gradients)(line.parametric_function, line.dataset)?;
+
+            return Ok(Some(LinePoint { step, loss: trial_loss, gradients }));
+        }
+
+        step *= contraction;
+    }
+
+    Ok(None)
+}
+
+// Algorithm 3.5 of Nocedal & Wright, Numerical Optimization: brackets a step satisfying the strong
+// Wolfe conditions by doubling the trial step, then zooms into the bracket.
+fn strong_wolfe<F, A, G>(line: &mut Line<F, A, G>,
+                         loss: f64,
+                         slope: f64,
+                         initial_step: f64,
+                         sufficient_decrease: f64,
+                         curvature: f64) -> Result<Option<LinePoint>>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    let start = Trial { step: 0.0, loss, gradients: Vector::zeros(0), slope };
+    let mut previous = None;
+    let mut step = initial_step;
+
+    for _ in 0..MAX_EVALUATIONS {
+        let trial = line.trial(step)?;
+        let previous_loss = previous.as_ref().map_or(loss, |previous: &Trial| previous.loss);
+
+        if !decreases_sufficiently(trial.loss, loss, slope, step, sufficient_decrease)
+           || (previous.is_some() && trial.loss >= previous_loss) {
+            let low = previous.unwrap_or(start);
+
+            return zoom(line, loss, slope, low, trial, sufficient_decrease, curvature);
+        }
+        if trial.slope.abs() <= -curvature * slope {
+            return Ok(Some(LinePoint { step: trial.step, loss: trial.loss, gradients: trial.gradients }));
+        }
+        if trial.slope >= 0.0 {
+            let high = previous.unwrap_or(start);
+
+            return zoom(line, loss, slope, trial, high, sufficient_decrease, curvature);
+        }
+
+        previous = Some(trial);
+        step *= 2.0;
+    }
+
+    Ok(None)
+}
+
+// Algorithm 3.6 of Nocedal & Wright: shrinks the bracket [low, high], where low is the best step
+// satisfying the sufficient decrease, around a step satisfying the strong Wolfe conditions.
+fn zoom<F, A, G>(line: &mut Line<F, A, G>,
+                 loss: f64,
+                 slope: f64,
+                 mut low: Trial,
+                 mut high: Trial,
+                 sufficient_decrease: f64,
+                 curvature: f64) -> Result<Option<LinePoint>>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+    for _ in 0..MAX_EVALUATIONS {
+        let trial = line.trial(interpolate(&low, &high))?;
+
+        if !decreases_sufficiently(trial.loss, loss, slope, trial.step, sufficient_decrease) || trial.loss >= low.loss {
+            high = trial;
+        } else {
+            if trial.slope.abs() <= -curvature * slope {
+                return Ok(Some(LinePoint { step: trial.step, loss: trial.loss, gradients: trial.gradients }));
+            }
+            if trial.slope * (high.step - low.step) >= 0.0 {
+                high = low;
+            }
+
+            low = trial;
+        }
+    }
+
+    // the curvature condition could not be met, fall back on the best step found
+    if low.step > 0.0 {
+        return Ok(Some(LinePoint { step: low.step, loss: low.loss, gradients: low.gradients }));
+    }
+
+    Ok(None)
+}
+
+// Minimum of the quadratic matching the losses at both ends and the slope at the low end,
+// safeguarded to stay within the inner 80% of the bracket.
+fn interpolate(low: &Trial, high: &Trial) -> f64 {
+    let width = high.step - low.step;
+    let curvature = (high.loss - low.loss - low.slope * width) / (width * width);
+    let safeguard_low = low.step + 0.1 * width;
+    let safeguard_high = high.step - 0.1 * width;
+    let (min, max) = if width > 0.0 { (safeguard_low, safeguard_high) } else { (safeguard_high, safeguard_low) };
+
+    if curvature > 0.0 && curvature.is_finite() {
+        (low.step - low.slope / (2.0 * curvature)).max(min).min(max)
+    } else {
+        low.step + width / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::vector::Vector;
+
+    use Result;
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::LineSearch;
+
+    use super::LinePoint;
+    use super::line_search;
+
+    // (b0 - 1)^2 + 100 (b1 - 2)^2
+    fn loss(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<f64> {
+        let parameters = function.parameters();
+
+        Ok((parameters[0] - 1.0).powi(2) + 100.0 * (parameters[1] - 2.0).powi(2))
+    }
+
+    fn gradients(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+        let parameters = function.parameters();
+
+        Ok(vector!(2.0 * (parameters[0] - 1.0), 200.0 * (parameters[1] - 2.0)))
+    }
+
+    fn search(method: LineSearch, direction: Vector<f64>) -> (LinearFunction, Option<LinePoint>, LinePoint) {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let start = LinePoint { step: 0.0, loss: loss(&function, &dataset).unwrap(), gradients: gradients(&function, &dataset).unwrap() };
+        let point = line_search(method, &dataset, &mut function, &loss, &gradients, &start, &direction).unwrap();
+
+        (function, point, start)
+    }
+
+    #[test]
+    fn armijo_decreases_loss_sufficiently() {
+        let (function, point, start) = search(LineSearch::armijo(), vector!(2.0, 400.0));
+        let point = point.unwrap();
+
+        assert!(point.loss <= start.loss + 1e-4 * point.step * start.gradients.dot(&vector!(2.0, 400.0)));
+        assert_eq!(function.parameters(), &(vector!(2.0, 400.0) * point.step));
+    }
+
+    #[test]
+    fn strong_wolfe_satisfies_both_conditions() {
+        let direction = vector!(2.0, 400.0);
+        let (function, point, start) = search(LineSearch::strong_wolfe(), direction.clone());
+        let point = point.unwrap();
+        let slope = start.gradients.dot(&direction);
+
+        assert!(point.loss <= start.loss + 1e-4 * point.step * slope);
+        assert!(point.gradients.dot(&direction).abs() <= 0.9 * slope.abs());
+        assert_eq!(function.parameters(), &(direction * point.step));
+    }
+
+    #[test]
+    fn strong_wolfe_exact_on_quadratic_along_one_axis() {
+        let (_, point, _) = search(LineSearch::StrongWolfe { initial_step: 4.0, sufficient_decrease: 1e-4, curvature: 0.1 },
+                                   vector!(1.0, 0.0));
+
+        // the quadratic interpolation finds the minimum at b0 = 1
+        assert_relative_eq!(point.unwrap().step, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn no_step_along_ascent_direction() {
+        let (function, point, _) = search(LineSearch::armijo(), vector!(-1.0, 0.0));
+
+        assert!(point.is_none());
+        assert_eq!(function.parameters(), &vector!(0.0, 0.0));
+    }
+}
@@ -0,0 +1,205 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::TrainingObserver;
+use super::StoppingCriteria;
+use super::LineSearch;
+use super::FitReport;
+use super::StopReason;
+use super::fit_progress::FitProgress;
+use super::gradient_descent::{norm, compute_loss};
+use super::line_search::{LinePoint, line_search};
+
+// Strategy of a line search method, choosing the direction to search at every iteration.
+pub trait SearchDirection {
+    fn direction(&mut self, gradients: &Vector<f64>) -> Vector<f64>;
+
+    // Called with the accepted step and the matching change of the gradients.
+    fn update(&mut self, step: &Vector<f64>, gradient_changes: &Vector<f64>);
+
+    // Forgets the previous iterations, so that the next direction follows the gradients.
+    fn reset(&mut self);
+}
+
+// Settings shared by the line search methods.
+pub trait LineSearchSettings {
+    fn stopping_criteria(&self) -> &StoppingCriteria;
+    fn line_search(&self) -> LineSearch;
+    fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>];
+}
+
+// Full batch descent along the directions of `search_direction`, whose steps are chosen by a line
+// search. A direction that does not decrease the loss is retried along the gradients before
+// stopping with a line search failure.
+pub fn line_search_fit<F, A, G, D, S>(dataset: &SupervisedDataset<f64>,
+                                      parametric_function: &mut F,
+                                      compute_error_average: &A,
+                                      compute_error_gradients: &G,
+                                      search_direction: &mut D,
+                                      settings: &mut S) -> Result<FitReport>
+where F: ParametricFunction + ?Sized,
+      A: Fn(&F, &SupervisedDataset<f64>) -> Result<f64>,
+      G: Fn(&F, &SupervisedDataset<f64>) -> Result<Vector<f64>>,
+      D: SearchDirection,
+      S: LineSearchSettings {
+    if dataset.is_empty() {
+        return Err(Error::EmptyDataset);
+    }
+
+    let method = settings.line_search();
+    let mut progress = FitProgress::new(settings.stopping_criteria(), compute_loss(parametric_function, dataset, compute_error_average)?);
+    let mut gradients = compute_error_gradients(parametric_function, dataset)?;
+
+    search_direction.reset();
+
+    let stop_reason = loop {
+        if let Some(stop_reason) = progress.stop_reason_before_iteration() {
+            break stop_reason;
+        }
+
+        let origin = LinePoint { step: 0.0, loss: progress.loss(), gradients };
+        let mut direction = search_direction.direction(&origin.gradients);
+        let mut point = line_search(method,
+                                    dataset,
+                                    parametric_function,
+                                    compute_error_average,
+                                    compute_error_gradients,
+                                    &origin,
+                                    &direction)?;
+
+        if point.is_none() {
+            search_direction.reset();
+            direction = search_direction.direction(&origin.gradients);
+            point = line_search(method,
+                                dataset,
+                                parametric_function,
+                                compute_error_average,
+                                compute_error_gradients,
+                                &origin,
+                                &direction)?;
+        }
+
+        let point = match point {
+            Some(point) => point,
+            None => break StopReason::LineSearchFailure
+        };
+        let step = &direction * point.step;
+        let gradient_norm = norm(&origin.gradients);
+
+        search_direction.update(&step, &(&point.gradients - &origin.gradients));
+        gradients = point.gradients;
+
+        if !point.loss.is_finite() || !gradients.iter().all(|gradient| gradient.is_finite()) {
+            return Err(Error::Divergence);
+        }
+
+        progress.record_iteration(point.loss, parametric_function.parameters(), gradient_norm, settings.observers_mut())?;
+
+        if let Some(stop_reason) = progress.stop_reason_after_iteration(Some(norm(&gradients)), norm(&step)) {
+            break stop_reason;
+        }
+    };
+
+    progress.finish(stop_reason, settings.observers_mut())
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::vector::Vector;
+
+    use {Error, Result};
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::TrainingObserver;
+    use optimization::StoppingCriteria;
+    use optimization::LineSearch;
+    use optimization::StopReason;
+
+    use super::SearchDirection;
+    use super::LineSearchSettings;
+    use super::line_search_fit;
+
+    // (b0 - 1)^2 + (b1 - 2)^2
+    fn distance_to_target(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<f64> {
+        let parameters = function.parameters();
+
+        Ok((parameters[0] - 1.0).powi(2) + (parameters[1] - 2.0).powi(2))
+    }
+
+    fn distance_to_target_gradients(function: &LinearFunction, _dataset: &SupervisedDataset<f64>) -> Result<Vector<f64>> {
+        let parameters = function.parameters();
+
+        Ok(vector!(2.0 * (parameters[0] - 1.0), 2.0 * (parameters[1] - 2.0)))
+    }
+
+    // Searches uphill until reset after the fit started, then along the gradients.
+    struct UphillDirection {
+        resets: u32
+    }
+
+    impl SearchDirection for UphillDirection {
+        fn direction(&mut self, gradients: &Vector<f64>) -> Vector<f64> {
+            if self.resets < 2 { gradients.clone() } else { -gradients }
+        }
+
+        fn update(&mut self, _step: &Vector<f64>, _gradient_changes: &Vector<f64>) {}
+
+        fn reset(&mut self) {
+            self.resets += 1;
+        }
+    }
+
+    struct Settings {
+        stopping_criteria: StoppingCriteria,
+        observers: Vec<Box<dyn TrainingObserver>>
+    }
+
+    impl LineSearchSettings for Settings {
+        fn stopping_criteria(&self) -> &StoppingCriteria {
+            &self.stopping_criteria
+        }
+
+        fn line_search(&self) -> LineSearch {
+            LineSearch::strong_wolfe()
+        }
+
+        fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>] {
+            &mut self.observers
+        }
+    }
+
+    fn fit(dataset: &SupervisedDataset<f64>, direction: &mut UphillDirection) -> Result<(LinearFunction, StopReason)> {
+        let mut function = LinearFunction::new(1);
+        let report = line_search_fit(dataset,
+                                     &mut function,
+                                     &distance_to_target,
+                                     &distance_to_target_gradients,
+                                     direction,
+                                     &mut Settings { stopping_criteria: StoppingCriteria::new(1), observers: vec!() })?;
+
+        Ok((function, report.stop_reason()))
+    }
+
+    #[test]
+    fn retry_along_gradients() {
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0))).unwrap();
+        let mut direction = UphillDirection { resets: 0 };
+        let (function, stop_reason) = fit(&dataset, &mut direction).unwrap();
+
+        // reset when the fit starts, then when the uphill direction fails
+        assert_eq!(direction.resets, 2);
+        assert_eq!(stop_reason, StopReason::MaxIterations);
+        assert_relative_eq!(function.parameters()[0], 1.0);
+        assert_relative_eq!(function.parameters()[1], 2.0);
+    }
+
+    #[test]
+    fn fit_empty_dataset() {
+        let dataset = SupervisedDataset::from_samples(vec!()).unwrap();
+
+        assert_eq!(fit(&dataset, &mut UphillDirection { resets: 0 }).err(), Some(Error::EmptyDataset));
+    }
+}
@@ -0,0 +1,97 @@
+use rulinalg::vector::Vector;
+
+use Result;
+use datasets::SupervisedDataset;
+use super::ParametricFunction;
+use super::Loss;
+use super::GradientDescent;
+use super::FitReport;
+use super::gradient_descent_fit;
+
+// Average loss of the function predictions over the dataset, weighted by the sample weights. A
+// dataset (e.g. a mini-batch) whose samples all weigh 0 has no loss.
+pub fn mean_loss<F, L>(function: &F, dataset: &SupervisedDataset<f64>, loss: &L) -> Result<f64>
+where F: ParametricFunction + ?Sized,
+      L: Loss {
+    let total_weight = dataset.total_weight();
+    let mut loss_sum = 0.0;
+
+    if total_weight == 0.0 {
+        return Ok(0.0);
+    }
+
+    for (x, &y, weight) in dataset.weighted_iter() {
+        loss_sum += weight * loss.value(function.f(&x)?, y);
+    }
+
+    Ok(loss_sum / total_weight)
+}
+
+// Gradients of mean_loss() with respect to the function parameters.
+pub fn mean_loss_gradients<F, L>(function: &F, dataset: &SupervisedDataset<f64>, loss: &L) -> Result<Vector<f64>>
+where F: ParametricFunction + ?Sized,
+      L: Loss {
+    let total_weight = dataset.total_weight();
+    let mut gradients = Vector::zeros(function.parameters().size());
+
+    if total_weight == 0.0 {
+        return Ok(gradients);
+    }
+
+    for (x, &y, weight) in dataset.weighted_iter() {
+        let loss_gradient = loss.gradient(function.f(&x)?, y);
+
+        gradients += function.parameter_gradients(&x)? * (weight * loss_gradient / total_weight);
+    }
+
+    Ok(gradients)
+}
+
+// Gradient descent fit minimizing the average loss. Other fitters, such as `lbfgs_fit`, take
+// mean_loss() and mean_loss_gradients() as closures.
+pub fn loss_fit<F, L>(function: &mut F,
+                      dataset: &SupervisedDataset<f64>,
+                      loss: &L,
+                      settings: &mut GradientDescent) -> Result<FitReport>
+where F: ParametricFunction + ?Sized,
+      L: Loss {
+    gradient_descent_fit(dataset,
+                         function,
+                         &|function: &F, dataset: &SupervisedDataset<f64>| mean_loss(function, dataset, loss),
+                         &|function: &F, dataset: &SupervisedDataset<f64>| mean_loss_gradients(function, dataset, loss),
+                         settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use datasets::SupervisedDataset;
+    use optimization::ParametricFunction;
+    use optimization::LinearFunction;
+    use optimization::losses::SquaredError;
+
+    use super::mean_loss;
+
+    fn build_test_function() -> LinearFunction {
+        let mut function = LinearFunction::new(1);
+
+        // f(x) = x
+        function.set_parameters(vector!(0.0, 1.0)).unwrap();
+
+        function
+    }
+
+    #[test]
+    fn mean_loss_weighted() {
+        let function = build_test_function();
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0),
+                                                           (vector!(1.0), 3.0),
+                                                           (vector!(2.0), 2.0),
+                                                           (vector!(3.0), 5.0))).unwrap()
+                                        .with_weights(vec!(1.0, 2.0, 1.0, 4.0)).unwrap();
+        let repeated_dataset = dataset.select(&[0, 1, 1, 2, 3, 3, 3, 3]).with_weights(vec![1.0; 8]).unwrap();
+
+        // squared errors (1, 4, 0, 4) weighted by (1, 2, 1, 4)
+        assert_relative_eq!(mean_loss(&function, &dataset, &SquaredError).unwrap(), 25.0 / 8.0, epsilon = 1e-14);
+        assert_relative_eq!(mean_loss(&function, &repeated_dataset, &SquaredError).unwrap(), 25.0 / 8.0, epsilon = 1e-14);
+    }
+}
@@ -0,0 +1,30 @@
+use optimization::Loss;
+
+// |target - prediction|, whose minimizer is the median of the targets. The gradient is 0 where the
+// loss is not differentiable.
+pub struct AbsoluteError;
+
+impl Loss for AbsoluteError {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        (target - prediction).abs()
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        if prediction == target { 0.0 } else { (prediction - target).signum() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::AbsoluteError;
+
+    #[test]
+    fn value_and_gradient() {
+        assert_eq!(AbsoluteError.value(1.0, 4.0), 3.0);
+        assert_eq!(AbsoluteError.gradient(1.0, 4.0), -1.0);
+        assert_eq!(AbsoluteError.gradient(4.0, 1.0), 1.0);
+        assert_eq!(AbsoluteError.gradient(1.0, 1.0), 0.0);
+    }
+}
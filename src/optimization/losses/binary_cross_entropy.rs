@@ -0,0 +1,35 @@
+use optimization::Loss;
+use optimization::sigmoid;
+use optimization::cross_entropy::softplus;
+
+// Cross-entropy of a target 0 or 1, the prediction being the logit of the probability that the
+// target is 1:
+//   log(1 + e^prediction) - target prediction
+pub struct BinaryCrossEntropy;
+
+impl Loss for BinaryCrossEntropy {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        softplus(prediction) - target * prediction
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        sigmoid(prediction) - target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    use optimization::Loss;
+
+    use super::BinaryCrossEntropy;
+
+    #[test]
+    fn value_and_gradient() {
+        assert_relative_eq!(BinaryCrossEntropy.value(0.0, 1.0), f64::consts::LN_2);
+        assert_relative_eq!(BinaryCrossEntropy.gradient(0.0, 1.0), -0.5);
+        assert_relative_eq!(BinaryCrossEntropy.value(-1000.0, 0.0), 0.0);
+        assert_relative_eq!(BinaryCrossEntropy.value(-1000.0, 1.0), 1000.0);
+    }
+}
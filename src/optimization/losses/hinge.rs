@@ -0,0 +1,36 @@
+use optimization::Loss;
+
+// Margin loss of linear support vector machines, max(0, 1 - y prediction), where y is -1 for the
+// targets 0 or -1, and 1 otherwise.
+pub struct Hinge;
+
+fn sign(target: f64) -> f64 {
+    if target > 0.0 { 1.0 } else { -1.0 }
+}
+
+impl Loss for Hinge {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        (1.0 - sign(target) * prediction).max(0.0)
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        if sign(target) * prediction < 1.0 { -sign(target) } else { 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::Hinge;
+
+    #[test]
+    fn value_and_gradient() {
+        assert_eq!(Hinge.value(0.5, 1.0), 0.5);
+        assert_eq!(Hinge.gradient(0.5, 1.0), -1.0);
+        assert_eq!(Hinge.value(2.0, 1.0), 0.0);
+        assert_eq!(Hinge.gradient(2.0, 1.0), 0.0);
+        assert_eq!(Hinge.value(0.5, 0.0), 1.5);
+        assert_eq!(Hinge.gradient(0.5, -1.0), 1.0);
+    }
+}
@@ -0,0 +1,52 @@
+use optimization::Loss;
+
+// Squared error for small residuals r = prediction - target, absolute error for large ones:
+//   r^2 / 2 when |r| <= delta
+//   delta (|r| - delta / 2) otherwise
+pub struct Huber {
+    delta: f64
+}
+
+impl Huber {
+    pub fn new(delta: f64) -> Huber {
+        Huber { delta }
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+}
+
+impl Loss for Huber {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        let residual = (prediction - target).abs();
+
+        if residual <= self.delta {
+            residual.powi(2) / 2.0
+        } else {
+            self.delta * (residual - self.delta / 2.0)
+        }
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        (prediction - target).max(-self.delta).min(self.delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::Huber;
+
+    #[test]
+    fn value_and_gradient() {
+        let huber = Huber::new(2.0);
+
+        assert_eq!(huber.value(1.0, 2.0), 0.5);
+        assert_eq!(huber.gradient(1.0, 2.0), -1.0);
+        assert_eq!(huber.value(5.0, 1.0), 6.0);
+        assert_eq!(huber.gradient(5.0, 1.0), 2.0);
+        assert_eq!(huber.gradient(-5.0, 1.0), -2.0);
+    }
+}
@@ -0,0 +1,35 @@
+use std::f64;
+
+use optimization::Loss;
+
+// log(cosh(prediction - target)), close to the squared error for small residuals and to the
+// absolute error for large ones, while being twice differentiable.
+pub struct LogCosh;
+
+impl Loss for LogCosh {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        let residual = (prediction - target).abs();
+
+        // log((e^r + e^-r) / 2), without overflowing for large residuals
+        residual + (-2.0 * residual).exp().ln_1p() - f64::consts::LN_2
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        (prediction - target).tanh()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::LogCosh;
+
+    #[test]
+    fn value_and_gradient() {
+        assert_eq!(LogCosh.value(1.0, 1.0), 0.0);
+        assert_relative_eq!(LogCosh.value(3.0, 1.0), 2.0f64.cosh().ln(), epsilon = 1e-15);
+        assert_relative_eq!(LogCosh.value(1001.0, 1.0), 1000.0 - 2.0f64.ln());
+        assert_relative_eq!(LogCosh.gradient(3.0, 1.0), 2.0f64.tanh());
+    }
+}
@@ -0,0 +1,17 @@
+mod squared_error;
+mod absolute_error;
+mod huber;
+mod log_cosh;
+mod quantile;
+mod poisson_deviance;
+mod binary_cross_entropy;
+mod hinge;
+
+pub use self::squared_error::SquaredError;
+pub use self::absolute_error::AbsoluteError;
+pub use self::huber::Huber;
+pub use self::log_cosh::LogCosh;
+pub use self::quantile::Quantile;
+pub use self::poisson_deviance::PoissonDeviance;
+pub use self::binary_cross_entropy::BinaryCrossEntropy;
+pub use self::hinge::Hinge;
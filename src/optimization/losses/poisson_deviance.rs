@@ -0,0 +1,35 @@
+use optimization::Loss;
+
+// Deviance of a Poisson distribution for count targets, the prediction being the logarithm of the
+// mean (log link) so that any prediction is valid:
+//   2 (target log(target / mean) - target + mean), with mean = e^prediction
+pub struct PoissonDeviance;
+
+impl Loss for PoissonDeviance {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        // target log(target) tends to 0 as target tends to 0
+        let entropy = if target == 0.0 { 0.0 } else { target * target.ln() };
+
+        2.0 * (entropy - target * prediction - target + prediction.exp())
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        2.0 * (prediction.exp() - target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::PoissonDeviance;
+
+    #[test]
+    fn value_and_gradient() {
+        // null at the target mean
+        assert_relative_eq!(PoissonDeviance.value(3.0f64.ln(), 3.0), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(PoissonDeviance.gradient(3.0f64.ln(), 3.0), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(PoissonDeviance.value(0.0, 0.0), 2.0);
+        assert_relative_eq!(PoissonDeviance.gradient(0.0, 3.0), -4.0);
+    }
+}
@@ -0,0 +1,58 @@
+use optimization::Loss;
+
+// Pinball loss, whose minimizer is the given quantile of the targets. With the residual
+// r = target - prediction:
+//   quantile r when r >= 0
+//   (quantile - 1) r otherwise
+pub struct Quantile {
+    quantile: f64
+}
+
+impl Quantile {
+    pub fn new(quantile: f64) -> Quantile {
+        Quantile { quantile }
+    }
+
+    pub fn quantile(&self) -> f64 {
+        self.quantile
+    }
+}
+
+impl Loss for Quantile {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        let residual = target - prediction;
+
+        if residual >= 0.0 {
+            self.quantile * residual
+        } else {
+            (self.quantile - 1.0) * residual
+        }
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        if target > prediction {
+            -self.quantile
+        } else if target < prediction {
+            1.0 - self.quantile
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::Quantile;
+
+    #[test]
+    fn value_and_gradient() {
+        let quantile = Quantile::new(0.9);
+
+        assert_relative_eq!(quantile.value(1.0, 3.0), 1.8);
+        assert_relative_eq!(quantile.gradient(1.0, 3.0), -0.9);
+        assert_relative_eq!(quantile.value(3.0, 1.0), 0.2);
+        assert_relative_eq!(quantile.gradient(3.0, 1.0), 0.1);
+    }
+}
@@ -0,0 +1,57 @@
+use optimization::Loss;
+
+// (target - prediction)^2
+pub struct SquaredError;
+
+impl Loss for SquaredError {
+    fn value(&self, prediction: f64, target: f64) -> f64 {
+        (target - prediction).powi(2)
+    }
+
+    fn gradient(&self, prediction: f64, target: f64) -> f64 {
+        -2.0 * (target - prediction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Loss;
+
+    use super::SquaredError;
+
+    #[test]
+    fn value_and_gradient() {
+        assert_eq!(SquaredError.value(1.0, 4.0), 9.0);
+        assert_eq!(SquaredError.gradient(1.0, 4.0), -6.0);
+        assert_eq!(SquaredError.gradient(4.0, 1.0), 6.0);
+    }
+
+    #[test]
+    fn zero_error() {
+        assert_eq!(SquaredError.value(-3.0, -3.0), 0.0);
+        assert_eq!(SquaredError.value(3.0, 3.0), 0.0);
+        assert_eq!(SquaredError.gradient(-3.0, -3.0), 0.0);
+        assert_eq!(SquaredError.gradient(3.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn gradient_sign() {
+        // twice the prediction minus the target
+        assert_eq!(SquaredError.gradient(-2.0, -3.0), 2.0);
+        assert_eq!(SquaredError.gradient(-2.0, -1.0), -2.0);
+        assert_eq!(SquaredError.gradient(-2.0, -4.0), 4.0);
+        assert_eq!(SquaredError.gradient(-2.0, 0.0), -4.0);
+        assert_eq!(SquaredError.gradient(-2.0, -5.0), 6.0);
+        assert_eq!(SquaredError.gradient(-2.0, 1.0), -6.0);
+        assert_eq!(SquaredError.gradient(-2.0, -6.0), 8.0);
+        assert_eq!(SquaredError.gradient(-2.0, 2.0), -8.0);
+        assert_eq!(SquaredError.gradient(2.0, 1.0), 2.0);
+        assert_eq!(SquaredError.gradient(2.0, 3.0), -2.0);
+        assert_eq!(SquaredError.gradient(2.0, 0.0), 4.0);
+        assert_eq!(SquaredError.gradient(2.0, 4.0), -4.0);
+        assert_eq!(SquaredError.gradient(2.0, -1.0), 6.0);
+        assert_eq!(SquaredError.gradient(2.0, 5.0), -6.0);
+        assert_eq!(SquaredError.gradient(2.0, -2.0), 8.0);
+        assert_eq!(SquaredError.gradient(2.0, 6.0), -8.0);
+    }
+}
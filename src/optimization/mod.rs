@@ -1,12 +1,76 @@
 mod traits;
 pub use self::traits::ParametricFunction;
+pub use self::traits::TrainingObserver;
+pub use self::traits::Optimizer;
+pub use self::traits::LearningRateSchedule;
+pub use self::traits::Loss;
 
 mod types;
 pub use self::types::FunctionParameters;
 pub use self::types::LinearFunction;
+pub use self::types::PolynomialFunction;
+pub use self::types::BasisExpansionFunction;
+pub use self::types::SoftmaxFunction;
+pub use self::types::StoppingCriteria;
+pub use self::types::FitReport;
+pub use self::types::StopReason;
+pub use self::types::IterationEvent;
+pub use self::types::BatchMode;
+pub use self::types::LineSearch;
+pub use self::types::GradientDescent;
+pub use self::types::Lbfgs;
+pub use self::types::ConjugateGradient;
+pub use self::types::ConjugateGradientMethod;
+pub use self::types::Penalty;
+pub use self::types::GradientCheck;
+
+pub mod observers;
+pub mod optimizers;
+pub mod schedules;
+pub mod losses;
+pub mod basis;
+pub mod autodiff;
+
+mod qr_decomposition;
+mod random;
+mod line_search;
+mod line_search_fit;
+mod fit_progress;
 
 mod least_squares;
 pub use self::least_squares::least_squares_fit;
+pub use self::least_squares::least_squares_qr_fit;
+pub use self::least_squares::least_squares_parameter_covariance;
+pub use self::least_squares::least_squares_lbfgs_fit;
+pub use self::least_squares::least_squares_conjugate_gradient_fit;
+pub use self::least_squares::least_squares_penalized_fit;
+pub use self::least_squares::least_squares_penalized_qr_fit;
+pub use self::least_squares::least_squares_coordinate_descent_fit;
+pub use self::least_squares::least_squares_regularization_path;
+
+mod gradient_check;
+pub use self::gradient_check::check_parameter_gradients;
+pub use self::gradient_check::check_input_gradient;
+pub use self::gradient_check::assert_gradients;
+
+mod loss_fit;
+pub use self::loss_fit::mean_loss;
+pub use self::loss_fit::mean_loss_gradients;
+pub use self::loss_fit::loss_fit;
+
+mod cross_entropy;
+pub use self::cross_entropy::sigmoid;
+pub use self::cross_entropy::cross_entropy_fit;
+pub use self::cross_entropy::softmax_cross_entropy_fit;
 
 mod gradient_descent;
 pub use self::gradient_descent::gradient_descent_fit;
+
+mod lbfgs;
+pub use self::lbfgs::lbfgs_fit;
+
+mod conjugate_gradient;
+pub use self::conjugate_gradient::conjugate_gradient_fit;
+
+mod levenberg_marquardt;
+pub use self::levenberg_marquardt::levenberg_marquardt_fit;
@@ -0,0 +1,84 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use Result;
+use optimization::TrainingObserver;
+use optimization::IterationEvent;
+use optimization::FitReport;
+
+// Writes one CSV row per iteration: iteration, loss, gradient norm, then every parameter.
+pub struct CsvLogger<W: Write> {
+    writer: W,
+    header_written: bool
+}
+
+impl CsvLogger<BufWriter<File>> {
+    pub fn create(path: &str) -> Result<CsvLogger<BufWriter<File>>> {
+        Ok(CsvLogger::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> CsvLogger<W> {
+    pub fn new(writer: W) -> CsvLogger<W> {
+        CsvLogger {
+            writer,
+            header_written: false
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> TrainingObserver for CsvLogger<W> {
+    fn on_iteration(&mut self, event: &IterationEvent) -> Result<()> {
+        if !self.header_written {
+            write!(self.writer, "iteration,loss,gradient_norm")?;
+
+            for index in 0..event.parameters().size() {
+                write!(self.writer, ",parameter_{}", index)?;
+            }
+
+            writeln!(self.writer)?;
+            self.header_written = true;
+        }
+
+        write!(self.writer, "{},{},{}", event.iteration(), event.loss(), event.gradient_norm())?;
+
+        for parameter in event.parameters().iter() {
+            write!(self.writer, ",{}", parameter)?;
+        }
+
+        writeln!(self.writer)?;
+
+        Ok(())
+    }
+
+    fn on_finish(&mut self, _report: &FitReport) -> Result<()> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::TrainingObserver;
+    use optimization::IterationEvent;
+
+    use super::CsvLogger;
+
+    #[test]
+    fn write_one_row_per_iteration() {
+        let mut logger = CsvLogger::new(vec!());
+
+        logger.on_iteration(&IterationEvent::new(1, 4.0, &vector!(0.5, 1.0), 2.0)).unwrap();
+        logger.on_iteration(&IterationEvent::new(2, 1.0, &vector!(0.75, 1.5), 1.0)).unwrap();
+
+        assert_eq!(String::from_utf8(logger.into_inner()).unwrap(),
+                   "iteration,loss,gradient_norm,parameter_0,parameter_1\n\
+                    1,4,2,0.5,1\n\
+                    2,1,1,0.75,1.5\n");
+    }
+}
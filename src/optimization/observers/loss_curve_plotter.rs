@@ -0,0 +1,101 @@
+use std::process::{Command, Stdio};
+
+use gnuplot::{Figure, AxesCommon, Color};
+
+use {Error, Result};
+use optimization::TrainingObserver;
+use optimization::IterationEvent;
+use optimization::FitReport;
+
+// Plots the loss of every iteration into a PNG file once the fit is over. Requires gnuplot.
+pub struct LossCurvePlotter {
+    output_file: String,
+    // only checked to be available, Figure::show() always spawning gnuplot
+    gnuplot_command: String,
+    iterations: Vec<u32>,
+    losses: Vec<f64>
+}
+
+impl LossCurvePlotter {
+    pub fn new(output_file: &str) -> LossCurvePlotter {
+        LossCurvePlotter {
+            output_file: output_file.to_string(),
+            gnuplot_command: "gnuplot".to_string(),
+            iterations: vec!(),
+            losses: vec!()
+        }
+    }
+
+    pub fn losses(&self) -> &[f64] {
+        &self.losses
+    }
+
+    // Figure::show() panics when gnuplot cannot be spawned, so fail beforehand instead.
+    fn check_gnuplot(&self) -> Result<()> {
+        Command::new(&self.gnuplot_command).arg("--version")
+                                           .stdout(Stdio::null())
+                                           .stderr(Stdio::null())
+                                           .status()
+                                           .map_err(|error| Error::Io(format!("cannot run {}: {}", self.gnuplot_command, error)))?;
+
+        Ok(())
+    }
+}
+
+impl TrainingObserver for LossCurvePlotter {
+    fn on_iteration(&mut self, event: &IterationEvent) -> Result<()> {
+        self.iterations.push(event.iteration());
+        self.losses.push(event.loss());
+
+        Ok(())
+    }
+
+    fn on_finish(&mut self, _report: &FitReport) -> Result<()> {
+        self.check_gnuplot()?;
+
+        let mut figure = Figure::new();
+
+        figure.set_terminal("png", &self.output_file)
+              .axes2d()
+              .set_x_label("Iteration", &[])
+              .set_y_label("Error", &[])
+              .lines(&self.iterations, &self.losses, &[Color("red")]);
+        figure.show();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Error;
+    use optimization::TrainingObserver;
+    use optimization::IterationEvent;
+    use optimization::FitReport;
+    use optimization::StopReason;
+
+    use super::LossCurvePlotter;
+
+    #[test]
+    fn collect_losses() {
+        let mut plotter = LossCurvePlotter::new("error.png");
+        let parameters = vector!(1.0);
+
+        plotter.on_iteration(&IterationEvent::new(1, 4.0, &parameters, 2.0)).unwrap();
+        plotter.on_iteration(&IterationEvent::new(2, 1.0, &parameters, 1.0)).unwrap();
+
+        assert_eq!(plotter.losses(), &[4.0, 1.0]);
+    }
+
+    #[test]
+    fn finish_without_gnuplot() {
+        let mut plotter = LossCurvePlotter::new("error.png");
+
+        plotter.gnuplot_command = "missing-gnuplot-binary".to_string();
+
+        match plotter.on_finish(&FitReport::new(StopReason::MaxIterations, 0, vec!(1.0))) {
+            Err(Error::Io(message)) => assert!(message.starts_with("cannot run missing-gnuplot-binary")),
+            result => panic!("unexpected result {:?}", result)
+        }
+    }
+}
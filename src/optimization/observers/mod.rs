@@ -0,0 +1,7 @@
+mod loss_curve_plotter;
+mod csv_logger;
+mod progress_printer;
+
+pub use self::loss_curve_plotter::LossCurvePlotter;
+pub use self::csv_logger::CsvLogger;
+pub use self::progress_printer::ProgressPrinter;
@@ -0,0 +1,77 @@
+use std::io::{self, Stdout, Write};
+
+use Result;
+use optimization::TrainingObserver;
+use optimization::IterationEvent;
+use optimization::FitReport;
+
+// Reports the progress of a fit every `interval` iterations, and once it is over.
+pub struct ProgressPrinter<W: Write> {
+    writer: W,
+    interval: u32
+}
+
+impl ProgressPrinter<Stdout> {
+    pub fn new(interval: u32) -> ProgressPrinter<Stdout> {
+        ProgressPrinter::with_writer(io::stdout(), interval)
+    }
+}
+
+impl<W: Write> ProgressPrinter<W> {
+    pub fn with_writer(writer: W, interval: u32) -> ProgressPrinter<W> {
+        ProgressPrinter {
+            writer,
+            interval: interval.max(1)
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> TrainingObserver for ProgressPrinter<W> {
+    fn on_iteration(&mut self, event: &IterationEvent) -> Result<()> {
+        if event.iteration().is_multiple_of(self.interval) {
+            writeln!(self.writer,
+                     "iteration {}: loss {:e}, gradient norm {:e}",
+                     event.iteration(), event.loss(), event.gradient_norm())?;
+        }
+
+        Ok(())
+    }
+
+    fn on_finish(&mut self, report: &FitReport) -> Result<()> {
+        writeln!(self.writer,
+                 "stopped after {} iterations ({:?}): loss {:e}",
+                 report.iterations(), report.stop_reason(), report.final_loss())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::TrainingObserver;
+    use optimization::IterationEvent;
+    use optimization::FitReport;
+    use optimization::StopReason;
+
+    use super::ProgressPrinter;
+
+    #[test]
+    fn print_every_interval() {
+        let mut printer = ProgressPrinter::with_writer(vec!(), 2);
+        let parameters = vector!(1.0);
+
+        for iteration in 1..5 {
+            printer.on_iteration(&IterationEvent::new(iteration, 1.0 / iteration as f64, &parameters, 0.5)).unwrap();
+        }
+        printer.on_finish(&FitReport::new(StopReason::MaxIterations, 4, vec!(2.0, 1.0, 0.5, 0.25))).unwrap();
+
+        assert_eq!(String::from_utf8(printer.into_inner()).unwrap(),
+                   "iteration 2: loss 5e-1, gradient norm 5e-1\n\
+                    iteration 4: loss 2.5e-1, gradient norm 5e-1\n\
+                    stopped after 4 iterations (MaxIterations): loss 2.5e-1\n");
+    }
+}
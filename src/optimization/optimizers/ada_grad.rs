@@ -0,0 +1,69 @@
+use rulinalg::vector::Vector;
+
+use optimization::Optimizer;
+use super::zeros_like;
+
+// AdaGrad, which scales the learning rate of every parameter by its accumulated squared gradients:
+//   s <- s + g^2
+//   update = -learning_rate g / (sqrt(s) + epsilon)
+pub struct AdaGrad {
+    learning_rate: f64,
+    epsilon: f64,
+    squared_gradients_sum: Vector<f64>
+}
+
+impl AdaGrad {
+    pub fn new(learning_rate: f64) -> AdaGrad {
+        AdaGrad {
+            learning_rate,
+            epsilon: 1e-8,
+            squared_gradients_sum: Vector::zeros(0)
+        }
+    }
+
+    pub fn with_epsilon(mut self, epsilon: f64) -> AdaGrad {
+        self.epsilon = epsilon;
+        self
+    }
+}
+
+impl Optimizer for AdaGrad {
+    fn update(&mut self, _parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64> {
+        zeros_like(&mut self.squared_gradients_sum, gradients);
+
+        self.squared_gradients_sum = &self.squared_gradients_sum + gradients.elemul(gradients);
+
+        Vector::new(gradients.iter()
+                             .zip(self.squared_gradients_sum.iter())
+                             .map(|(g, s)| -self.learning_rate * g / (s.sqrt() + self.epsilon))
+                             .collect::<Vec<f64>>())
+    }
+
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn reset(&mut self) {
+        self.squared_gradients_sum = Vector::zeros(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Optimizer;
+
+    use super::AdaGrad;
+
+    #[test]
+    fn update() {
+        let mut optimizer = AdaGrad::new(1.0).with_epsilon(0.0);
+
+        assert_eq!(optimizer.update(&vector!(1.0, 1.0), &vector!(3.0, -4.0)), vector!(-1.0, 1.0));
+        // s = (25, 16)
+        assert_eq!(optimizer.update(&vector!(0.0, 2.0), &vector!(4.0, 0.0)), vector!(-0.8, 0.0));
+    }
+}
@@ -0,0 +1,111 @@
+use rulinalg::vector::Vector;
+
+use optimization::Optimizer;
+use super::zeros_like;
+
+// Adam, which combines momentum with RMSProp and corrects the bias of both moving averages:
+//   m <- beta1 m + (1 - beta1) g
+//   v <- beta2 v + (1 - beta2) g^2
+//   update = -learning_rate (m / (1 - beta1^t)) / (sqrt(v / (1 - beta2^t)) + epsilon)
+pub struct Adam {
+    learning_rate: f64,
+    beta1: f64,
+    beta2: f64,
+    epsilon: f64,
+    step: i32,
+    gradients_average: Vector<f64>,
+    squared_gradients_average: Vector<f64>
+}
+
+impl Adam {
+    pub fn new(learning_rate: f64) -> Adam {
+        Adam {
+            learning_rate,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            step: 0,
+            gradients_average: Vector::zeros(0),
+            squared_gradients_average: Vector::zeros(0)
+        }
+    }
+
+    pub fn with_betas(mut self, beta1: f64, beta2: f64) -> Adam {
+        self.beta1 = beta1;
+        self.beta2 = beta2;
+        self
+    }
+
+    pub fn with_epsilon(mut self, epsilon: f64) -> Adam {
+        self.epsilon = epsilon;
+        self
+    }
+}
+
+impl Optimizer for Adam {
+    fn update(&mut self, _parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64> {
+        zeros_like(&mut self.gradients_average, gradients);
+        zeros_like(&mut self.squared_gradients_average, gradients);
+
+        self.step += 1;
+        self.gradients_average = &self.gradients_average * self.beta1 + gradients * (1.0 - self.beta1);
+        self.squared_gradients_average = &self.squared_gradients_average * self.beta2
+                                         + gradients.elemul(gradients) * (1.0 - self.beta2);
+
+        let gradients_correction = 1.0 - self.beta1.powi(self.step);
+        let squared_gradients_correction = 1.0 - self.beta2.powi(self.step);
+
+        Vector::new(self.gradients_average
+                        .iter()
+                        .zip(self.squared_gradients_average.iter())
+                        .map(|(m, v)| {
+                            let m = m / gradients_correction;
+                            let v = v / squared_gradients_correction;
+
+                            -self.learning_rate * m / (v.sqrt() + self.epsilon)
+                        })
+                        .collect::<Vec<f64>>())
+    }
+
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn reset(&mut self) {
+        self.step = 0;
+        self.gradients_average = Vector::zeros(0);
+        self.squared_gradients_average = Vector::zeros(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Optimizer;
+
+    use super::Adam;
+
+    #[test]
+    fn first_update_is_the_learning_rate_against_the_gradient_signs() {
+        let mut optimizer = Adam::new(0.1).with_epsilon(0.0);
+        let update = optimizer.update(&vector!(1.0, 1.0), &vector!(2.0, -4.0));
+
+        assert_relative_eq!(update[0], -0.1);
+        assert_relative_eq!(update[1], 0.1);
+    }
+
+    #[test]
+    fn update() {
+        let mut optimizer = Adam::new(1.0).with_betas(0.5, 0.5).with_epsilon(0.0);
+
+        optimizer.update(&vector!(1.0), &vector!(2.0));
+
+        // m = (0.5 + 2) / 0.75, v = (1 + 8) / 0.75
+        let update = optimizer.update(&vector!(0.0), &vector!(4.0));
+
+        assert_relative_eq!(update[0], -(2.5 / 0.75) / (9.0f64 / 0.75).sqrt());
+    }
+}
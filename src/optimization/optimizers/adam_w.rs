@@ -0,0 +1,62 @@
+use rulinalg::vector::Vector;
+
+use optimization::Optimizer;
+use super::Adam;
+
+// Adam with a weight decay decoupled from the gradients (Loshchilov & Hutter):
+//   update = adam_update - learning_rate weight_decay parameters
+pub struct AdamW {
+    adam: Adam,
+    weight_decay: f64
+}
+
+impl AdamW {
+    pub fn new(learning_rate: f64, weight_decay: f64) -> AdamW {
+        AdamW::from_adam(Adam::new(learning_rate), weight_decay)
+    }
+
+    pub fn from_adam(adam: Adam, weight_decay: f64) -> AdamW {
+        AdamW { adam, weight_decay }
+    }
+
+    pub fn weight_decay(&self) -> f64 {
+        self.weight_decay
+    }
+}
+
+impl Optimizer for AdamW {
+    fn update(&mut self, parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64> {
+        let decay = parameters * (self.adam.learning_rate() * self.weight_decay);
+
+        self.adam.update(parameters, gradients) - decay
+    }
+
+    fn learning_rate(&self) -> f64 {
+        self.adam.learning_rate()
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.adam.set_learning_rate(learning_rate);
+    }
+
+    fn reset(&mut self) {
+        self.adam.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Optimizer;
+
+    use super::AdamW;
+    use super::super::Adam;
+
+    #[test]
+    fn update_decays_parameters() {
+        let mut optimizer = AdamW::from_adam(Adam::new(0.1).with_epsilon(0.0), 0.5);
+        let update = optimizer.update(&vector!(2.0, -4.0), &vector!(2.0, -4.0));
+
+        assert_relative_eq!(update[0], -0.1 - 0.1 * 0.5 * 2.0);
+        assert_relative_eq!(update[1], 0.1 + 0.1 * 0.5 * 4.0);
+    }
+}
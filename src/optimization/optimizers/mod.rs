@@ -0,0 +1,22 @@
+mod sgd;
+mod nesterov;
+mod ada_grad;
+mod rms_prop;
+mod adam;
+mod adam_w;
+
+pub use self::sgd::Sgd;
+pub use self::nesterov::Nesterov;
+pub use self::ada_grad::AdaGrad;
+pub use self::rms_prop::RmsProp;
+pub use self::adam::Adam;
+pub use self::adam_w::AdamW;
+
+use rulinalg::vector::Vector;
+
+// Optimizer states are created on the first update, once the number of parameters is known.
+fn zeros_like(state: &mut Vector<f64>, gradients: &Vector<f64>) {
+    if state.size() != gradients.size() {
+        *state = Vector::zeros(gradients.size());
+    }
+}
@@ -0,0 +1,67 @@
+use rulinalg::vector::Vector;
+
+use optimization::Optimizer;
+use super::zeros_like;
+
+// Nesterov accelerated gradient, written in terms of the gradients at the current parameters:
+//   v <- momentum v - learning_rate g
+//   update = momentum v - learning_rate g
+pub struct Nesterov {
+    learning_rate: f64,
+    momentum: f64,
+    velocity: Vector<f64>
+}
+
+impl Nesterov {
+    pub fn new(learning_rate: f64, momentum: f64) -> Nesterov {
+        Nesterov {
+            learning_rate,
+            momentum,
+            velocity: Vector::zeros(0)
+        }
+    }
+
+    pub fn momentum(&self) -> f64 {
+        self.momentum
+    }
+}
+
+impl Optimizer for Nesterov {
+    fn update(&mut self, _parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64> {
+        zeros_like(&mut self.velocity, gradients);
+
+        let step = gradients * self.learning_rate;
+
+        self.velocity = &self.velocity * self.momentum - &step;
+
+        &self.velocity * self.momentum - step
+    }
+
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn reset(&mut self) {
+        self.velocity = Vector::zeros(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Optimizer;
+
+    use super::Nesterov;
+
+    #[test]
+    fn update() {
+        let mut optimizer = Nesterov::new(0.5, 0.5);
+
+        // v = (-1, 2), then v = (-1.5, 3)
+        assert_eq!(optimizer.update(&vector!(1.0, 1.0), &vector!(2.0, -4.0)), vector!(-1.5, 3.0));
+        assert_eq!(optimizer.update(&vector!(0.0, 3.0), &vector!(2.0, -4.0)), vector!(-1.75, 3.5));
+    }
+}
@@ -0,0 +1,82 @@
+use rulinalg::vector::Vector;
+
+use optimization::Optimizer;
+use super::zeros_like;
+
+// RMSProp, which scales the learning rate of every parameter by a moving average of its squared
+// gradients:
+//   s <- decay s + (1 - decay) g^2
+//   update = -learning_rate g / (sqrt(s) + epsilon)
+pub struct RmsProp {
+    learning_rate: f64,
+    decay: f64,
+    epsilon: f64,
+    squared_gradients_average: Vector<f64>
+}
+
+impl RmsProp {
+    pub fn new(learning_rate: f64) -> RmsProp {
+        RmsProp {
+            learning_rate,
+            decay: 0.9,
+            epsilon: 1e-8,
+            squared_gradients_average: Vector::zeros(0)
+        }
+    }
+
+    pub fn with_decay(mut self, decay: f64) -> RmsProp {
+        self.decay = decay;
+        self
+    }
+
+    pub fn with_epsilon(mut self, epsilon: f64) -> RmsProp {
+        self.epsilon = epsilon;
+        self
+    }
+}
+
+impl Optimizer for RmsProp {
+    fn update(&mut self, _parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64> {
+        zeros_like(&mut self.squared_gradients_average, gradients);
+
+        self.squared_gradients_average = &self.squared_gradients_average * self.decay
+                                         + gradients.elemul(gradients) * (1.0 - self.decay);
+
+        Vector::new(gradients.iter()
+                             .zip(self.squared_gradients_average.iter())
+                             .map(|(g, s)| -self.learning_rate * g / (s.sqrt() + self.epsilon))
+                             .collect::<Vec<f64>>())
+    }
+
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn reset(&mut self) {
+        self.squared_gradients_average = Vector::zeros(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Optimizer;
+
+    use super::RmsProp;
+
+    #[test]
+    fn update() {
+        let mut optimizer = RmsProp::new(1.0).with_decay(0.75).with_epsilon(0.0);
+
+        // s = (4, 16) / 4
+        assert_eq!(optimizer.update(&vector!(1.0, 1.0), &vector!(2.0, -4.0)), vector!(-2.0, 2.0));
+        // s = 0.75 (1, 4) + 0.25 (4, 16) = (1.75, 7)
+        let update = optimizer.update(&vector!(0.0, 3.0), &vector!(2.0, -4.0));
+
+        assert_relative_eq!(update[0], -2.0 / 1.75f64.sqrt());
+        assert_relative_eq!(update[1], 4.0 / 7.0f64.sqrt());
+    }
+}
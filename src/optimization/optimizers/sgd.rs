@@ -0,0 +1,78 @@
+use rulinalg::vector::Vector;
+
+use optimization::Optimizer;
+use super::zeros_like;
+
+// Stochastic gradient descent, with optional (heavy ball) momentum:
+//   v <- momentum v - learning_rate g
+//   update = v
+pub struct Sgd {
+    learning_rate: f64,
+    momentum: f64,
+    velocity: Vector<f64>
+}
+
+impl Sgd {
+    pub fn new(learning_rate: f64) -> Sgd {
+        Sgd {
+            learning_rate,
+            momentum: 0.0,
+            velocity: Vector::zeros(0)
+        }
+    }
+
+    pub fn with_momentum(mut self, momentum: f64) -> Sgd {
+        self.momentum = momentum;
+        self
+    }
+
+    pub fn momentum(&self) -> f64 {
+        self.momentum
+    }
+}
+
+impl Optimizer for Sgd {
+    fn update(&mut self, _parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64> {
+        zeros_like(&mut self.velocity, gradients);
+
+        self.velocity = &self.velocity * self.momentum - gradients * self.learning_rate;
+        self.velocity.clone()
+    }
+
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn reset(&mut self) {
+        self.velocity = Vector::zeros(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::Optimizer;
+
+    use super::Sgd;
+
+    #[test]
+    fn update_without_momentum() {
+        let mut optimizer = Sgd::new(0.5);
+
+        assert_eq!(optimizer.update(&vector!(1.0, 1.0), &vector!(2.0, -4.0)), vector!(-1.0, 2.0));
+        assert_eq!(optimizer.update(&vector!(0.0, 3.0), &vector!(2.0, -4.0)), vector!(-1.0, 2.0));
+    }
+
+    #[test]
+    fn update_with_momentum() {
+        let mut optimizer = Sgd::new(0.5).with_momentum(0.5);
+
+        assert_eq!(optimizer.update(&vector!(1.0, 1.0), &vector!(2.0, -4.0)), vector!(-1.0, 2.0));
+        assert_eq!(optimizer.update(&vector!(0.0, 3.0), &vector!(2.0, -4.0)), vector!(-1.5, 3.0));
+        optimizer.reset();
+        assert_eq!(optimizer.update(&vector!(0.0, 3.0), &vector!(2.0, -4.0)), vector!(-1.0, 2.0));
+    }
+}
@@ -0,0 +1,176 @@
+use rulinalg::matrix::{Matrix, BaseMatrix};
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+
+// Householder QR decomposition of a design matrix, applied to the targets on the fly.
+//
+// rulinalg's `qr_decomp()` builds the full `n x n` orthogonal matrix, which is both slow and
+// memory hungry on datasets with many rows. Only `R` and `Q^T y` are needed to solve a least
+// squares problem, so `Q` is never formed.
+pub struct QrDecomposition {
+    rows: usize,
+    r: Matrix<f64>,
+    qty: Vector<f64>
+}
+
+impl QrDecomposition {
+    // Underdetermined design matrices (fewer rows than columns) are rank deficient.
+    pub fn new(design: Matrix<f64>, targets: Vector<f64>) -> Result<QrDecomposition> {
+        let rows = design.rows();
+        let cols = design.cols();
+
+        if targets.size() != rows {
+            return Err(Error::DimensionMismatch { expected: rows, actual: targets.size() });
+        }
+        if rows < cols {
+            return Err(Error::RankDeficient);
+        }
+
+        let mut a = design;
+        let mut y = targets;
+
+        for k in 0..cols {
+            let norm = (k..rows).map(|i| a[[i, k]].powi(2)).sum::<f64>().sqrt();
+
+            if norm == 0.0 {
+                continue;
+            }
+
+            let alpha = if a[[k, k]] > 0.0 { -norm } else { norm };
+            let mut householder: Vec<f64> = (k..rows).map(|i| a[[i, k]]).collect();
+
+            householder[0] -= alpha;
+
+            let householder_norm = householder.iter().map(|v| v.powi(2)).sum::<f64>();
+
+            for j in k..cols {
+                let projection = householder.iter()
+                                            .enumerate()
+                                            .map(|(i, v)| v * a[[k + i, j]])
+                                            .sum::<f64>();
+                let scale = 2.0 * projection / householder_norm;
+
+                for (i, v) in householder.iter().enumerate() {
+                    a[[k + i, j]] -= scale * v;
+                }
+            }
+
+            let projection = householder.iter()
+                                        .enumerate()
+                                        .map(|(i, v)| v * y[k + i])
+                                        .sum::<f64>();
+            let scale = 2.0 * projection / householder_norm;
+
+            for (i, v) in householder.iter().enumerate() {
+                y[k + i] -= scale * v;
+            }
+        }
+
+        Ok(QrDecomposition {
+            rows,
+            r: Matrix::from_fn(cols, cols, |j, i| if j >= i { a[[i, j]] } else { 0.0 }),
+            qty: y
+        })
+    }
+
+    pub fn solve(&self) -> Result<Vector<f64>> {
+        self.check_rank()?;
+
+        let qty = Vector::new(&self.qty.data()[..self.r.cols()]);
+
+        self.r.solve_u_triangular(qty).map_err(|_| Error::RankDeficient)
+    }
+
+    // Inverse of the Gram matrix of the design, (X^T X)^-1 = R^-1 R^-T.
+    pub fn inverse_gram(&self) -> Result<Matrix<f64>> {
+        self.check_rank()?;
+
+        let cols = self.r.cols();
+        let mut r_inverse = Matrix::zeros(cols, cols);
+
+        for j in 0..cols {
+            let unit = Vector::new((0..cols).map(|i| if i == j { 1.0 } else { 0.0 }).collect::<Vec<f64>>());
+            let column = self.r.solve_u_triangular(unit).map_err(|_| Error::RankDeficient)?;
+
+            for i in 0..cols {
+                r_inverse[[i, j]] = column[i];
+            }
+        }
+
+        Ok(&r_inverse * r_inverse.transpose())
+    }
+
+    fn check_rank(&self) -> Result<()> {
+        let cols = self.r.cols();
+        let max_diagonal = (0..cols).map(|i| self.r[[i, i]].abs()).fold(0.0, f64::max);
+        let tolerance = max_diagonal * (self.rows as f64) * f64::EPSILON;
+
+        if (0..cols).any(|i| self.r[[i, i]].abs() <= tolerance) {
+            return Err(Error::RankDeficient);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use super::QrDecomposition;
+    use Error;
+
+    #[test]
+    fn solve_square_system() {
+        // x + y = 3, x - y = 1
+        let decomposition = QrDecomposition::new(matrix![1.0, 1.0; 1.0, -1.0], vector!(3.0, 1.0)).unwrap();
+        let solution = decomposition.solve().unwrap();
+
+        assert_relative_eq!(solution[0], 2.0, epsilon = 1e-15);
+        assert_relative_eq!(solution[1], 1.0, epsilon = 1e-15);
+    }
+
+    #[test]
+    fn solve_overdetermined_system() {
+        // y = 1 + 2x, with residuals (-1, 2, -1) orthogonal to the design columns
+        let decomposition = QrDecomposition::new(matrix![1.0, 0.0; 1.0, 1.0; 1.0, 2.0],
+                                                 vector!(0.0, 5.0, 4.0)).unwrap();
+        let solution = decomposition.solve().unwrap();
+
+        assert_relative_eq!(solution[0], 1.0, epsilon = 1e-14);
+        assert_relative_eq!(solution[1], 2.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn inverse_gram_matrix() {
+        // X^T X = [3 3; 3 5]
+        let decomposition = QrDecomposition::new(matrix![1.0, 0.0; 1.0, 1.0; 1.0, 2.0], vector!(0.0, 5.0, 4.0)).unwrap();
+        let inverse = decomposition.inverse_gram().unwrap();
+
+        assert_relative_eq!(inverse[[0, 0]], 5.0 / 6.0, epsilon = 1e-14);
+        assert_relative_eq!(inverse[[0, 1]], -0.5, epsilon = 1e-14);
+        assert_relative_eq!(inverse[[1, 0]], -0.5, epsilon = 1e-14);
+        assert_relative_eq!(inverse[[1, 1]], 0.5, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn solve_rank_deficient_system() {
+        let decomposition = QrDecomposition::new(matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0], vector!(1.0, 2.0, 3.0)).unwrap();
+
+        assert_eq!(decomposition.solve().unwrap_err(), Error::RankDeficient);
+        assert_eq!(decomposition.inverse_gram().unwrap_err(), Error::RankDeficient);
+    }
+
+    #[test]
+    fn decompose_underdetermined_system() {
+        assert_eq!(QrDecomposition::new(Matrix::new(1, 2, vec![1.0, 2.0]), vector!(1.0)).err(),
+                   Some(Error::RankDeficient));
+    }
+
+    #[test]
+    fn decompose_with_wrong_number_of_targets() {
+        assert_eq!(QrDecomposition::new(Matrix::new(2, 1, vec![1.0, 2.0]), vector!(1.0)).err(),
+                   Some(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+}
@@ -0,0 +1,66 @@
+const SEED_MIXER: u64 = 0x9e37_79b9_7f4a_7c15;
+
+// Seeded xorshift64* generator, so that shuffled fits are reproducible across runs and platforms.
+pub struct Random {
+    state: u64
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        let state = seed ^ SEED_MIXER;
+
+        // the state of a xorshift generator must never be 0
+        Random { state: if state == 0 { SEED_MIXER } else { state } }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // Uniform integer in [0, bound).
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    // Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.below(i + 1);
+
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Random;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut random1 = Random::new(42);
+        let mut random2 = Random::new(42);
+        let mut random3 = Random::new(43);
+        let sequence1: Vec<u64> = (0..10).map(|_| random1.next_u64()).collect();
+        let sequence2: Vec<u64> = (0..10).map(|_| random2.next_u64()).collect();
+        let sequence3: Vec<u64> = (0..10).map(|_| random3.next_u64()).collect();
+
+        assert_eq!(sequence1, sequence2);
+        assert_ne!(sequence1, sequence3);
+    }
+
+    #[test]
+    fn shuffle_permutes_values() {
+        let mut random = Random::new(0);
+        let mut values: Vec<usize> = (0..100).collect();
+
+        random.shuffle(&mut values);
+
+        assert_ne!(values, (0..100).collect::<Vec<usize>>());
+        values.sort();
+        assert_eq!(values, (0..100).collect::<Vec<usize>>());
+    }
+}
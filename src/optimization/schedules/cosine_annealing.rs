@@ -0,0 +1,85 @@
+use std::f64::consts::PI;
+
+use optimization::LearningRateSchedule;
+
+// Cosine annealing with warm restarts (SGDR): the learning rate follows half a cosine from the base
+// learning rate down to `min_learning_rate` over a period, then restarts. Every period is
+// `period_multiplier` times longer than the previous one.
+pub struct CosineAnnealing {
+    first_period: u32,
+    period_multiplier: u32,
+    min_learning_rate: f64
+}
+
+impl CosineAnnealing {
+    pub fn new(first_period: u32) -> CosineAnnealing {
+        CosineAnnealing {
+            first_period: first_period.max(1),
+            period_multiplier: 1,
+            min_learning_rate: 0.0
+        }
+    }
+
+    pub fn with_period_multiplier(mut self, period_multiplier: u32) -> CosineAnnealing {
+        self.period_multiplier = period_multiplier.max(1);
+        self
+    }
+
+    pub fn with_min_learning_rate(mut self, min_learning_rate: f64) -> CosineAnnealing {
+        self.min_learning_rate = min_learning_rate;
+        self
+    }
+}
+
+impl LearningRateSchedule for CosineAnnealing {
+    fn learning_rate(&mut self, base_learning_rate: f64, iteration: u32, _losses: &[f64]) -> f64 {
+        let mut period = u64::from(self.first_period);
+        let mut position = u64::from(iteration);
+
+        if self.period_multiplier == 1 {
+            position %= period;
+        } else {
+            // periods grow geometrically, so this takes a logarithmic number of steps
+            while position >= period {
+                position -= period;
+                period *= u64::from(self.period_multiplier);
+            }
+        }
+
+        let progress = position as f64 / period as f64;
+
+        self.min_learning_rate + (base_learning_rate - self.min_learning_rate) * (1.0 + (PI * progress).cos()) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::LearningRateSchedule;
+
+    use super::CosineAnnealing;
+
+    #[test]
+    fn learning_rate_with_restarts() {
+        let mut schedule = CosineAnnealing::new(2).with_min_learning_rate(1.0);
+        let learning_rates: Vec<f64> = (0..5).map(|iteration| schedule.learning_rate(3.0, iteration, &[])).collect();
+
+        assert_relative_eq!(learning_rates[0], 3.0);
+        assert_relative_eq!(learning_rates[1], 2.0);
+        assert_relative_eq!(learning_rates[2], 3.0);
+        assert_relative_eq!(learning_rates[3], 2.0);
+        assert_relative_eq!(learning_rates[4], 3.0);
+        assert_relative_eq!(schedule.learning_rate(3.0, u32::MAX, &[]), 2.0);
+    }
+
+    #[test]
+    fn learning_rate_with_growing_periods() {
+        let mut schedule = CosineAnnealing::new(1).with_period_multiplier(2);
+        let learning_rates: Vec<f64> = (0..4).map(|iteration| schedule.learning_rate(1.0, iteration, &[])).collect();
+
+        // periods of 1, 2 and 4 iterations start at iterations 0, 1 and 3
+        assert_relative_eq!(learning_rates[0], 1.0);
+        assert_relative_eq!(learning_rates[1], 1.0);
+        assert_relative_eq!(learning_rates[2], 0.5);
+        assert_relative_eq!(learning_rates[3], 1.0);
+    }
+}
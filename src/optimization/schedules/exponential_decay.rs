@@ -0,0 +1,33 @@
+use optimization::LearningRateSchedule;
+
+// learning_rate = base_learning_rate * decay_rate^iteration
+pub struct ExponentialDecay {
+    decay_rate: f64
+}
+
+impl ExponentialDecay {
+    pub fn new(decay_rate: f64) -> ExponentialDecay {
+        ExponentialDecay { decay_rate }
+    }
+}
+
+impl LearningRateSchedule for ExponentialDecay {
+    fn learning_rate(&mut self, base_learning_rate: f64, iteration: u32, _losses: &[f64]) -> f64 {
+        base_learning_rate * self.decay_rate.powf(f64::from(iteration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::LearningRateSchedule;
+
+    use super::ExponentialDecay;
+
+    #[test]
+    fn learning_rate() {
+        let mut schedule = ExponentialDecay::new(0.5);
+        let learning_rates: Vec<f64> = (0..4).map(|iteration| schedule.learning_rate(2.0, iteration, &[])).collect();
+
+        assert_eq!(learning_rates, vec!(2.0, 1.0, 0.5, 0.25));
+    }
+}
@@ -0,0 +1,33 @@
+use optimization::LearningRateSchedule;
+
+// learning_rate = base_learning_rate / (1 + decay_rate * iteration)
+pub struct InverseTimeDecay {
+    decay_rate: f64
+}
+
+impl InverseTimeDecay {
+    pub fn new(decay_rate: f64) -> InverseTimeDecay {
+        InverseTimeDecay { decay_rate }
+    }
+}
+
+impl LearningRateSchedule for InverseTimeDecay {
+    fn learning_rate(&mut self, base_learning_rate: f64, iteration: u32, _losses: &[f64]) -> f64 {
+        base_learning_rate / (1.0 + self.decay_rate * f64::from(iteration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::LearningRateSchedule;
+
+    use super::InverseTimeDecay;
+
+    #[test]
+    fn learning_rate() {
+        let mut schedule = InverseTimeDecay::new(0.5);
+        let learning_rates: Vec<f64> = (0..4).map(|iteration| schedule.learning_rate(3.0, iteration, &[])).collect();
+
+        assert_eq!(learning_rates, vec!(3.0, 2.0, 1.5, 1.2));
+    }
+}
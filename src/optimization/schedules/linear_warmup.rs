@@ -0,0 +1,67 @@
+use optimization::LearningRateSchedule;
+
+// Raises the learning rate linearly up to the base learning rate over the first
+// `warmup_iterations` iterations, then keeps it constant or hands over to another schedule, which
+// then starts counting iterations from 0.
+pub struct LinearWarmup {
+    warmup_iterations: u32,
+    schedule: Option<Box<dyn LearningRateSchedule>>
+}
+
+impl LinearWarmup {
+    pub fn new(warmup_iterations: u32) -> LinearWarmup {
+        LinearWarmup {
+            warmup_iterations,
+            schedule: None
+        }
+    }
+
+    pub fn followed_by<S>(mut self, schedule: S) -> LinearWarmup
+    where S: LearningRateSchedule + 'static {
+        self.schedule = Some(Box::new(schedule));
+        self
+    }
+}
+
+impl LearningRateSchedule for LinearWarmup {
+    fn learning_rate(&mut self, base_learning_rate: f64, iteration: u32, losses: &[f64]) -> f64 {
+        if iteration < self.warmup_iterations {
+            return base_learning_rate * f64::from(iteration + 1) / f64::from(self.warmup_iterations);
+        }
+
+        match self.schedule {
+            None => base_learning_rate,
+            Some(ref mut schedule) => schedule.learning_rate(base_learning_rate, iteration - self.warmup_iterations, losses)
+        }
+    }
+
+    fn reset(&mut self) {
+        if let Some(ref mut schedule) = self.schedule {
+            schedule.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::LearningRateSchedule;
+    use optimization::schedules::StepDecay;
+
+    use super::LinearWarmup;
+
+    #[test]
+    fn learning_rate() {
+        let mut schedule = LinearWarmup::new(4);
+        let learning_rates: Vec<f64> = (0..6).map(|iteration| schedule.learning_rate(1.0, iteration, &[])).collect();
+
+        assert_eq!(learning_rates, vec!(0.25, 0.5, 0.75, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn learning_rate_followed_by_schedule() {
+        let mut schedule = LinearWarmup::new(2).followed_by(StepDecay::new(1, 0.5));
+        let learning_rates: Vec<f64> = (0..5).map(|iteration| schedule.learning_rate(1.0, iteration, &[])).collect();
+
+        assert_eq!(learning_rates, vec!(0.5, 1.0, 1.0, 0.5, 0.25));
+    }
+}
@@ -0,0 +1,13 @@
+mod step_decay;
+mod exponential_decay;
+mod inverse_time_decay;
+mod cosine_annealing;
+mod linear_warmup;
+mod reduce_on_plateau;
+
+pub use self::step_decay::StepDecay;
+pub use self::exponential_decay::ExponentialDecay;
+pub use self::inverse_time_decay::InverseTimeDecay;
+pub use self::cosine_annealing::CosineAnnealing;
+pub use self::linear_warmup::LinearWarmup;
+pub use self::reduce_on_plateau::ReduceOnPlateau;
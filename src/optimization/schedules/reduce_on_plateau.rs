@@ -0,0 +1,83 @@
+use optimization::LearningRateSchedule;
+
+// Multiplies the learning rate by `factor` once the loss has not improved on its best value by
+// more than `threshold` (relatively) for more than `patience` iterations.
+pub struct ReduceOnPlateau {
+    factor: f64,
+    patience: u32,
+    threshold: f64,
+    min_learning_rate: f64,
+    best_loss: f64,
+    iterations_without_improvement: u32,
+    scale: f64
+}
+
+impl ReduceOnPlateau {
+    pub fn new(factor: f64, patience: u32) -> ReduceOnPlateau {
+        ReduceOnPlateau {
+            factor,
+            patience,
+            threshold: 1e-4,
+            min_learning_rate: 0.0,
+            best_loss: f64::INFINITY,
+            iterations_without_improvement: 0,
+            scale: 1.0
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: f64) -> ReduceOnPlateau {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_min_learning_rate(mut self, min_learning_rate: f64) -> ReduceOnPlateau {
+        self.min_learning_rate = min_learning_rate;
+        self
+    }
+}
+
+impl LearningRateSchedule for ReduceOnPlateau {
+    fn learning_rate(&mut self, base_learning_rate: f64, _iteration: u32, losses: &[f64]) -> f64 {
+        if let Some(&loss) = losses.last() {
+            if loss < self.best_loss * (1.0 - self.threshold) {
+                self.best_loss = loss;
+                self.iterations_without_improvement = 0;
+            } else {
+                self.iterations_without_improvement += 1;
+
+                if self.iterations_without_improvement > self.patience {
+                    self.scale *= self.factor;
+                    self.iterations_without_improvement = 0;
+                }
+            }
+        }
+
+        (base_learning_rate * self.scale).max(self.min_learning_rate)
+    }
+
+    fn reset(&mut self) {
+        self.best_loss = f64::INFINITY;
+        self.iterations_without_improvement = 0;
+        self.scale = 1.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::LearningRateSchedule;
+
+    use super::ReduceOnPlateau;
+
+    #[test]
+    fn learning_rate() {
+        let mut schedule = ReduceOnPlateau::new(0.5, 1).with_threshold(0.0).with_min_learning_rate(0.3);
+        let losses = [4.0, 3.0, 3.0, 3.0, 2.0, 2.0, 2.0, 2.0, 2.0];
+        let learning_rates: Vec<f64> = (0..losses.len()).map(|i| schedule.learning_rate(1.0, i as u32, &losses[..(i + 1)]))
+                                                         .collect();
+
+        assert_eq!(learning_rates, vec!(1.0, 1.0, 1.0, 0.5, 0.5, 0.5, 0.3, 0.3, 0.3));
+
+        schedule.reset();
+        assert_eq!(schedule.learning_rate(1.0, 0, &[1.0]), 1.0);
+    }
+}
@@ -0,0 +1,37 @@
+use optimization::LearningRateSchedule;
+
+// Multiplies the learning rate by `factor` every `step_size` iterations.
+pub struct StepDecay {
+    step_size: u32,
+    factor: f64
+}
+
+impl StepDecay {
+    pub fn new(step_size: u32, factor: f64) -> StepDecay {
+        StepDecay {
+            step_size: step_size.max(1),
+            factor
+        }
+    }
+}
+
+impl LearningRateSchedule for StepDecay {
+    fn learning_rate(&mut self, base_learning_rate: f64, iteration: u32, _losses: &[f64]) -> f64 {
+        base_learning_rate * self.factor.powi((iteration / self.step_size) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use optimization::LearningRateSchedule;
+
+    use super::StepDecay;
+
+    #[test]
+    fn learning_rate() {
+        let mut schedule = StepDecay::new(2, 0.5);
+        let learning_rates: Vec<f64> = (0..6).map(|iteration| schedule.learning_rate(1.0, iteration, &[])).collect();
+
+        assert_eq!(learning_rates, vec!(1.0, 1.0, 0.5, 0.5, 0.25, 0.25));
+    }
+}
@@ -1,10 +1,55 @@
+use rulinalg::matrix::Matrix;
 use rulinalg::vector::Vector;
 
+use {Error, Result};
+use super::FitReport;
+use super::IterationEvent;
+
+// Functions are built by their own constructors, which keeps the trait object safe: fitters accept
+// any `&mut dyn ParametricFunction`.
 pub trait ParametricFunction {
-    fn new(input_size: usize) -> Self;
     fn parameters(&self) -> &Vector<f64>;
-    fn set_parameters(&mut self, new_parameters: Vector<f64>);
-    fn f(&self, input: &Vector<f64>) -> f64;
-    fn df(&self, input: &Vector<f64>) -> f64;
-    fn parameter_gradients(&self, input: &Vector<f64>) -> Vector<f64>;
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()>;
+    fn f(&self, input: &Vector<f64>) -> Result<f64>;
+    // Gradient of f with respect to the inputs, with one partial derivative per input variable.
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>>;
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>>;
+
+    // Matrix of the second partial derivatives of f with respect to the inputs.
+    fn input_hessian(&self, _input: &Vector<f64>) -> Result<Matrix<f64>> {
+        Err(Error::Unsupported)
+    }
+}
+
+// Receives the events of an iterative fit. Observers only run when they are attached to the fit.
+pub trait TrainingObserver {
+    fn on_iteration(&mut self, event: &IterationEvent) -> Result<()>;
+
+    fn on_finish(&mut self, _report: &FitReport) -> Result<()> {
+        Ok(())
+    }
+}
+
+// Turns the loss gradients at the current parameters into the update to add to those parameters.
+// Optimizers may keep state between updates (e.g. velocities), which reset() clears before a new fit.
+pub trait Optimizer {
+    fn update(&mut self, parameters: &Vector<f64>, gradients: &Vector<f64>) -> Vector<f64>;
+    fn learning_rate(&self) -> f64;
+    fn set_learning_rate(&mut self, learning_rate: f64);
+    fn reset(&mut self);
+}
+
+// Learning rate of an iteration (starting at 0), from the learning rate the optimizer was created
+// with and the losses so far (the initial loss followed by the loss after every iteration).
+pub trait LearningRateSchedule {
+    fn learning_rate(&mut self, base_learning_rate: f64, iteration: u32, losses: &[f64]) -> f64;
+
+    fn reset(&mut self) {}
+}
+
+// Loss of a prediction against its target. The gradient is the derivative of the loss with
+// respect to the prediction.
+pub trait Loss {
+    fn value(&self, prediction: f64, target: f64) -> f64;
+    fn gradient(&self, prediction: f64, target: f64) -> f64;
 }
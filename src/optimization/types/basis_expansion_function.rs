@@ -0,0 +1,158 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use optimization::traits::ParametricFunction;
+use optimization::basis::BasisFunction;
+use optimization::gradient_check::central_difference;
+use super::FunctionParameters;
+
+// Linear combination of basis functions of the inputs (e.g. `basis::rbf`), with one parameter per
+// basis function. The input gradient of a basis function without gradient, such as a plain
+// closure, is estimated with central differences.
+pub struct BasisExpansionFunction {
+    input_size: usize,
+    basis: Vec<BasisFunction>,
+    parameters: FunctionParameters
+}
+
+impl BasisExpansionFunction {
+    // Without any basis function yet.
+    pub fn new(input_size: usize) -> BasisExpansionFunction {
+        BasisExpansionFunction {
+            input_size,
+            basis: vec!(),
+            parameters: FunctionParameters::new(Vector::zeros(0))
+        }
+    }
+
+    // Adds a basis function, whose parameter starts at 0.
+    pub fn with_basis_function<B>(self, basis_function: B) -> BasisExpansionFunction
+    where B: Fn(&Vector<f64>) -> f64 + 'static {
+        self.push_basis(BasisFunction::new(basis_function))
+    }
+
+    // Adds a basis function built with its gradient, e.g. `basis::rbf`, failing when it cannot
+    // evaluate inputs of the function's input size.
+    pub fn with_basis(self, basis_function: BasisFunction) -> Result<BasisExpansionFunction> {
+        basis_function.check_input_size(self.input_size)?;
+
+        Ok(self.push_basis(basis_function))
+    }
+
+    fn push_basis(mut self, basis_function: BasisFunction) -> BasisExpansionFunction {
+        let mut parameters = self.parameters.vector().clone().into_vec();
+
+        parameters.push(0.0);
+        self.basis.push(basis_function);
+        self.parameters = FunctionParameters::new(Vector::new(parameters));
+        self
+    }
+
+    pub fn basis_size(&self) -> usize {
+        self.basis.len()
+    }
+
+    fn check_input_size(&self, input: &Vector<f64>) -> Result<()> {
+        if input.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: input.size() });
+        }
+
+        Ok(())
+    }
+
+    fn basis_gradient(&self, basis_function: &BasisFunction, input: &Vector<f64>) -> Result<Vector<f64>> {
+        let gradient = match basis_function.gradient(input) {
+            Some(gradient) => gradient,
+            None => {
+                return (0..self.input_size).map(|i| central_difference(|shifted| Ok(basis_function.value(shifted)), input, i, 1e-6))
+                                           .collect();
+            }
+        };
+
+        if gradient.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: gradient.size() });
+        }
+
+        Ok(gradient)
+    }
+}
+
+impl ParametricFunction for BasisExpansionFunction {
+    fn parameters(&self) -> &Vector<f64> {
+        self.parameters.vector()
+    }
+
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
+        self.parameters.set_vector(new_parameters)
+    }
+
+    fn f(&self, input: &Vector<f64>) -> Result<f64> {
+        Ok(self.parameters.vector().dot(&self.parameter_gradients(input)?))
+    }
+
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        let mut gradient = Vector::zeros(self.input_size);
+
+        for (basis_function, parameter) in self.basis.iter().zip(self.parameters.vector().iter()) {
+            gradient += self.basis_gradient(basis_function, input)? * *parameter;
+        }
+
+        Ok(gradient)
+    }
+
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        Ok(self.basis.iter().map(|basis_function| basis_function.value(input)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BasisExpansionFunction;
+    use Error;
+    use optimization::traits::ParametricFunction;
+    use optimization::basis;
+
+    #[test]
+    fn evaluate_basis_functions() {
+        let mut function = BasisExpansionFunction::new(2).with_basis_function(|_| 1.0)
+                                                         .with_basis_function(|x| x[0] * x[1]);
+
+        // 3 + 2 x1 x2
+        function.set_parameters(vector!(3.0, 2.0)).unwrap();
+
+        assert_eq!(function.basis_size(), 2);
+        assert_eq!(function.f(&vector!(2.0, 5.0)), Ok(23.0));
+        assert_eq!(function.parameter_gradients(&vector!(2.0, 5.0)), Ok(vector!(1.0, 10.0)));
+        assert_eq!(function.f(&vector!(2.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+
+        // 2 (x2, x1), from central differences
+        let gradient = function.input_gradient(&vector!(2.0, 5.0)).unwrap();
+
+        assert_relative_eq!(gradient[0], 10.0, epsilon = 1e-6);
+        assert_relative_eq!(gradient[1], 4.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn input_gradient_of_basis_functions_with_gradient() {
+        let mut function = BasisExpansionFunction::new(2).with_basis(basis::constant()).unwrap()
+                                                         .with_basis(basis::truncated_power(1, 1.0, 2)).unwrap();
+
+        // 3 + 2 max(0, x2 - 1)^2
+        function.set_parameters(vector!(3.0, 2.0)).unwrap();
+
+        assert_eq!(function.input_gradient(&vector!(2.0, 4.0)), Ok(vector!(0.0, 12.0)));
+        assert_eq!(function.input_gradient(&vector!(2.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+
+    #[test]
+    fn reject_basis_functions_of_other_input_sizes() {
+        assert_eq!(BasisExpansionFunction::new(2).with_basis(basis::fourier_sine(2, 1.0, 1.0)).err(),
+                   Some(Error::DimensionMismatch { expected: 3, actual: 2 }));
+        assert_eq!(BasisExpansionFunction::new(2).with_basis(basis::rbf(vector!(0.0), 1.0)).err(),
+                   Some(Error::DimensionMismatch { expected: 1, actual: 2 }));
+    }
+}
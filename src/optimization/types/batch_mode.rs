@@ -0,0 +1,26 @@
+// How many samples every gradient descent update is computed on. Except in full batch mode, an
+// iteration is an epoch: one pass over the dataset, shuffled from the seed at the start of every
+// epoch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BatchMode {
+    FullBatch,
+    MiniBatch { batch_size: usize, seed: u64 },
+    Stochastic { seed: u64 }
+}
+
+impl BatchMode {
+    pub fn batch_size(&self) -> Option<usize> {
+        match *self {
+            BatchMode::FullBatch => None,
+            BatchMode::MiniBatch { batch_size, .. } => Some(batch_size),
+            BatchMode::Stochastic { .. } => Some(1)
+        }
+    }
+
+    pub fn seed(&self) -> Option<u64> {
+        match *self {
+            BatchMode::FullBatch => None,
+            BatchMode::MiniBatch { seed, .. } | BatchMode::Stochastic { seed } => Some(seed)
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use optimization::traits::TrainingObserver;
+use super::StoppingCriteria;
+use super::LineSearch;
+
+// Formula of the factor mixing the previous direction into the new one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConjugateGradientMethod {
+    FletcherReeves,
+    // restarted along the gradients whenever the factor would be negative (PR+)
+    PolakRibiere
+}
+
+// Settings of a nonlinear conjugate gradient fit.
+pub struct ConjugateGradient {
+    method: ConjugateGradientMethod,
+    stopping_criteria: StoppingCriteria,
+    line_search: LineSearch,
+    observers: Vec<Box<dyn TrainingObserver>>
+}
+
+impl ConjugateGradient {
+    pub fn new(method: ConjugateGradientMethod, stopping_criteria: StoppingCriteria) -> ConjugateGradient {
+        ConjugateGradient {
+            method,
+            stopping_criteria,
+            line_search: LineSearch::StrongWolfe { initial_step: 1.0, sufficient_decrease: 1e-4, curvature: 0.1 },
+            observers: vec!()
+        }
+    }
+
+    pub fn with_line_search(mut self, line_search: LineSearch) -> ConjugateGradient {
+        self.line_search = line_search;
+        self
+    }
+
+    pub fn with_observer<O>(mut self, observer: O) -> ConjugateGradient
+    where O: TrainingObserver + 'static {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn method(&self) -> ConjugateGradientMethod {
+        self.method
+    }
+
+    pub fn stopping_criteria(&self) -> &StoppingCriteria {
+        &self.stopping_criteria
+    }
+
+    pub fn line_search(&self) -> LineSearch {
+        self.line_search
+    }
+
+    pub fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>] {
+        &mut self.observers
+    }
+}
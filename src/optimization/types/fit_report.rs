@@ -0,0 +1,41 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StopReason {
+    MaxIterations,
+    LossTolerance,
+    RelativeLossTolerance,
+    GradientTolerance,
+    ParameterTolerance,
+    TimeBudget,
+    LineSearchFailure
+}
+
+// Outcome of an iterative fit: why it stopped, after how many iterations, and the loss before the
+// first iteration followed by the loss after each iteration.
+#[derive(Clone, Debug)]
+pub struct FitReport {
+    stop_reason: StopReason,
+    iterations: u32,
+    losses: Vec<f64>
+}
+
+impl FitReport {
+    pub fn new(stop_reason: StopReason, iterations: u32, losses: Vec<f64>) -> FitReport {
+        FitReport { stop_reason, iterations, losses }
+    }
+
+    pub fn stop_reason(&self) -> StopReason {
+        self.stop_reason
+    }
+
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    pub fn final_loss(&self) -> f64 {
+        *self.losses.last().expect("FitReport: a report holds at least the initial loss.")
+    }
+
+    pub fn losses(&self) -> &[f64] {
+        &self.losses
+    }
+}
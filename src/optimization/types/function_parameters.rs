@@ -1,5 +1,7 @@
 use rulinalg::vector::Vector;
 
+use {Error, Result};
+
 pub struct FunctionParameters {
     vector: Vector<f64>
 }
@@ -13,43 +15,46 @@ impl FunctionParameters {
         &self.vector
     }
 
-    pub fn set_vector(&mut self, new_vector: Vector<f64>) {
+    pub fn set_vector(&mut self, new_vector: Vector<f64>) -> Result<()> {
         if new_vector.size() != self.vector.size() {
-            panic!("FunctionParameters: trying to update function parameters with parameters of different size ({} instead of {}).", new_vector.size(), self.vector.size())
+            return Err(Error::DimensionMismatch { expected: self.vector.size(), actual: new_vector.size() });
         }
 
         self.vector = new_vector;
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::FunctionParameters;
+    use Error;
 
     #[test]
     fn set_vector() {
         let mut parameters = FunctionParameters::new(vector!(1.0, 2.0, 3.0));
 
         assert_eq!(parameters.vector(), &vector!(1.0, 2.0, 3.0));
-        parameters.set_vector(vector!(3.0, 2.0, 1.0));
+        parameters.set_vector(vector!(3.0, 2.0, 1.0)).unwrap();
         assert_eq!(parameters.vector(), &vector!(3.0, 2.0, 1.0));
     }
 
     #[test]
-    #[should_panic(expected = "FunctionParameters: trying to update function parameters with parameters of different size (2 instead of 3).")]
     fn set_vector_without_enough_parameters() {
         let mut parameters = FunctionParameters::new(vector!(1.0, 2.0, 3.0));
 
+        assert_eq!(parameters.set_vector(vector!(3.0, 2.0)),
+                   Err(Error::DimensionMismatch { expected: 3, actual: 2 }));
         assert_eq!(parameters.vector(), &vector!(1.0, 2.0, 3.0));
-        parameters.set_vector(vector!(3.0, 2.0));
     }
 
     #[test]
-    #[should_panic(expected = "FunctionParameters: trying to update function parameters with parameters of different size (4 instead of 3).")]
     fn set_vector_with_too_many_parameters() {
         let mut parameters = FunctionParameters::new(vector!(1.0, 2.0, 3.0));
 
+        assert_eq!(parameters.set_vector(vector!(3.0, 2.0, 1.0, 0.0)),
+                   Err(Error::DimensionMismatch { expected: 3, actual: 4 }));
         assert_eq!(parameters.vector(), &vector!(1.0, 2.0, 3.0));
-        parameters.set_vector(vector!(3.0, 2.0, 1.0, 0.0));
     }
 }
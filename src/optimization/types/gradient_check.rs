@@ -0,0 +1,80 @@
+use rulinalg::vector::Vector;
+
+// Analytic gradients next to their central finite difference approximations.
+//
+// The relative error of a partial derivative is |analytic - numerical| / max(|analytic|, |numerical|, 1),
+// i.e. an absolute error for derivatives smaller than 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradientCheck {
+    analytic: Vector<f64>,
+    numerical: Vector<f64>
+}
+
+impl GradientCheck {
+    pub fn new(analytic: Vector<f64>, numerical: Vector<f64>) -> GradientCheck {
+        if analytic.size() != numerical.size() {
+            panic!("GradientCheck: {} analytic gradients for {} numerical gradients.", analytic.size(), numerical.size())
+        }
+
+        GradientCheck { analytic, numerical }
+    }
+
+    pub fn analytic(&self) -> &Vector<f64> {
+        &self.analytic
+    }
+
+    pub fn numerical(&self) -> &Vector<f64> {
+        &self.numerical
+    }
+
+    pub fn relative_errors(&self) -> Vector<f64> {
+        self.analytic
+            .iter()
+            .zip(self.numerical.iter())
+            .map(|(a, n)| (a - n).abs() / a.abs().max(n.abs()).max(1.0))
+            .collect()
+    }
+
+    // NaN as soon as one of the gradients is not a number.
+    pub fn max_relative_error(&self) -> f64 {
+        self.relative_errors().iter().fold(0.0, |max, &error| if error.is_nan() || error > max { error } else { max })
+    }
+
+    // Index of the partial derivative with the largest relative error.
+    pub fn worst_index(&self) -> Option<usize> {
+        let max_relative_error = self.max_relative_error();
+
+        self.relative_errors().iter().position(|&error| error == max_relative_error || error.is_nan())
+    }
+
+    pub fn passes(&self, tolerance: f64) -> bool {
+        self.max_relative_error() <= tolerance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    use super::GradientCheck;
+
+    #[test]
+    fn relative_errors() {
+        let check = GradientCheck::new(vector!(100.0, 0.5, -2.0), vector!(101.0, 0.25, -2.0));
+
+        assert_eq!(check.relative_errors(), vector!(1.0 / 101.0, 0.25, 0.0));
+        assert_eq!(check.max_relative_error(), 0.25);
+        assert_eq!(check.worst_index(), Some(1));
+        assert!(check.passes(0.25));
+        assert!(!check.passes(0.1));
+    }
+
+    #[test]
+    fn not_a_number_fails() {
+        let check = GradientCheck::new(vector!(1.0, f64::NAN, 1.0), vector!(1.0, 1.0, 3.0));
+
+        assert!(check.max_relative_error().is_nan());
+        assert_eq!(check.worst_index(), Some(1));
+        assert!(!check.passes(f64::INFINITY));
+    }
+}
@@ -0,0 +1,86 @@
+use optimization::traits::TrainingObserver;
+use optimization::traits::Optimizer;
+use optimization::traits::LearningRateSchedule;
+use super::StoppingCriteria;
+use super::BatchMode;
+use super::LineSearch;
+
+// Settings of a gradient descent fit.
+pub struct GradientDescent {
+    optimizer: Box<dyn Optimizer>,
+    stopping_criteria: StoppingCriteria,
+    batch_mode: BatchMode,
+    schedule: Option<Box<dyn LearningRateSchedule>>,
+    line_search: Option<LineSearch>,
+    observers: Vec<Box<dyn TrainingObserver>>
+}
+
+impl GradientDescent {
+    pub fn new<O>(optimizer: O, stopping_criteria: StoppingCriteria) -> GradientDescent
+    where O: Optimizer + 'static {
+        GradientDescent {
+            optimizer: Box::new(optimizer),
+            stopping_criteria,
+            batch_mode: BatchMode::FullBatch,
+            schedule: None,
+            line_search: None,
+            observers: vec!()
+        }
+    }
+
+    pub fn with_batch_mode(mut self, batch_mode: BatchMode) -> GradientDescent {
+        self.batch_mode = batch_mode;
+        self
+    }
+
+    // The schedule sets the learning rate of the optimizer before every iteration.
+    pub fn with_schedule<S>(mut self, schedule: S) -> GradientDescent
+    where S: LearningRateSchedule + 'static {
+        self.schedule = Some(Box::new(schedule));
+        self
+    }
+
+    // The line search scales every update of the optimizer, e.g. Sgd::new(1.0) for a steepest
+    // descent along the gradients.
+    pub fn with_line_search(mut self, line_search: LineSearch) -> GradientDescent {
+        self.line_search = Some(line_search);
+        self
+    }
+
+    pub fn with_observer<O>(mut self, observer: O) -> GradientDescent
+    where O: TrainingObserver + 'static {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn optimizer(&self) -> &dyn Optimizer {
+        self.optimizer.as_ref()
+    }
+
+    pub fn optimizer_mut(&mut self) -> &mut dyn Optimizer {
+        self.optimizer.as_mut()
+    }
+
+    pub fn stopping_criteria(&self) -> &StoppingCriteria {
+        &self.stopping_criteria
+    }
+
+    pub fn batch_mode(&self) -> BatchMode {
+        self.batch_mode
+    }
+
+    pub fn schedule_mut(&mut self) -> Option<&mut dyn LearningRateSchedule> {
+        match self.schedule {
+            None => None,
+            Some(ref mut schedule) => Some(schedule.as_mut())
+        }
+    }
+
+    pub fn line_search(&self) -> Option<LineSearch> {
+        self.line_search
+    }
+
+    pub fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>] {
+        &mut self.observers
+    }
+}
@@ -0,0 +1,34 @@
+use rulinalg::vector::Vector;
+
+// State of an iterative fit right after one of its iterations.
+pub struct IterationEvent<'a> {
+    iteration: u32,
+    loss: f64,
+    parameters: &'a Vector<f64>,
+    gradient_norm: f64
+}
+
+impl<'a> IterationEvent<'a> {
+    pub fn new(iteration: u32, loss: f64, parameters: &'a Vector<f64>, gradient_norm: f64) -> IterationEvent<'a> {
+        IterationEvent { iteration, loss, parameters, gradient_norm }
+    }
+
+    // Starts at 1 for the first iteration.
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    // Loss of the updated parameters.
+    pub fn loss(&self) -> f64 {
+        self.loss
+    }
+
+    pub fn parameters(&self) -> &Vector<f64> {
+        self.parameters
+    }
+
+    // Euclidean norm of the gradients used to update the parameters.
+    pub fn gradient_norm(&self) -> f64 {
+        self.gradient_norm
+    }
+}
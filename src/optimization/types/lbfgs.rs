@@ -0,0 +1,50 @@
+use optimization::traits::TrainingObserver;
+use super::StoppingCriteria;
+use super::LineSearch;
+
+// Settings of a limited-memory BFGS fit, which approximates the inverse Hessian of the loss from
+// the last `history_size` steps and gradient changes.
+pub struct Lbfgs {
+    history_size: usize,
+    stopping_criteria: StoppingCriteria,
+    line_search: LineSearch,
+    observers: Vec<Box<dyn TrainingObserver>>
+}
+
+impl Lbfgs {
+    pub fn new(history_size: usize, stopping_criteria: StoppingCriteria) -> Lbfgs {
+        Lbfgs {
+            history_size,
+            stopping_criteria,
+            line_search: LineSearch::strong_wolfe(),
+            observers: vec!()
+        }
+    }
+
+    pub fn with_line_search(mut self, line_search: LineSearch) -> Lbfgs {
+        self.line_search = line_search;
+        self
+    }
+
+    pub fn with_observer<O>(mut self, observer: O) -> Lbfgs
+    where O: TrainingObserver + 'static {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn history_size(&self) -> usize {
+        self.history_size
+    }
+
+    pub fn stopping_criteria(&self) -> &StoppingCriteria {
+        &self.stopping_criteria
+    }
+
+    pub fn line_search(&self) -> LineSearch {
+        self.line_search
+    }
+
+    pub fn observers_mut(&mut self) -> &mut [Box<dyn TrainingObserver>] {
+        &mut self.observers
+    }
+}
@@ -0,0 +1,23 @@
+// Line search choosing how far to move along the update of the optimizer, so that the step size
+// does not depend on a hand-tuned learning rate.
+//
+// Armijo backtracking starts from `initial_step` and multiplies the step by `contraction` until the
+// loss decreases sufficiently:
+//   loss(p + step d) <= loss(p) + sufficient_decrease step (gradients . d)
+// Strong Wolfe also requires the slope to flatten enough:
+//   |gradients(p + step d) . d| <= curvature |gradients(p) . d|
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineSearch {
+    Armijo { initial_step: f64, contraction: f64, sufficient_decrease: f64 },
+    StrongWolfe { initial_step: f64, sufficient_decrease: f64, curvature: f64 }
+}
+
+impl LineSearch {
+    pub fn armijo() -> LineSearch {
+        LineSearch::Armijo { initial_step: 1.0, contraction: 0.5, sufficient_decrease: 1e-4 }
+    }
+
+    pub fn strong_wolfe() -> LineSearch {
+        LineSearch::StrongWolfe { initial_step: 1.0, sufficient_decrease: 1e-4, curvature: 0.9 }
+    }
+}
@@ -1,14 +1,47 @@
+use rulinalg::matrix::Matrix;
 use rulinalg::vector::Vector;
 
+use {Error, Result};
 use optimization::traits::ParametricFunction;
 use super::FunctionParameters;
 
+// f(x) = b0 + b1 x1 + ... + bn xn, or b1 x1 + ... + bn xn through the origin without intercept.
 pub struct LinearFunction {
     input_size: usize,
+    intercept: bool,
     parameters: FunctionParameters
 }
 
 impl LinearFunction {
+    pub fn new(input_size: usize) -> LinearFunction {
+        LinearFunction {
+            input_size,
+            intercept: true,
+            parameters: FunctionParameters::new(vector![0.0; input_size + 1])
+        }
+    }
+
+    // Function whose parameters are only the coefficients, going through the origin.
+    pub fn without_intercept(input_size: usize) -> LinearFunction {
+        LinearFunction {
+            input_size,
+            intercept: false,
+            parameters: FunctionParameters::new(vector![0.0; input_size])
+        }
+    }
+
+    pub fn has_intercept(&self) -> bool {
+        self.intercept
+    }
+
+    fn check_input_size(&self, input: &Vector<f64>) -> Result<()> {
+        if input.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: input.size() });
+        }
+
+        Ok(())
+    }
+
     fn add_y_intercept(input: &Vector<f64>) -> Vector<f64> {
         let mut input_with_intercept = input.data().clone();
 
@@ -16,62 +49,66 @@ impl LinearFunction {
 
         Vector::new(input_with_intercept)
     }
-}
 
-impl ParametricFunction for LinearFunction {
-    fn new(input_size: usize) -> Self {
-        LinearFunction {
-            input_size,
-            parameters: FunctionParameters::new(vector![0.0; input_size + 1])
+    // The input multiplied by the parameters, starting with the constant 1 of the intercept.
+    fn design_row(&self, input: &Vector<f64>) -> Vector<f64> {
+        if self.intercept {
+            Self::add_y_intercept(input)
+        } else {
+            input.clone()
         }
     }
+}
 
+impl ParametricFunction for LinearFunction {
     fn parameters(&self) -> &Vector<f64> {
         self.parameters.vector()
     }
 
-    fn set_parameters(&mut self, new_parameters: Vector<f64>) {
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
         self.parameters.set_vector(new_parameters)
     }
 
-    fn f(&self, input: &Vector<f64>) -> f64 {
-        if input.size() != self.input_size {
-            panic!("LinearFunction: trying to apply f() with the wrong number of input variables ({} instead of {}).", input.size(), self.input_size)
-        }
+    fn f(&self, input: &Vector<f64>) -> Result<f64> {
+        self.check_input_size(input)?;
 
-        self.parameters.vector().dot(&Self::add_y_intercept(input))
+        Ok(self.parameters.vector().dot(&self.design_row(input)))
     }
 
-    fn df(&self, input: &Vector<f64>) -> f64 {
-        if input.size() != self.input_size {
-            panic!("LinearFunction: trying to apply df() with the wrong number of input variables ({} instead of {}).", input.size(), self.input_size)
-        }
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
 
-        let parameters_vector = self.parameters.vector();
+        let coefficients_start = if self.intercept { 1 } else { 0 };
 
-        parameters_vector.sum() - parameters_vector[0]
+        Ok(Vector::new(&self.parameters.vector().data()[coefficients_start..]))
     }
 
-    fn parameter_gradients(&self, input: &Vector<f64>) -> Vector<f64> {
-        if input.size() != self.input_size {
-            panic!("LinearFunction: trying to get parameter_gradients() with the wrong number of input variables ({} instead of {}).", input.size(), self.input_size)
-        }
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        Ok(self.design_row(input))
+    }
+
+    fn input_hessian(&self, input: &Vector<f64>) -> Result<Matrix<f64>> {
+        self.check_input_size(input)?;
 
-        Self::add_y_intercept(input)
+        Ok(Matrix::zeros(self.input_size, self.input_size))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use rulinalg::matrix::Matrix;
     use rulinalg::vector::Vector;
 
     use super::LinearFunction;
+    use Error;
     use optimization::traits::ParametricFunction;
 
-    fn test_function(function: &LinearFunction, input: Vector<f64>, y: f64, dx: f64, parameter_gradients: Vector<f64>) {
-        assert_eq!(function.f(&input), y);
-        assert_eq!(function.df(&input), dx);
-        assert_eq!(function.parameter_gradients(&input), parameter_gradients);
+    fn test_function(function: &LinearFunction, input: Vector<f64>, y: f64, input_gradient: Vector<f64>, parameter_gradients: Vector<f64>) {
+        assert_eq!(function.f(&input), Ok(y));
+        assert_eq!(function.input_gradient(&input), Ok(input_gradient));
+        assert_eq!(function.parameter_gradients(&input), Ok(parameter_gradients));
     }
 
     #[test]
@@ -88,81 +125,75 @@ mod tests {
         let mut function = LinearFunction::new(3);
 
         assert_eq!(*function.parameters(), vector!(0.0, 0.0, 0.0, 0.0));
-        function.set_parameters(vector!(0.0, 3.0, 2.0, 1.0));
+        function.set_parameters(vector!(0.0, 3.0, 2.0, 1.0)).unwrap();
         assert_eq!(*function.parameters(), vector!(0.0, 3.0, 2.0, 1.0));
     }
 
     #[test]
-    #[should_panic(expected = "LinearFunction: trying to apply f() with the wrong number of input variables (1 instead of 2).")]
     fn f_without_enough_input_variables() {
         let function = LinearFunction::new(2);
 
-        function.f(&vector!(1.0));
+        assert_eq!(function.f(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
     }
 
     #[test]
-    #[should_panic(expected = "LinearFunction: trying to apply f() with the wrong number of input variables (3 instead of 2).")]
     fn f_with_too_many_input_variables() {
         let function = LinearFunction::new(2);
 
-        function.f(&vector!(1.0, 2.0, 3.0));
+        assert_eq!(function.f(&vector!(1.0, 2.0, 3.0)), Err(Error::DimensionMismatch { expected: 2, actual: 3 }));
     }
 
     #[test]
-    #[should_panic(expected = "LinearFunction: trying to apply df() with the wrong number of input variables (1 instead of 2).")]
-    fn df_without_enough_input_variables() {
+    fn input_gradient_without_enough_input_variables() {
         let function = LinearFunction::new(2);
 
-        function.df(&vector!(1.0));
+        assert_eq!(function.input_gradient(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
     }
 
     #[test]
-    #[should_panic(expected = "LinearFunction: trying to apply df() with the wrong number of input variables (3 instead of 2).")]
-    fn df_with_too_many_input_variables() {
+    fn input_gradient_with_too_many_input_variables() {
         let function = LinearFunction::new(2);
 
-        function.df(&vector!(1.0, 2.0, 3.0));
+        assert_eq!(function.input_gradient(&vector!(1.0, 2.0, 3.0)), Err(Error::DimensionMismatch { expected: 2, actual: 3 }));
     }
 
     #[test]
-    #[should_panic(expected = "LinearFunction: trying to get parameter_gradients() with the wrong number of input variables (1 instead of 2).")]
     fn parameter_gradients_without_enough_input_variables() {
         let function = LinearFunction::new(2);
 
-        function.parameter_gradients(&vector!(1.0));
+        assert_eq!(function.parameter_gradients(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
     }
 
     #[test]
-    #[should_panic(expected = "LinearFunction: trying to get parameter_gradients() with the wrong number of input variables (3 instead of 2).")]
     fn parameter_gradients_with_too_many_input_variables() {
         let function = LinearFunction::new(2);
 
-        function.parameter_gradients(&vector!(1.0, 2.0, 3.0));
+        assert_eq!(function.parameter_gradients(&vector!(1.0, 2.0, 3.0)), Err(Error::DimensionMismatch { expected: 2, actual: 3 }));
     }
 
     #[test]
     fn function_without_input_variables() {
         let mut function = LinearFunction::new(0);
 
-        //  f(x) = 3
-        // df(x) = 0
-        function.set_parameters(vector!(3.0));
+        //      f() = 3
+        // grad f() = ()
+        function.set_parameters(vector!(3.0)).unwrap();
 
-        test_function(&function, vector!(), 3.0, 0.0, vector!(1.0))
+        test_function(&function, vector!(), 3.0, vector!(), vector!(1.0))
     }
 
     #[test]
     fn function_without_y_intercept() {
         let mut function = LinearFunction::new(1);
 
-        //  f(x) = 1x
-        // df(x) = 1
-        function.set_parameters(vector!(0.0, 1.0));
+        //      f(x) = 1x
+        // grad f(x) = (1)
+        function.set_parameters(vector!(0.0, 1.0)).unwrap();
 
-        test_function(&function, vector!(0.0), 0.0, 1.0, vector!(1.0, 0.0));
-        test_function(&function, vector!(1.0), 1.0, 1.0, vector!(1.0, 1.0));
-        test_function(&function, vector!(2.0), 2.0, 1.0, vector!(1.0, 2.0));
-        test_function(&function, vector!(3.0), 3.0, 1.0, vector!(1.0, 3.0));
+        test_function(&function, vector!(0.0), 0.0, vector!(1.0), vector!(1.0, 0.0));
+        test_function(&function, vector!(1.0), 1.0, vector!(1.0), vector!(1.0, 1.0));
+        test_function(&function, vector!(2.0), 2.0, vector!(1.0), vector!(1.0, 2.0));
+        test_function(&function, vector!(3.0), 3.0, vector!(1.0), vector!(1.0, 3.0));
     }
 
     #[test]
@@ -170,64 +201,91 @@ mod tests {
         let mut function0 = LinearFunction::new(1);
         let mut function1 = LinearFunction::new(1);
 
-        //  f(x) = 0 + 0x
-        // df(x) = 0
-        function0.set_parameters(vector!(0.0, 0.0));
+        //      f(x) = 0 + 0x
+        // grad f(x) = (0)
+        function0.set_parameters(vector!(0.0, 0.0)).unwrap();
 
-        test_function(&function0, vector!(0.0), 0.0, 0.0, vector!(1.0, 0.0));
-        test_function(&function0, vector!(1.0), 0.0, 0.0, vector!(1.0, 1.0));
-        test_function(&function0, vector!(2.0), 0.0, 0.0, vector!(1.0, 2.0));
-        test_function(&function0, vector!(3.0), 0.0, 0.0, vector!(1.0, 3.0));
+        test_function(&function0, vector!(0.0), 0.0, vector!(0.0), vector!(1.0, 0.0));
+        test_function(&function0, vector!(1.0), 0.0, vector!(0.0), vector!(1.0, 1.0));
+        test_function(&function0, vector!(2.0), 0.0, vector!(0.0), vector!(1.0, 2.0));
+        test_function(&function0, vector!(3.0), 0.0, vector!(0.0), vector!(1.0, 3.0));
 
-        //  f(x) = 1 + 0x
-        // df(x) = 0
-        function1.set_parameters(vector!(1.0, 0.0));
+        //      f(x) = 1 + 0x
+        // grad f(x) = (0)
+        function1.set_parameters(vector!(1.0, 0.0)).unwrap();
 
-        test_function(&function1, vector!(0.0), 1.0, 0.0, vector!(1.0, 0.0));
-        test_function(&function1, vector!(1.0), 1.0, 0.0, vector!(1.0, 1.0));
-        test_function(&function1, vector!(2.0), 1.0, 0.0, vector!(1.0, 2.0));
-        test_function(&function1, vector!(3.0), 1.0, 0.0, vector!(1.0, 3.0));
+        test_function(&function1, vector!(0.0), 1.0, vector!(0.0), vector!(1.0, 0.0));
+        test_function(&function1, vector!(1.0), 1.0, vector!(0.0), vector!(1.0, 1.0));
+        test_function(&function1, vector!(2.0), 1.0, vector!(0.0), vector!(1.0, 2.0));
+        test_function(&function1, vector!(3.0), 1.0, vector!(0.0), vector!(1.0, 3.0));
     }
 
     #[test]
     fn function_with_one_input_variable() {
         let mut function = LinearFunction::new(1);
 
-        //  f(x) = 1 + 2x
-        // df(x) = 2
-        function.set_parameters(vector!(1.0, 2.0));
+        //      f(x) = 1 + 2x
+        // grad f(x) = (2)
+        function.set_parameters(vector!(1.0, 2.0)).unwrap();
 
-        test_function(&function, vector!(0.0), 1.0, 2.0, vector!(1.0, 0.0));
-        test_function(&function, vector!(1.0), 3.0, 2.0, vector!(1.0, 1.0));
-        test_function(&function, vector!(2.0), 5.0, 2.0, vector!(1.0, 2.0));
-        test_function(&function, vector!(3.0), 7.0, 2.0, vector!(1.0, 3.0));
+        test_function(&function, vector!(0.0), 1.0, vector!(2.0), vector!(1.0, 0.0));
+        test_function(&function, vector!(1.0), 3.0, vector!(2.0), vector!(1.0, 1.0));
+        test_function(&function, vector!(2.0), 5.0, vector!(2.0), vector!(1.0, 2.0));
+        test_function(&function, vector!(3.0), 7.0, vector!(2.0), vector!(1.0, 3.0));
     }
 
     #[test]
     fn function_with_two_input_variables() {
         let mut function = LinearFunction::new(2);
 
-        //  f(x, y) = 1 + 2x + y
-        // df(x, y) = 3
-        function.set_parameters(vector!(1.0, 2.0, 1.0));
+        //      f(x, y) = 1 + 2x + y
+        // grad f(x, y) = (2, 1)
+        function.set_parameters(vector!(1.0, 2.0, 1.0)).unwrap();
 
-        test_function(&function, vector!(0.0, 3.0), 4.0, 3.0, vector!(1.0, 0.0, 3.0));
-        test_function(&function, vector!(1.0, 2.0), 5.0, 3.0, vector!(1.0, 1.0, 2.0));
-        test_function(&function, vector!(2.0, 1.0), 6.0, 3.0, vector!(1.0, 2.0, 1.0));
-        test_function(&function, vector!(3.0, 0.0), 7.0, 3.0, vector!(1.0, 3.0, 0.0));
+        test_function(&function, vector!(0.0, 3.0), 4.0, vector!(2.0, 1.0), vector!(1.0, 0.0, 3.0));
+        test_function(&function, vector!(1.0, 2.0), 5.0, vector!(2.0, 1.0), vector!(1.0, 1.0, 2.0));
+        test_function(&function, vector!(2.0, 1.0), 6.0, vector!(2.0, 1.0), vector!(1.0, 2.0, 1.0));
+        test_function(&function, vector!(3.0, 0.0), 7.0, vector!(2.0, 1.0), vector!(1.0, 3.0, 0.0));
     }
 
     #[test]
     fn function_with_three_input_variables() {
         let mut function = LinearFunction::new(3);
 
-        //  f(x, y, z) = 1 + 3x + 2y + z
-        // df(x, y, z) = 6
-        function.set_parameters(vector!(1.0, 3.0, 2.0, 1.0));
+        //      f(x, y, z) = 1 + 3x + 2y + z
+        // grad f(x, y, z) = (3, 2, 1)
+        function.set_parameters(vector!(1.0, 3.0, 2.0, 1.0)).unwrap();
+
+        test_function(&function, vector!(0.0, 3.0, 1.0), 8.0, vector!(3.0, 2.0, 1.0), vector!(1.0, 0.0, 3.0, 1.0));
+        test_function(&function, vector!(1.0, 2.0, 0.0), 8.0, vector!(3.0, 2.0, 1.0), vector!(1.0, 1.0, 2.0, 0.0));
+        test_function(&function, vector!(2.0, 1.0, 3.0), 12.0, vector!(3.0, 2.0, 1.0), vector!(1.0, 2.0, 1.0, 3.0));
+        test_function(&function, vector!(3.0, 0.0, 2.0), 12.0, vector!(3.0, 2.0, 1.0), vector!(1.0, 3.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn input_hessian_is_zero() {
+        let mut function = LinearFunction::new(2);
+
+        function.set_parameters(vector!(1.0, 2.0, 1.0)).unwrap();
+
+        assert_eq!(function.input_hessian(&vector!(1.0, 2.0)), Ok(Matrix::zeros(2, 2)));
+        assert_eq!(function.input_hessian(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+
+    #[test]
+    fn function_without_intercept() {
+        let mut function = LinearFunction::without_intercept(2);
+
+        assert!(!function.has_intercept());
+        assert_eq!(*function.parameters(), vector!(0.0, 0.0));
+
+        //      f(x, y) = 2x + y
+        // grad f(x, y) = (2, 1)
+        function.set_parameters(vector!(2.0, 1.0)).unwrap();
 
-        test_function(&function, vector!(0.0, 3.0, 1.0), 8.0, 6.0, vector!(1.0, 0.0, 3.0, 1.0));
-        test_function(&function, vector!(1.0, 2.0, 0.0), 8.0, 6.0, vector!(1.0, 1.0, 2.0, 0.0));
-        test_function(&function, vector!(2.0, 1.0, 3.0), 12.0, 6.0, vector!(1.0, 2.0, 1.0, 3.0));
-        test_function(&function, vector!(3.0, 0.0, 2.0), 12.0, 6.0, vector!(1.0, 3.0, 0.0, 2.0));
+        test_function(&function, vector!(0.0, 0.0), 0.0, vector!(2.0, 1.0), vector!(0.0, 0.0));
+        test_function(&function, vector!(1.0, 2.0), 4.0, vector!(2.0, 1.0), vector!(1.0, 2.0));
+        test_function(&function, vector!(3.0, 0.0), 6.0, vector!(2.0, 1.0), vector!(3.0, 0.0));
+        assert_eq!(function.set_parameters(vector!(1.0, 2.0, 1.0)).err(), Some(Error::DimensionMismatch { expected: 2, actual: 3 }));
     }
 }
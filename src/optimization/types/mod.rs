@@ -1,5 +1,33 @@
 mod function_parameters;
 mod linear_function;
+mod polynomial_function;
+mod basis_expansion_function;
+mod stopping_criteria;
+mod fit_report;
+mod iteration_event;
+mod batch_mode;
+mod line_search;
+mod gradient_descent;
+mod lbfgs;
+mod conjugate_gradient;
+mod penalty;
+mod softmax_function;
+mod gradient_check;
 
 pub use self::function_parameters::FunctionParameters;
 pub use self::linear_function::LinearFunction;
+pub use self::polynomial_function::PolynomialFunction;
+pub use self::basis_expansion_function::BasisExpansionFunction;
+pub use self::stopping_criteria::StoppingCriteria;
+pub use self::fit_report::FitReport;
+pub use self::fit_report::StopReason;
+pub use self::iteration_event::IterationEvent;
+pub use self::batch_mode::BatchMode;
+pub use self::line_search::LineSearch;
+pub use self::gradient_descent::GradientDescent;
+pub use self::lbfgs::Lbfgs;
+pub use self::conjugate_gradient::ConjugateGradient;
+pub use self::conjugate_gradient::ConjugateGradientMethod;
+pub use self::penalty::Penalty;
+pub use self::softmax_function::SoftmaxFunction;
+pub use self::gradient_check::GradientCheck;
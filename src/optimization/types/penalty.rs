@@ -0,0 +1,114 @@
+use rulinalg::vector::Vector;
+
+// Elastic Net penalty alpha (l1_ratio ||b||_1 + (1 - l1_ratio) ||b||^2) on the parameters, but for
+// the first ones which can be left unpenalized (e.g. the intercept of a `LinearFunction`). By
+// default every parameter is penalized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Penalty {
+    alpha: f64,
+    l1_ratio: f64,
+    unpenalized_parameters: usize
+}
+
+impl Penalty {
+    pub fn new(alpha: f64, l1_ratio: f64) -> Penalty {
+        Penalty {
+            alpha,
+            l1_ratio,
+            unpenalized_parameters: 0
+        }
+    }
+
+    // L2 penalty alpha ||b||^2.
+    pub fn ridge(alpha: f64) -> Penalty {
+        Penalty::new(alpha, 0.0)
+    }
+
+    // L1 penalty alpha ||b||_1.
+    pub fn lasso(alpha: f64) -> Penalty {
+        Penalty::new(alpha, 1.0)
+    }
+
+    pub fn elastic_net(alpha: f64, l1_ratio: f64) -> Penalty {
+        Penalty::new(alpha, l1_ratio)
+    }
+
+    pub fn with_alpha(mut self, alpha: f64) -> Penalty {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn with_unpenalized_parameters(mut self, count: usize) -> Penalty {
+        self.unpenalized_parameters = count;
+        self
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    pub fn l1_ratio(&self) -> f64 {
+        self.l1_ratio
+    }
+
+    pub fn unpenalized_parameters(&self) -> usize {
+        self.unpenalized_parameters
+    }
+
+    // Weight of the L1 norm in the penalty.
+    pub fn l1_weight(&self) -> f64 {
+        self.alpha * self.l1_ratio
+    }
+
+    // Weight of the squared L2 norm in the penalty.
+    pub fn l2_weight(&self) -> f64 {
+        self.alpha * (1.0 - self.l1_ratio)
+    }
+
+    pub fn is_penalized(&self, index: usize) -> bool {
+        index >= self.unpenalized_parameters
+    }
+
+    pub fn value(&self, parameters: &Vector<f64>) -> f64 {
+        parameters.iter()
+                  .enumerate()
+                  .filter(|&(index, _)| self.is_penalized(index))
+                  .map(|(_, p)| self.l1_weight() * p.abs() + self.l2_weight() * p.powi(2))
+                  .sum()
+    }
+
+    // Gradients of the penalty, taking the subgradient 0 of the L1 norm at 0.
+    pub fn gradients(&self, parameters: &Vector<f64>) -> Vector<f64> {
+        parameters.iter()
+                  .enumerate()
+                  .map(|(index, &p)| {
+                      if !self.is_penalized(index) || p == 0.0 {
+                          0.0
+                      } else {
+                          self.l1_weight() * p.signum() + 2.0 * self.l2_weight() * p
+                      }
+                  })
+                  .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Penalty;
+
+    #[test]
+    fn value_skips_intercept() {
+        let penalty = Penalty::elastic_net(2.0, 0.25);
+
+        // 2 (0.25 (1 + 2) + 0.75 (1 + 4))
+        assert_relative_eq!(penalty.with_unpenalized_parameters(1).value(&vector!(10.0, -1.0, 2.0)), 9.0);
+        assert_relative_eq!(penalty.value(&vector!(10.0, -1.0, 2.0)), 9.0 + 155.0);
+    }
+
+    #[test]
+    fn gradients_skip_intercept() {
+        let penalty = Penalty::elastic_net(2.0, 0.25).with_unpenalized_parameters(1);
+
+        assert_eq!(penalty.gradients(&vector!(10.0, -1.0, 0.0, 2.0)), vector!(0.0, -0.5 - 3.0, 0.0, 0.5 + 6.0));
+    }
+}
@@ -0,0 +1,201 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use optimization::traits::ParametricFunction;
+use super::FunctionParameters;
+
+// Linear combination of the monomials of the inputs up to a total degree, with one parameter per
+// monomial. Monomials are ordered by degree, starting with the constant term, then by the
+// exponents of the first inputs: 1, x1, x2, x1^2, x1 x2, x2^2, ...
+//
+// With interactions only, monomials are products of distinct inputs (1, x1, x2, x1 x2, ...).
+pub struct PolynomialFunction {
+    input_size: usize,
+    degree: u32,
+    interaction_only: bool,
+    exponents: Vec<Vec<u32>>,
+    parameters: FunctionParameters
+}
+
+// Exponents of the monomials of `input_size` inputs with the given total degree, the exponents
+// being at most `max_exponent`.
+fn monomial_exponents(input_size: usize, degree: u32, max_exponent: u32) -> Vec<Vec<u32>> {
+    if input_size == 0 {
+        return if degree == 0 { vec!(vec!()) } else { vec!() };
+    }
+
+    let mut exponents = vec!();
+
+    for first_exponent in (0..(degree.min(max_exponent) + 1)).rev() {
+        for rest in monomial_exponents(input_size - 1, degree - first_exponent, max_exponent) {
+            let mut monomial = vec!(first_exponent);
+
+            monomial.extend(rest);
+            exponents.push(monomial);
+        }
+    }
+
+    exponents
+}
+
+impl PolynomialFunction {
+    pub fn with_degree(input_size: usize, degree: u32) -> PolynomialFunction {
+        PolynomialFunction::build(input_size, degree, false)
+    }
+
+    pub fn with_interactions_only(input_size: usize, degree: u32) -> PolynomialFunction {
+        PolynomialFunction::build(input_size, degree, true)
+    }
+
+    fn build(input_size: usize, degree: u32, interaction_only: bool) -> PolynomialFunction {
+        let max_exponent = if interaction_only { 1 } else { degree };
+        let exponents: Vec<Vec<u32>> = (0..(degree + 1)).flat_map(|d| monomial_exponents(input_size, d, max_exponent))
+                                                        .collect();
+        let parameters = FunctionParameters::new(Vector::zeros(exponents.len()));
+
+        PolynomialFunction { input_size, degree, interaction_only, exponents, parameters }
+    }
+
+    pub fn degree(&self) -> u32 {
+        self.degree
+    }
+
+    pub fn interaction_only(&self) -> bool {
+        self.interaction_only
+    }
+
+    // Exponents of the inputs in each monomial, in the order of the parameters.
+    pub fn exponents(&self) -> &[Vec<u32>] {
+        &self.exponents
+    }
+
+    fn check_input_size(&self, input: &Vector<f64>) -> Result<()> {
+        if input.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: input.size() });
+        }
+
+        Ok(())
+    }
+
+    fn monomial(input: &Vector<f64>, exponents: &[u32]) -> f64 {
+        input.iter().zip(exponents.iter()).map(|(x, &e)| x.powi(e as i32)).product()
+    }
+
+    // Partial derivative of a monomial with respect to the given inputs in turn.
+    fn monomial_derivative(input: &Vector<f64>, exponents: &[u32], variables: &[usize]) -> f64 {
+        let mut derivative_exponents = exponents.to_vec();
+        let mut factor = 1.0;
+
+        for &variable in variables {
+            if derivative_exponents[variable] == 0 {
+                return 0.0;
+            }
+
+            factor *= f64::from(derivative_exponents[variable]);
+            derivative_exponents[variable] -= 1;
+        }
+
+        factor * Self::monomial(input, &derivative_exponents)
+    }
+
+    // Sum of the partial derivatives of the monomials with respect to the given inputs, weighted by
+    // the parameters.
+    fn derivative(&self, input: &Vector<f64>, variables: &[usize]) -> f64 {
+        self.exponents
+            .iter()
+            .zip(self.parameters.vector().iter())
+            .map(|(exponents, p)| p * Self::monomial_derivative(input, exponents, variables))
+            .sum()
+    }
+}
+
+impl ParametricFunction for PolynomialFunction {
+    fn parameters(&self) -> &Vector<f64> {
+        self.parameters.vector()
+    }
+
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
+        self.parameters.set_vector(new_parameters)
+    }
+
+    fn f(&self, input: &Vector<f64>) -> Result<f64> {
+        Ok(self.parameters.vector().dot(&self.parameter_gradients(input)?))
+    }
+
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        Ok((0..self.input_size).map(|i| self.derivative(input, &[i])).collect())
+    }
+
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(input)?;
+
+        Ok(self.exponents.iter().map(|exponents| Self::monomial(input, exponents)).collect())
+    }
+
+    fn input_hessian(&self, input: &Vector<f64>) -> Result<Matrix<f64>> {
+        self.check_input_size(input)?;
+
+        Ok(Matrix::from_fn(self.input_size, self.input_size, |col, row| self.derivative(input, &[row, col])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use super::PolynomialFunction;
+    use Error;
+    use optimization::traits::ParametricFunction;
+
+    #[test]
+    fn monomials_of_degree_2() {
+        let function = PolynomialFunction::with_degree(2, 2);
+
+        assert_eq!(function.exponents(), &[vec!(0, 0), vec!(1, 0), vec!(0, 1), vec!(2, 0), vec!(1, 1), vec!(0, 2)][..]);
+        assert_eq!(function.parameter_gradients(&vector!(2.0, 3.0)), Ok(vector!(1.0, 2.0, 3.0, 4.0, 6.0, 9.0)));
+    }
+
+    #[test]
+    fn monomials_with_interactions_only() {
+        let function = PolynomialFunction::with_interactions_only(3, 3);
+
+        assert_eq!(function.exponents(), &[vec!(0, 0, 0),
+                                           vec!(1, 0, 0), vec!(0, 1, 0), vec!(0, 0, 1),
+                                           vec!(1, 1, 0), vec!(1, 0, 1), vec!(0, 1, 1),
+                                           vec!(1, 1, 1)][..]);
+    }
+
+    #[test]
+    fn evaluate_univariate_polynomial() {
+        let mut function = PolynomialFunction::with_degree(1, 3);
+
+        // 1 + 2x - x^3
+        function.set_parameters(vector!(1.0, 2.0, 0.0, -1.0)).unwrap();
+
+        assert_eq!(function.f(&vector!(2.0)), Ok(-3.0));
+        assert_eq!(function.input_gradient(&vector!(2.0)), Ok(vector!(2.0 - 12.0)));
+        assert_eq!(function.input_hessian(&vector!(2.0)), Ok(Matrix::new(1, 1, vec!(-12.0))));
+        assert_eq!(function.parameter_gradients(&vector!(2.0)), Ok(vector!(1.0, 2.0, 4.0, 8.0)));
+    }
+
+    #[test]
+    fn derivatives_of_bivariate_polynomial() {
+        let mut function = PolynomialFunction::with_degree(2, 2);
+
+        // 1 + x1 - x1 x2 + 2 x2^2
+        function.set_parameters(vector!(1.0, 1.0, 0.0, 0.0, -1.0, 2.0)).unwrap();
+
+        assert_eq!(function.input_gradient(&vector!(2.0, 3.0)), Ok(vector!(1.0 - 3.0, -2.0 + 12.0)));
+        assert_eq!(function.input_hessian(&vector!(2.0, 3.0)), Ok(Matrix::new(2, 2, vec!(0.0, -1.0, -1.0, 4.0))));
+    }
+
+    #[test]
+    fn evaluate_with_wrong_input_size() {
+        let function = PolynomialFunction::with_degree(2, 2);
+
+        assert_eq!(function.f(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+}
@@ -0,0 +1,173 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use optimization::traits::ParametricFunction;
+use super::FunctionParameters;
+
+// One linear function per class, whose outputs (logits) are turned into class probabilities by the
+// softmax function. The parameters are the intercept and coefficients of each class in turn.
+//
+// As a ParametricFunction, the input is made of the features followed by a class index, and f() is
+// the logit of that class.
+pub struct SoftmaxFunction {
+    input_size: usize,
+    class_count: usize,
+    parameters: FunctionParameters
+}
+
+impl SoftmaxFunction {
+    pub fn with_class_count(input_size: usize, class_count: usize) -> SoftmaxFunction {
+        SoftmaxFunction {
+            input_size,
+            class_count,
+            parameters: FunctionParameters::new(vector![0.0; class_count * (input_size + 1)])
+        }
+    }
+
+    pub fn input_size(&self) -> usize {
+        self.input_size
+    }
+
+    pub fn class_count(&self) -> usize {
+        self.class_count
+    }
+
+    fn check_input_size(&self, features: &Vector<f64>) -> Result<()> {
+        if features.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: features.size() });
+        }
+
+        Ok(())
+    }
+
+    // Splits a ParametricFunction input into the features and the class index.
+    fn split_class(&self, input: &Vector<f64>) -> Result<(Vector<f64>, usize)> {
+        if input.size() != self.input_size + 1 {
+            return Err(Error::DimensionMismatch { expected: self.input_size + 1, actual: input.size() });
+        }
+
+        let class = input[self.input_size];
+
+        if class < 0.0 || class.fract() != 0.0 || class as usize >= self.class_count {
+            return Err(Error::InvalidLabel(class));
+        }
+
+        Ok((Vector::new(&input.data()[..self.input_size]), class as usize))
+    }
+
+    fn class_parameters(&self, class: usize) -> &[f64] {
+        let block_size = self.input_size + 1;
+
+        &self.parameters.vector().data()[class * block_size..(class + 1) * block_size]
+    }
+
+    fn logit(&self, features: &Vector<f64>, class: usize) -> f64 {
+        let parameters = self.class_parameters(class);
+
+        parameters[0] + parameters[1..].iter().zip(features.iter()).map(|(p, x)| p * x).sum::<f64>()
+    }
+
+    pub fn logits(&self, features: &Vector<f64>) -> Result<Vector<f64>> {
+        self.check_input_size(features)?;
+
+        Ok((0..self.class_count).map(|class| self.logit(features, class)).collect())
+    }
+
+    pub fn probabilities(&self, features: &Vector<f64>) -> Result<Vector<f64>> {
+        let logits = self.logits(features)?;
+        // shifted by the largest logit to avoid overflows
+        let max = logits.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let exponentials: Vector<f64> = logits.iter().map(|logit| (logit - max).exp()).collect();
+        let sum = exponentials.sum();
+
+        Ok(exponentials / sum)
+    }
+}
+
+impl ParametricFunction for SoftmaxFunction {
+    fn parameters(&self) -> &Vector<f64> {
+        self.parameters.vector()
+    }
+
+    fn set_parameters(&mut self, new_parameters: Vector<f64>) -> Result<()> {
+        self.parameters.set_vector(new_parameters)
+    }
+
+    fn f(&self, input: &Vector<f64>) -> Result<f64> {
+        let (features, class) = self.split_class(input)?;
+
+        Ok(self.logit(&features, class))
+    }
+
+    // The class index is not differentiable: its partial derivative is reported as 0.
+    fn input_gradient(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        let (_, class) = self.split_class(input)?;
+        let mut gradient = self.class_parameters(class)[1..].to_vec();
+
+        gradient.push(0.0);
+
+        Ok(Vector::new(gradient))
+    }
+
+    fn parameter_gradients(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        let (features, class) = self.split_class(input)?;
+        let block_size = self.input_size + 1;
+        let mut gradients = vec![0.0; self.class_count * block_size];
+
+        gradients[class * block_size] = 1.0;
+        gradients[class * block_size + 1..(class + 1) * block_size].copy_from_slice(features.data());
+
+        Ok(Vector::new(gradients))
+    }
+
+    fn input_hessian(&self, input: &Vector<f64>) -> Result<Matrix<f64>> {
+        self.split_class(input)?;
+
+        Ok(Matrix::zeros(input.size(), input.size()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SoftmaxFunction;
+    use Error;
+    use optimization::traits::ParametricFunction;
+
+    fn build_test_function() -> SoftmaxFunction {
+        let mut function = SoftmaxFunction::with_class_count(1, 3);
+
+        // logits 0, 1 + x and -x
+        function.set_parameters(vector!(0.0, 0.0, 1.0, 1.0, 0.0, -1.0)).unwrap();
+
+        function
+    }
+
+    #[test]
+    fn logit_of_class() {
+        let function = build_test_function();
+
+        assert_eq!(function.f(&vector!(2.0, 1.0)), Ok(3.0));
+        assert_eq!(function.input_gradient(&vector!(2.0, 2.0)), Ok(vector!(-1.0, 0.0)));
+        assert_eq!(function.parameter_gradients(&vector!(2.0, 1.0)), Ok(vector!(0.0, 0.0, 1.0, 2.0, 0.0, 0.0)));
+        assert_eq!(function.logits(&vector!(2.0)), Ok(vector!(0.0, 3.0, -2.0)));
+    }
+
+    #[test]
+    fn probabilities_sum_to_1() {
+        let function = build_test_function();
+        let probabilities = function.probabilities(&vector!(1000.0)).unwrap();
+
+        assert_relative_eq!(probabilities.sum(), 1.0);
+        assert_relative_eq!(probabilities[1], 1.0);
+    }
+
+    #[test]
+    fn invalid_class() {
+        let function = build_test_function();
+
+        assert_eq!(function.f(&vector!(2.0, 3.0)), Err(Error::InvalidLabel(3.0)));
+        assert_eq!(function.f(&vector!(2.0, 0.5)), Err(Error::InvalidLabel(0.5)));
+        assert_eq!(function.f(&vector!(2.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+// Rules deciding when an iterative fit stops. Only the maximum number of iterations is mandatory,
+// every other rule is disabled until it is set.
+#[derive(Clone, Debug)]
+pub struct StoppingCriteria {
+    max_iterations: u32,
+    loss_tolerance: Option<f64>,
+    relative_loss_tolerance: Option<f64>,
+    gradient_tolerance: Option<f64>,
+    parameter_tolerance: Option<f64>,
+    time_budget: Option<Duration>
+}
+
+impl StoppingCriteria {
+    pub fn new(max_iterations: u32) -> StoppingCriteria {
+        StoppingCriteria {
+            max_iterations,
+            loss_tolerance: None,
+            relative_loss_tolerance: None,
+            gradient_tolerance: None,
+            parameter_tolerance: None,
+            time_budget: None
+        }
+    }
+
+    // Stops once the loss is lower than or equal to the tolerance.
+    pub fn with_loss_tolerance(mut self, tolerance: f64) -> StoppingCriteria {
+        self.loss_tolerance = Some(tolerance);
+        self
+    }
+
+    // Stops once an iteration improves the loss by less than the tolerance times the previous loss.
+    pub fn with_relative_loss_tolerance(mut self, tolerance: f64) -> StoppingCriteria {
+        self.relative_loss_tolerance = Some(tolerance);
+        self
+    }
+
+    // Stops once the Euclidean norm of the loss gradients is lower than or equal to the tolerance.
+    pub fn with_gradient_tolerance(mut self, tolerance: f64) -> StoppingCriteria {
+        self.gradient_tolerance = Some(tolerance);
+        self
+    }
+
+    // Stops once an iteration moves the parameters by a Euclidean distance lower than or equal to
+    // the tolerance.
+    pub fn with_parameter_tolerance(mut self, tolerance: f64) -> StoppingCriteria {
+        self.parameter_tolerance = Some(tolerance);
+        self
+    }
+
+    // Stops once the fit has run for longer than the budget.
+    pub fn with_time_budget(mut self, budget: Duration) -> StoppingCriteria {
+        self.time_budget = Some(budget);
+        self
+    }
+
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    pub fn loss_tolerance(&self) -> Option<f64> {
+        self.loss_tolerance
+    }
+
+    pub fn relative_loss_tolerance(&self) -> Option<f64> {
+        self.relative_loss_tolerance
+    }
+
+    pub fn gradient_tolerance(&self) -> Option<f64> {
+        self.gradient_tolerance
+    }
+
+    pub fn parameter_tolerance(&self) -> Option<f64> {
+        self.parameter_tolerance
+    }
+
+    pub fn time_budget(&self) -> Option<Duration> {
+        self.time_budget
+    }
+}
@@ -0,0 +1,97 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+
+// Values of a feature over the dataset.
+pub fn feature_values(dataset: &UnsupervisedDataset, feature: usize) -> Vec<f64> {
+    (0..dataset.len()).map(|index| dataset.inputs()[[index, feature]]).collect()
+}
+
+// Shift and scale of every feature, z = (x - shift) / scale, fitted by each scaler in its own way.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureScaling {
+    shifts: Vec<f64>,
+    scales: Vec<f64>
+}
+
+impl FeatureScaling {
+    // Fits the shift and the scale of each feature from its values. Scales of 0 (e.g. constant
+    // features) are replaced by 1, so that those features are only shifted.
+    pub fn fit<S>(dataset: &UnsupervisedDataset, fit_feature: S) -> Result<FeatureScaling>
+    where S: Fn(&[f64]) -> (f64, f64) {
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let mut shifts = Vec::with_capacity(dataset.feature_count());
+        let mut scales = Vec::with_capacity(dataset.feature_count());
+
+        for feature in 0..dataset.feature_count() {
+            let (shift, scale) = fit_feature(&feature_values(dataset, feature));
+
+            shifts.push(shift);
+            scales.push(if scale == 0.0 { 1.0 } else { scale });
+        }
+
+        Ok(FeatureScaling { shifts, scales })
+    }
+
+    pub fn shifts(&self) -> &[f64] {
+        &self.shifts
+    }
+
+    pub fn scales(&self) -> &[f64] {
+        &self.scales
+    }
+
+    pub fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        if input.size() != self.shifts.len() {
+            return Err(Error::DimensionMismatch { expected: self.shifts.len(), actual: input.size() });
+        }
+
+        Ok(input.iter().zip(self.shifts.iter().zip(self.scales.iter())).map(|(x, (shift, scale))| (x - shift) / scale).collect())
+    }
+
+    // diag(1 / scale) x - shift / scale
+    pub fn affine_map(&self) -> (Matrix<f64>, Vector<f64>) {
+        let size = self.scales.len();
+
+        (Matrix::from_fn(size, size, |j, i| if i == j { 1.0 / self.scales[i] } else { 0.0 }),
+         self.shifts.iter().zip(self.scales.iter()).map(|(shift, scale)| -shift / scale).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use Error;
+    use datasets::UnsupervisedDataset;
+    use super::FeatureScaling;
+
+    #[test]
+    fn fit_and_transform() {
+        let dataset = UnsupervisedDataset::new(Matrix::new(2, 2, vec!(1.0, 5.0, 3.0, 5.0)));
+        // shift by the first value, scale by the difference to the second one
+        let scaling = FeatureScaling::fit(&dataset, |values| (values[0], values[1] - values[0])).unwrap();
+
+        assert_eq!(scaling.shifts(), &[1.0, 5.0]);
+        // the constant feature is only shifted
+        assert_eq!(scaling.scales(), &[2.0, 1.0]);
+        assert_eq!(scaling.transform(&vector!(2.0, 7.0)), Ok(vector!(0.5, 2.0)));
+        assert_eq!(scaling.transform(&vector!(2.0)), Err(Error::DimensionMismatch { expected: 2, actual: 1 }));
+
+        let (matrix, vector) = scaling.affine_map();
+
+        assert_eq!(&matrix * vector!(2.0, 7.0) + vector, vector!(0.5, 2.0));
+    }
+
+    #[test]
+    fn fit_empty_dataset() {
+        let dataset = UnsupervisedDataset::from_inputs(&[]).unwrap();
+
+        assert_eq!(FeatureScaling::fit(&dataset, |_| (0.0, 1.0)).err(), Some(Error::EmptyDataset));
+    }
+}
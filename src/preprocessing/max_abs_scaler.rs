@@ -0,0 +1,62 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+use super::Transformer;
+use super::feature_scaling::FeatureScaling;
+
+// Scales every feature by its maximum absolute value onto [-1, 1], without shifting it so that
+// sparse features (mostly 0) stay sparse.
+#[derive(Clone, Debug, Default)]
+pub struct MaxAbsScaler {
+    scaling: Option<FeatureScaling>
+}
+
+impl MaxAbsScaler {
+    pub fn new() -> MaxAbsScaler {
+        MaxAbsScaler { scaling: None }
+    }
+
+    // Maximum absolute value of every feature, 1 for features that are always 0.
+    pub fn maximum_absolute_values(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.scales())
+    }
+}
+
+impl Transformer for MaxAbsScaler {
+    fn fit(&mut self, dataset: &UnsupervisedDataset) -> Result<()> {
+        self.scaling = Some(FeatureScaling::fit(dataset, |values| {
+            (0.0, values.iter().fold(0.0, |maximum, value| f64::max(maximum, value.abs())))
+        })?);
+
+        Ok(())
+    }
+
+    fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.scaling.as_ref().ok_or(Error::NotFitted)?.transform(input)
+    }
+
+    fn affine_map(&self) -> Result<(Matrix<f64>, Vector<f64>)> {
+        Ok(self.scaling.as_ref().ok_or(Error::NotFitted)?.affine_map())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use datasets::UnsupervisedDataset;
+    use preprocessing::Transformer;
+    use super::MaxAbsScaler;
+
+    #[test]
+    fn fit_transform() {
+        let mut scaler = MaxAbsScaler::new();
+        let dataset = UnsupervisedDataset::new(Matrix::new(3, 2, vec!(-4.0, 0.0, 2.0, 0.0, 0.0, 0.0)));
+        let transformed_dataset = scaler.fit_transform(&dataset).unwrap();
+
+        assert_eq!(scaler.maximum_absolute_values(), Some(&[4.0, 1.0][..]));
+        assert_eq!(transformed_dataset.iter().collect::<Vec<_>>(), vec!(vector!(-1.0, 0.0), vector!(0.5, 0.0), vector!(0.0, 0.0)));
+    }
+}
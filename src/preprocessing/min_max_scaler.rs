@@ -0,0 +1,71 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+use super::Transformer;
+use super::feature_scaling::FeatureScaling;
+
+// Maps every feature from its [minimum, maximum] range onto [0, 1].
+#[derive(Clone, Debug, Default)]
+pub struct MinMaxScaler {
+    scaling: Option<FeatureScaling>
+}
+
+impl MinMaxScaler {
+    pub fn new() -> MinMaxScaler {
+        MinMaxScaler { scaling: None }
+    }
+
+    pub fn minimums(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.shifts())
+    }
+
+    // Maximum - minimum of every feature, 1 for constant features.
+    pub fn ranges(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.scales())
+    }
+}
+
+impl Transformer for MinMaxScaler {
+    fn fit(&mut self, dataset: &UnsupervisedDataset) -> Result<()> {
+        self.scaling = Some(FeatureScaling::fit(dataset, |values| {
+            let minimum = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            let maximum = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+            (minimum, maximum - minimum)
+        })?);
+
+        Ok(())
+    }
+
+    fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.scaling.as_ref().ok_or(Error::NotFitted)?.transform(input)
+    }
+
+    fn affine_map(&self) -> Result<(Matrix<f64>, Vector<f64>)> {
+        Ok(self.scaling.as_ref().ok_or(Error::NotFitted)?.affine_map())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use datasets::UnsupervisedDataset;
+    use preprocessing::Transformer;
+    use super::MinMaxScaler;
+
+    #[test]
+    fn fit_transform() {
+        let mut scaler = MinMaxScaler::new();
+        let dataset = UnsupervisedDataset::new(Matrix::new(3, 2, vec!(-1.0, 4.0, 3.0, 4.0, 1.0, 4.0)));
+        let transformed_dataset = scaler.fit_transform(&dataset).unwrap();
+
+        assert_eq!(scaler.minimums(), Some(&[-1.0, 4.0][..]));
+        assert_eq!(scaler.ranges(), Some(&[4.0, 1.0][..]));
+        assert_eq!(transformed_dataset.iter().collect::<Vec<_>>(), vec!(vector!(0.0, 0.0), vector!(1.0, 0.0), vector!(0.5, 0.0)));
+        // new inputs may fall outside of [0, 1]
+        assert_eq!(scaler.transform(&vector!(5.0, 6.0)), Ok(vector!(1.5, 2.0)));
+    }
+}
@@ -0,0 +1,17 @@
+mod traits;
+pub use self::traits::Transformer;
+
+mod feature_scaling;
+mod standard_scaler;
+mod min_max_scaler;
+mod robust_scaler;
+mod max_abs_scaler;
+mod one_hot_encoder;
+mod pipeline;
+
+pub use self::standard_scaler::StandardScaler;
+pub use self::min_max_scaler::MinMaxScaler;
+pub use self::robust_scaler::RobustScaler;
+pub use self::max_abs_scaler::MaxAbsScaler;
+pub use self::one_hot_encoder::OneHotEncoder;
+pub use self::pipeline::Pipeline;
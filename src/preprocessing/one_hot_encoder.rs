@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+use super::Transformer;
+use super::feature_scaling::feature_values;
+
+// Replaces every categorical feature by one indicator feature per category seen when fitting, in
+// increasing order of the category values. The other features are kept as they are.
+//
+// With an intercept, the indicators of a feature always sum to 1 and the least squares problem is
+// rank deficient: dropping the first indicator makes its category the reference one.
+#[derive(Clone, Debug)]
+pub struct OneHotEncoder {
+    features: Vec<usize>,
+    drop_first: bool,
+    input_size: usize,
+    categories: Option<Vec<Vec<f64>>>
+}
+
+impl OneHotEncoder {
+    // Encoder of the categorical features at the given indices.
+    pub fn new(features: Vec<usize>) -> OneHotEncoder {
+        OneHotEncoder {
+            features,
+            drop_first: false,
+            input_size: 0,
+            categories: None
+        }
+    }
+
+    pub fn with_drop_first(mut self, drop_first: bool) -> OneHotEncoder {
+        self.drop_first = drop_first;
+        self
+    }
+
+    pub fn features(&self) -> &[usize] {
+        &self.features
+    }
+
+    // Sorted categories of every encoded feature, in the order of features().
+    pub fn categories(&self) -> Option<&[Vec<f64>]> {
+        self.categories.as_deref()
+    }
+
+    // Categories with an indicator feature.
+    fn encoded_categories<'a>(&self, categories: &'a [f64]) -> &'a [f64] {
+        if self.drop_first && !categories.is_empty() {
+            &categories[1..]
+        } else {
+            categories
+        }
+    }
+
+    fn feature_categories(&self, feature: usize) -> Option<&[f64]> {
+        let position = self.features.iter().position(|&encoded_feature| encoded_feature == feature)?;
+
+        self.categories.as_ref().map(|categories| &categories[position][..])
+    }
+}
+
+impl Transformer for OneHotEncoder {
+    fn fit(&mut self, dataset: &UnsupervisedDataset) -> Result<()> {
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let mut categories = Vec::with_capacity(self.features.len());
+
+        for (i, &feature) in self.features.iter().enumerate() {
+            if feature >= dataset.feature_count() {
+                return Err(Error::InvalidSetting(format!("feature {} is not among the {} features", feature, dataset.feature_count())));
+            }
+            if self.features[..i].contains(&feature) {
+                return Err(Error::InvalidSetting(format!("feature {} is encoded twice", feature)));
+            }
+
+            let mut values = feature_values(dataset, feature);
+
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+            values.dedup();
+            categories.push(values);
+        }
+
+        self.input_size = dataset.feature_count();
+        self.categories = Some(categories);
+
+        Ok(())
+    }
+
+    fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        if self.categories.is_none() {
+            return Err(Error::NotFitted);
+        }
+        if input.size() != self.input_size {
+            return Err(Error::DimensionMismatch { expected: self.input_size, actual: input.size() });
+        }
+
+        let mut output = Vec::with_capacity(self.input_size);
+
+        for (feature, &value) in input.iter().enumerate() {
+            match self.feature_categories(feature) {
+                None => output.push(value),
+                Some(categories) => {
+                    if !categories.contains(&value) {
+                        return Err(Error::UnknownCategory { feature, value });
+                    }
+
+                    output.extend(self.encoded_categories(categories).iter().map(|&category| if category == value { 1.0 } else { 0.0 }));
+                }
+            }
+        }
+
+        Ok(Vector::new(output))
+    }
+
+    // Names of the indicators as "feature=category".
+    fn transform_feature_names(&self, feature_names: &[String]) -> Result<Vec<String>> {
+        if self.categories.is_none() {
+            return Err(Error::NotFitted);
+        }
+
+        let mut output_names = vec!();
+
+        for (feature, name) in feature_names.iter().enumerate() {
+            match self.feature_categories(feature) {
+                None => output_names.push(name.clone()),
+                Some(categories) => {
+                    output_names.extend(self.encoded_categories(categories).iter().map(|category| format!("{}={}", name, category)));
+                }
+            }
+        }
+
+        Ok(output_names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use Error;
+    use datasets::UnsupervisedDataset;
+    use preprocessing::Transformer;
+    use super::OneHotEncoder;
+
+    fn build_dataset() -> UnsupervisedDataset {
+        // a numerical feature between two categorical ones
+        UnsupervisedDataset::new(Matrix::new(3, 3, vec!(2.0, 0.5, 1.0,
+                                                        0.0, 1.5, 1.0,
+                                                        2.0, 2.5, 3.0)))
+            .with_feature_names(vec!("color".to_string(), "size".to_string(), "shape".to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn fit_transform() {
+        let mut encoder = OneHotEncoder::new(vec!(0, 2));
+
+        assert_eq!(encoder.transform(&vector!(2.0, 0.5, 1.0)), Err(Error::NotFitted));
+
+        let transformed_dataset = encoder.fit_transform(&build_dataset()).unwrap();
+
+        assert_eq!(encoder.categories(), Some(&[vec!(0.0, 2.0), vec!(1.0, 3.0)][..]));
+        assert_eq!(transformed_dataset.iter().collect::<Vec<_>>(), vec!(vector!(0.0, 1.0, 0.5, 1.0, 0.0),
+                                                                         vector!(1.0, 0.0, 1.5, 1.0, 0.0),
+                                                                         vector!(0.0, 1.0, 2.5, 0.0, 1.0)));
+        assert_eq!(transformed_dataset.feature_names().unwrap(), &["color=0", "color=2", "size", "shape=1", "shape=3"]);
+        assert_eq!(encoder.transform(&vector!(1.0, 0.5, 1.0)), Err(Error::UnknownCategory { feature: 0, value: 1.0 }));
+        assert_eq!(encoder.transform(&vector!(2.0, 0.5)), Err(Error::DimensionMismatch { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn fit_transform_dropping_first_category() {
+        let mut encoder = OneHotEncoder::new(vec!(2, 0)).with_drop_first(true);
+        let transformed_dataset = encoder.fit_transform(&build_dataset()).unwrap();
+
+        assert_eq!(transformed_dataset.iter().collect::<Vec<_>>(), vec!(vector!(1.0, 0.5, 0.0),
+                                                                         vector!(0.0, 1.5, 0.0),
+                                                                         vector!(1.0, 2.5, 1.0)));
+        assert_eq!(transformed_dataset.feature_names().unwrap(), &["color=2", "size", "shape=3"]);
+    }
+
+    #[test]
+    fn fit_invalid_features() {
+        assert_eq!(OneHotEncoder::new(vec!(3)).fit(&build_dataset()),
+                   Err(Error::InvalidSetting("feature 3 is not among the 3 features".to_string())));
+        assert_eq!(OneHotEncoder::new(vec!(1, 1)).fit(&build_dataset()),
+                   Err(Error::InvalidSetting("feature 1 is encoded twice".to_string())));
+    }
+}
@@ -0,0 +1,228 @@
+use rulinalg::matrix::BaseMatrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use Model;
+use datasets::SupervisedDataset;
+use datasets::UnsupervisedDataset;
+use super::Transformer;
+
+// Transformers applied in turn to the inputs of a model, fitted on the inputs of the dataset the
+// pipeline is fitted on, then applied to every input it predicts.
+pub struct Pipeline<M> {
+    transformers: Vec<Box<dyn Transformer>>,
+    model: M
+}
+
+impl<M> Pipeline<M> {
+    pub fn new(model: M) -> Pipeline<M> {
+        Pipeline {
+            transformers: vec!(),
+            model
+        }
+    }
+
+    // Transformer applied after the previous ones.
+    pub fn with_transformer<T: Transformer + 'static>(mut self, transformer: T) -> Pipeline<M> {
+        self.transformers.push(Box::new(transformer));
+        self
+    }
+
+    pub fn transformers(&self) -> &[Box<dyn Transformer>] {
+        &self.transformers
+    }
+
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    // The input as given to the model.
+    pub fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        let mut transformed_input = input.clone();
+
+        for transformer in self.transformers.iter() {
+            transformed_input = transformer.transform(&transformed_input)?;
+        }
+
+        Ok(transformed_input)
+    }
+
+    // The dataset as given to the model.
+    pub fn transform_dataset(&self, dataset: &UnsupervisedDataset) -> Result<UnsupervisedDataset> {
+        let mut transformed_dataset = dataset.clone();
+
+        for transformer in self.transformers.iter() {
+            transformed_dataset = transformer.transform_dataset(&transformed_dataset)?;
+        }
+
+        Ok(transformed_dataset)
+    }
+
+    // Parameters (intercept followed by the coefficients) of a linear function of the original
+    // inputs, equal to the linear function of the transformed inputs with the given parameters,
+    // whose intercept is 0 when they only hold coefficients (e.g. a model without intercept).
+    // Only available when every transformer is affine, x -> A x + c giving b0 + b.c + (A^T b).x.
+    pub fn original_parameters(&self, parameters: &Vector<f64>) -> Result<Vector<f64>> {
+        let affine_maps = self.transformers.iter().map(|transformer| transformer.affine_map()).collect::<Result<Vec<_>>>()?;
+        // without transformers, the parameters are taken to start with an intercept
+        let transformed_size = affine_maps.last().map_or(parameters.size().saturating_sub(1), |affine_map| affine_map.0.rows());
+        let (mut intercept, mut coefficients) = if parameters.size() == transformed_size {
+            (0.0, parameters.clone())
+        } else if parameters.size() == transformed_size + 1 {
+            (parameters[0], Vector::new(&parameters.data()[1..]))
+        } else {
+            return Err(Error::DimensionMismatch { expected: transformed_size + 1, actual: parameters.size() });
+        };
+
+        for (matrix, vector) in affine_maps.iter().rev() {
+            if coefficients.size() != matrix.rows() {
+                return Err(Error::DimensionMismatch { expected: matrix.rows() + 1, actual: coefficients.size() + 1 });
+            }
+
+            intercept += coefficients.dot(vector);
+            coefficients = matrix.transpose() * coefficients;
+        }
+
+        let mut original_parameters = vec!(intercept);
+
+        original_parameters.extend(coefficients.into_vec());
+
+        Ok(Vector::new(original_parameters))
+    }
+
+    fn fit_transformers(&mut self, dataset: &UnsupervisedDataset) -> Result<UnsupervisedDataset> {
+        let mut transformed_dataset = dataset.clone();
+
+        for transformer in self.transformers.iter_mut() {
+            transformed_dataset = transformer.fit_transform(&transformed_dataset)?;
+        }
+
+        Ok(transformed_dataset)
+    }
+}
+
+impl<M, O> Model<Vector<f64>, O> for Pipeline<M>
+where M: Model<Vector<f64>, O>,
+      O: Clone {
+    fn fit_supervised_dataset(&mut self, dataset: &SupervisedDataset<O>) -> Result<()> {
+        let transformed_inputs = self.fit_transformers(dataset.unsupervised_dataset())?;
+
+        self.model.fit_supervised_dataset(&dataset.clone().with_unsupervised_dataset(transformed_inputs)?)
+    }
+
+    fn fit_unsupervised_dataset(&mut self, dataset: &UnsupervisedDataset) -> Result<()> {
+        let transformed_dataset = self.fit_transformers(dataset)?;
+
+        self.model.fit_unsupervised_dataset(&transformed_dataset)
+    }
+
+    fn predict(&self, data: &Vector<f64>) -> Result<O> {
+        self.model.predict(&self.transform(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::vector::Vector;
+
+    use {Error, Result};
+    use Model;
+    use datasets::SupervisedDataset;
+    use preprocessing::StandardScaler;
+    use preprocessing::MinMaxScaler;
+    use preprocessing::OneHotEncoder;
+    use regression::LinearRegressionModel;
+    use regression::LinearRegressionSolver;
+    use super::Pipeline;
+
+    // Model predicting the sum of the inputs it was fitted on, to observe the transformed inputs.
+    struct InputSum {
+        sum: Option<f64>
+    }
+
+    impl Model<Vector<f64>, f64> for InputSum {
+        fn fit_supervised_dataset(&mut self, dataset: &SupervisedDataset<f64>) -> Result<()> {
+            self.sum = Some(dataset.iter().map(|(x, _)| x.sum()).sum());
+
+            Ok(())
+        }
+
+        fn predict(&self, data: &Vector<f64>) -> Result<f64> {
+            self.sum.ok_or(Error::NotFitted).map(|sum| sum + data.sum())
+        }
+    }
+
+    // y = 1 + 2x - z
+    fn build_dataset() -> SupervisedDataset<f64> {
+        SupervisedDataset::from_samples(vec!((vector!(0.0, 10.0), -9.0),
+                                             (vector!(1.0, 30.0), -27.0),
+                                             (vector!(2.0, 20.0), -15.0),
+                                             (vector!(4.0, 60.0), -51.0))).unwrap()
+    }
+
+    #[test]
+    fn fit_and_predict_transformed_inputs() {
+        let mut pipeline = Pipeline::new(InputSum { sum: None }).with_transformer(MinMaxScaler::new());
+
+        assert_eq!(pipeline.predict(&vector!(0.0, 10.0)), Err(Error::NotFitted));
+        pipeline.fit_supervised_dataset(&build_dataset()).unwrap();
+
+        // inputs (0, 0), (0.25, 0.4), (0.5, 0.2) and (1, 1)
+        assert_eq!(pipeline.transform(&vector!(2.0, 20.0)), Ok(vector!(0.5, 0.2)));
+        assert_relative_eq!(pipeline.predict(&vector!(4.0, 60.0)).unwrap(), 3.35 + 2.0, epsilon = 1e-12);
+        assert_eq!(pipeline.fit_unsupervised_dataset(build_dataset().unsupervised_dataset()), Err(Error::Unsupported));
+    }
+
+    #[test]
+    fn original_parameters_of_linear_regression() {
+        let dataset = build_dataset();
+        let mut pipeline = Pipeline::new(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr))
+            .with_transformer(StandardScaler::new())
+            .with_transformer(MinMaxScaler::new());
+
+        pipeline.fit_supervised_dataset(&dataset).unwrap();
+
+        let parameters = pipeline.original_parameters(pipeline.model().parameters().unwrap()).unwrap();
+
+        assert_relative_eq!(parameters[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(parameters[1], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(parameters[2], -1.0, epsilon = 1e-12);
+        assert_relative_eq!(pipeline.predict(&vector!(3.0, 5.0)).unwrap(), 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn original_parameters_of_linear_regression_without_intercept() {
+        let mut pipeline = Pipeline::new(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr).with_intercept(false))
+            .with_transformer(StandardScaler::new());
+
+        pipeline.fit_supervised_dataset(&build_dataset()).unwrap();
+
+        // through the origin of the standardized inputs, i.e. the mean input (1.75, 30), the fit
+        // keeps the slopes of y = 1 + 2x - z but predicts 0 there
+        let parameters = pipeline.original_parameters(pipeline.model().parameters().unwrap()).unwrap();
+
+        assert_eq!(pipeline.model().parameters().unwrap().size(), 2);
+        assert_relative_eq!(parameters[0], 30.0 - 2.0 * 1.75, epsilon = 1e-12);
+        assert_relative_eq!(parameters[1], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(parameters[2], -1.0, epsilon = 1e-12);
+        assert_relative_eq!(pipeline.predict(&vector!(3.0, 5.0)).unwrap(),
+                            parameters[0] + 3.0 * parameters[1] + 5.0 * parameters[2],
+                            epsilon = 1e-12);
+    }
+
+    #[test]
+    fn original_parameters_errors() {
+        let mut pipeline = Pipeline::new(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr))
+            .with_transformer(StandardScaler::new());
+
+        assert_eq!(pipeline.original_parameters(&vector!(1.0, 2.0, 3.0)), Err(Error::NotFitted));
+        pipeline.fit_supervised_dataset(&build_dataset()).unwrap();
+        assert_eq!(pipeline.original_parameters(&vector!(1.0)), Err(Error::DimensionMismatch { expected: 3, actual: 1 }));
+
+        // the indicators are not an affine map of the category
+        let pipeline = Pipeline::new(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr))
+            .with_transformer(OneHotEncoder::new(vec!(0)));
+
+        assert_eq!(pipeline.original_parameters(&vector!(1.0, 2.0, 3.0)), Err(Error::Unsupported));
+    }
+}
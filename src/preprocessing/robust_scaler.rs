@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+use super::Transformer;
+use super::feature_scaling::FeatureScaling;
+
+// Quantile of sorted values, linearly interpolated between the closest ranks.
+fn quantile(sorted_values: &[f64], probability: f64) -> f64 {
+    let rank = probability * (sorted_values.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted_values[lower] + (rank - lower as f64) * (sorted_values[upper] - sorted_values[lower])
+}
+
+// Centers every feature on its median and scales it by its interquartile range, so that outliers
+// weigh less on the scaling than with the StandardScaler.
+#[derive(Clone, Debug, Default)]
+pub struct RobustScaler {
+    scaling: Option<FeatureScaling>
+}
+
+impl RobustScaler {
+    pub fn new() -> RobustScaler {
+        RobustScaler { scaling: None }
+    }
+
+    pub fn medians(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.shifts())
+    }
+
+    // Third - first quartile of every feature, 1 when they are equal.
+    pub fn interquartile_ranges(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.scales())
+    }
+}
+
+impl Transformer for RobustScaler {
+    fn fit(&mut self, dataset: &UnsupervisedDataset) -> Result<()> {
+        self.scaling = Some(FeatureScaling::fit(dataset, |values| {
+            let mut sorted_values = values.to_vec();
+
+            sorted_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+            (quantile(&sorted_values, 0.5), quantile(&sorted_values, 0.75) - quantile(&sorted_values, 0.25))
+        })?);
+
+        Ok(())
+    }
+
+    fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.scaling.as_ref().ok_or(Error::NotFitted)?.transform(input)
+    }
+
+    fn affine_map(&self) -> Result<(Matrix<f64>, Vector<f64>)> {
+        Ok(self.scaling.as_ref().ok_or(Error::NotFitted)?.affine_map())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use datasets::UnsupervisedDataset;
+    use preprocessing::Transformer;
+    use super::quantile;
+    use super::RobustScaler;
+
+    #[test]
+    fn quantile_interpolation() {
+        assert_eq!(quantile(&[1.0, 2.0, 4.0, 8.0], 0.0), 1.0);
+        assert_eq!(quantile(&[1.0, 2.0, 4.0, 8.0], 0.5), 3.0);
+        assert_eq!(quantile(&[1.0, 2.0, 4.0, 8.0], 0.75), 5.0);
+        assert_eq!(quantile(&[1.0, 2.0, 4.0, 8.0], 1.0), 8.0);
+        assert_eq!(quantile(&[3.0], 0.25), 3.0);
+    }
+
+    #[test]
+    fn fit_transform_with_outlier() {
+        let mut scaler = RobustScaler::new();
+        // the outlier 1000 moves neither the median 3 nor the quartiles 2 and 4
+        let dataset = UnsupervisedDataset::new(Matrix::new(5, 1, vec!(1000.0, 2.0, 3.0, 1.0, 4.0)));
+        let transformed_dataset = scaler.fit_transform(&dataset).unwrap();
+
+        assert_eq!(scaler.medians(), Some(&[3.0][..]));
+        assert_eq!(scaler.interquartile_ranges(), Some(&[2.0][..]));
+        assert_eq!(transformed_dataset.input(0), vector!(498.5));
+        assert_eq!(transformed_dataset.input(3), vector!(-1.0));
+    }
+}
@@ -0,0 +1,76 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+use super::Transformer;
+use super::feature_scaling::FeatureScaling;
+
+// Centers every feature on its mean and scales it by its (population) standard deviation.
+#[derive(Clone, Debug, Default)]
+pub struct StandardScaler {
+    scaling: Option<FeatureScaling>
+}
+
+impl StandardScaler {
+    pub fn new() -> StandardScaler {
+        StandardScaler { scaling: None }
+    }
+
+    pub fn means(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.shifts())
+    }
+
+    pub fn standard_deviations(&self) -> Option<&[f64]> {
+        self.scaling.as_ref().map(|scaling| scaling.scales())
+    }
+}
+
+impl Transformer for StandardScaler {
+    fn fit(&mut self, dataset: &UnsupervisedDataset) -> Result<()> {
+        self.scaling = Some(FeatureScaling::fit(dataset, |values| {
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / n;
+
+            (mean, variance.sqrt())
+        })?);
+
+        Ok(())
+    }
+
+    fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>> {
+        self.scaling.as_ref().ok_or(Error::NotFitted)?.transform(input)
+    }
+
+    fn affine_map(&self) -> Result<(Matrix<f64>, Vector<f64>)> {
+        Ok(self.scaling.as_ref().ok_or(Error::NotFitted)?.affine_map())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rulinalg::matrix::Matrix;
+
+    use Error;
+    use datasets::UnsupervisedDataset;
+    use preprocessing::Transformer;
+    use super::StandardScaler;
+
+    #[test]
+    fn fit_transform() {
+        let mut scaler = StandardScaler::new();
+        // means (2, 10) and standard deviations (sqrt(2 / 3), 0)
+        let dataset = UnsupervisedDataset::new(Matrix::new(3, 2, vec!(1.0, 10.0, 2.0, 10.0, 3.0, 10.0)));
+
+        assert_eq!(scaler.transform(&vector!(1.0, 10.0)), Err(Error::NotFitted));
+
+        let transformed_dataset = scaler.fit_transform(&dataset).unwrap();
+        let standard_deviation = (2.0f64 / 3.0).sqrt();
+
+        assert_eq!(scaler.means(), Some(&[2.0, 10.0][..]));
+        assert_eq!(scaler.standard_deviations(), Some(&[standard_deviation, 1.0][..]));
+        assert_eq!(transformed_dataset.input(0), vector!(-1.0 / standard_deviation, 0.0));
+        assert_eq!(transformed_dataset.input(2), vector!(1.0 / standard_deviation, 0.0));
+    }
+}
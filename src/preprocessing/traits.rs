@@ -0,0 +1,38 @@
+use rulinalg::matrix::Matrix;
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use datasets::UnsupervisedDataset;
+
+// Preprocessing step whose settings are fitted on the inputs of a dataset, then mapping every input
+// (of the same dataset or of new ones) to a transformed input.
+pub trait Transformer {
+    fn fit(&mut self, dataset: &UnsupervisedDataset) -> Result<()>;
+
+    fn transform(&self, input: &Vector<f64>) -> Result<Vector<f64>>;
+
+    // Names of the transformed features, given the names of the input ones.
+    fn transform_feature_names(&self, feature_names: &[String]) -> Result<Vec<String>> {
+        Ok(feature_names.to_vec())
+    }
+
+    // Matrix A and vector c such that transform(x) = A x + c, for the affine transforms only.
+    fn affine_map(&self) -> Result<(Matrix<f64>, Vector<f64>)> {
+        Err(Error::Unsupported)
+    }
+
+    fn transform_dataset(&self, dataset: &UnsupervisedDataset) -> Result<UnsupervisedDataset> {
+        let inputs = dataset.iter().map(|input| self.transform(&input)).collect::<Result<Vec<Vector<f64>>>>()?;
+        let transformed_dataset = UnsupervisedDataset::from_inputs(&inputs)?;
+
+        match dataset.feature_names() {
+            None => Ok(transformed_dataset),
+            Some(feature_names) => transformed_dataset.with_feature_names(self.transform_feature_names(feature_names)?)
+        }
+    }
+
+    fn fit_transform(&mut self, dataset: &UnsupervisedDataset) -> Result<UnsupervisedDataset> {
+        self.fit(dataset)?;
+        self.transform_dataset(dataset)
+    }
+}
@@ -0,0 +1,56 @@
+use {Error, Result};
+
+// Weights of the classes in the loss of a classifier, to make up for imbalanced classes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClassWeights {
+    Uniform,
+    // n / (class count * class size), so that every class weighs as much in the loss
+    Balanced,
+    Custom(Vec<f64>)
+}
+
+impl ClassWeights {
+    // Weight of each class for the given class index labels.
+    pub fn weights(&self, labels: &[usize], class_count: usize) -> Result<Vec<f64>> {
+        match *self {
+            ClassWeights::Uniform => Ok(vec![1.0; class_count]),
+            ClassWeights::Balanced => {
+                let mut class_sizes = vec![0; class_count];
+
+                for &label in labels {
+                    class_sizes[label] += 1;
+                }
+
+                Ok(class_sizes.iter()
+                              .map(|&size| if size == 0 { 0.0 } else { labels.len() as f64 / (class_count * size) as f64 })
+                              .collect())
+            },
+            ClassWeights::Custom(ref weights) => {
+                if weights.len() != class_count {
+                    return Err(Error::InvalidSetting(format!("{} class weights given for {} classes", weights.len(), class_count)));
+                }
+
+                Ok(weights.clone())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Error;
+
+    use super::ClassWeights;
+
+    #[test]
+    fn balanced_weights() {
+        assert_eq!(ClassWeights::Balanced.weights(&[0, 1, 1, 1, 2, 2], 4), Ok(vec!(1.5, 0.5, 0.75, 0.0)));
+    }
+
+    #[test]
+    fn custom_weights() {
+        assert_eq!(ClassWeights::Custom(vec!(1.0, 2.0)).weights(&[0, 1], 2), Ok(vec!(1.0, 2.0)));
+        assert_eq!(ClassWeights::Custom(vec!(1.0, 2.0)).weights(&[0, 1], 3),
+                   Err(Error::InvalidSetting("2 class weights given for 3 classes".to_string())));
+    }
+}
@@ -0,0 +1,204 @@
+use std::f64;
+
+// Natural logarithm of the gamma function (Lanczos approximation, g = 7).
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [0.999_999_999_999_809_9,
+                                    676.520_368_121_885_1,
+                                    -1_259.139_216_722_402_8,
+                                    771.323_428_777_653_1,
+                                    -176.615_029_162_140_6,
+                                    12.507_343_278_686_905,
+                                    -0.138_571_095_265_720_12,
+                                    9.984_369_578_019_572e-6,
+                                    1.505_632_735_149_311_6e-7];
+
+    if x < 0.5 {
+        // reflection formula
+        return (f64::consts::PI / (f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS[1..].iter()
+                                  .enumerate()
+                                  .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+
+    0.5 * (2.0 * f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+// Continued fraction of the incomplete beta function (modified Lentz's method).
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+
+    if d.abs() < TINY {
+        d = TINY;
+    }
+
+    d = 1.0 / d;
+
+    let mut fraction = d;
+
+    for m in 1..1000 {
+        let m = f64::from(m);
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+
+        for &coefficient in &[even, odd] {
+            d = 1.0 + coefficient * d;
+            c = 1.0 + coefficient / c;
+
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            if c.abs() < TINY {
+                c = TINY;
+            }
+
+            d = 1.0 / d;
+            fraction *= c * d;
+        }
+
+        if (c * d - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+
+    fraction
+}
+
+// Regularized incomplete beta function I_x(a, b).
+fn incomplete_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+
+    // the continued fraction converges quickly below the mean of the distribution
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - front * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+// P(T <= t) for Student's t distribution with the given degrees of freedom.
+pub fn student_t_cdf(t: f64, degrees_of_freedom: f64) -> f64 {
+    // I_x(a, b) = 1 - I_(1-x)(b, a): the first form keeps the precision of small t
+    let t_squared = t * t;
+
+    if t_squared < degrees_of_freedom {
+        let central = incomplete_beta(t_squared / (degrees_of_freedom + t_squared), 0.5, degrees_of_freedom / 2.0) / 2.0;
+
+        if t > 0.0 { 0.5 + central } else { 0.5 - central }
+    } else {
+        let tail = incomplete_beta(degrees_of_freedom / (degrees_of_freedom + t_squared), degrees_of_freedom / 2.0, 0.5) / 2.0;
+
+        if t > 0.0 { 1.0 - tail } else { tail }
+    }
+}
+
+// P(|T| > |t|) for Student's t distribution with the given degrees of freedom.
+pub fn student_t_two_tailed(t: f64, degrees_of_freedom: f64) -> f64 {
+    2.0 * student_t_cdf(-t.abs(), degrees_of_freedom)
+}
+
+// Value t such that P(T <= t) = probability, found by bisection.
+pub fn student_t_quantile(probability: f64, degrees_of_freedom: f64) -> f64 {
+    if probability <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if probability >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    let mut lower = -1.0;
+    let mut upper = 1.0;
+
+    while student_t_cdf(lower, degrees_of_freedom) > probability {
+        lower *= 2.0;
+    }
+    while student_t_cdf(upper, degrees_of_freedom) < probability {
+        upper *= 2.0;
+    }
+
+    for _ in 0..200 {
+        let middle = (lower + upper) / 2.0;
+
+        if middle == lower || middle == upper {
+            break;
+        }
+
+        if student_t_cdf(middle, degrees_of_freedom) < probability {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    (lower + upper) / 2.0
+}
+
+// P(F > f) for the F distribution with the given degrees of freedom.
+pub fn fisher_snedecor_survival(f: f64, numerator_degrees_of_freedom: f64, denominator_degrees_of_freedom: f64) -> f64 {
+    if f <= 0.0 {
+        return 1.0;
+    }
+
+    incomplete_beta(denominator_degrees_of_freedom / (denominator_degrees_of_freedom + numerator_degrees_of_freedom * f),
+                    denominator_degrees_of_freedom / 2.0,
+                    numerator_degrees_of_freedom / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    use super::{ln_gamma, student_t_cdf, student_t_two_tailed, student_t_quantile, fisher_snedecor_survival};
+
+    #[test]
+    fn gamma_function() {
+        assert_relative_eq!(ln_gamma(1.0), 0.0, epsilon = 1e-14);
+        assert_relative_eq!(ln_gamma(5.0), 24.0f64.ln(), epsilon = 1e-13);
+        assert_relative_eq!(ln_gamma(0.5), f64::consts::PI.sqrt().ln(), epsilon = 1e-14);
+        assert_relative_eq!(ln_gamma(0.25), 3.625_609_908_221_908f64.ln(), epsilon = 1e-14);
+    }
+
+    #[test]
+    fn student_t_distribution() {
+        // Cauchy distribution
+        assert_relative_eq!(student_t_cdf(1.0, 1.0), 0.75, epsilon = 1e-14);
+        // P(T <= t) = 1/2 + t / (2 sqrt(2 + t^2)) with 2 degrees of freedom
+        for &t in &[-3.0f64, -0.5, 0.0, 0.25, 4.0] {
+            assert_relative_eq!(student_t_cdf(t, 2.0), 0.5 + t / (2.0 * (2.0 + t * t).sqrt()), epsilon = 1e-14);
+        }
+        assert_relative_eq!(student_t_two_tailed(2.228_138_851_986_274, 10.0), 0.05, epsilon = 1e-13);
+    }
+
+    #[test]
+    fn student_t_quantiles() {
+        assert_relative_eq!(student_t_quantile(0.975, 10.0), 2.228_138_851_986_274, epsilon = 1e-12);
+        assert_relative_eq!(student_t_quantile(0.975, 30.0), 2.042_272_456_301_238, epsilon = 1e-12);
+        assert_relative_eq!(student_t_quantile(0.025, 30.0), -2.042_272_456_301_238, epsilon = 1e-12);
+        assert_relative_eq!(student_t_quantile(0.5, 3.0), 0.0, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn fisher_snedecor_distribution() {
+        // P(F > f) = (1 + 2 f / d)^(-d / 2) with 2 and d degrees of freedom
+        for &(f, d) in &[(0.5f64, 10.0f64), (3.0, 10.0), (20.0, 7.0)] {
+            let expected = (1.0 + 2.0 * f / d).powf(-d / 2.0);
+
+            assert_relative_eq!(fisher_snedecor_survival(f, 2.0, d), expected, epsilon = 1e-14);
+        }
+        // F(1, d) is the square of T(d)
+        assert_relative_eq!(fisher_snedecor_survival(4.0, 1.0, 12.0), student_t_two_tailed(2.0, 12.0), epsilon = 1e-14);
+        assert_eq!(fisher_snedecor_survival(0.0, 3.0, 4.0), 1.0);
+    }
+}
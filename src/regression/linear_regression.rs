@@ -1,48 +1,226 @@
 use rulinalg::vector::Vector;
 
+use {Error, Result};
 use Model;
+use datasets::SupervisedDataset;
 use optimization::ParametricFunction;
 use optimization::LinearFunction;
+use optimization::GradientDescent;
+use optimization::StoppingCriteria;
+use optimization::Penalty;
+use optimization::optimizers::Sgd;
 use optimization::least_squares_fit;
+use optimization::least_squares_qr_fit;
+use optimization::least_squares_penalized_fit;
+use optimization::least_squares_penalized_qr_fit;
+use optimization::least_squares_coordinate_descent_fit;
+use super::RegressionSummary;
+use super::PredictionInterval;
+
+pub enum LinearRegressionSolver {
+    GradientDescent(GradientDescent),
+    Qr,
+    // the only solver supporting L1 penalties
+    CoordinateDescent(StoppingCriteria)
+}
 
 pub struct LinearRegressionModel {
-    learning_rate: f64,
-    max_iterations: u32,
-    linear_function: Option<LinearFunction>
+    solver: LinearRegressionSolver,
+    penalty: Option<Penalty>,
+    intercept: bool,
+    linear_function: Option<LinearFunction>,
+    // summary of an unpenalized fit, or why it is not available
+    fit_summary: Option<Result<RegressionSummary>>
 }
 
 impl LinearRegressionModel {
-        pub fn new(learning_rate: f64, max_iterations: u32) -> LinearRegressionModel {
+    pub fn new(learning_rate: f64, max_iterations: u32) -> LinearRegressionModel {
+        LinearRegressionModel::with_solver(LinearRegressionSolver::GradientDescent(
+            GradientDescent::new(Sgd::new(learning_rate), StoppingCriteria::new(max_iterations))
+        ))
+    }
+
+    pub fn with_solver(solver: LinearRegressionSolver) -> LinearRegressionModel {
         LinearRegressionModel {
-            learning_rate,
-            max_iterations,
-            linear_function: None
+            solver,
+            penalty: None,
+            intercept: true,
+            linear_function: None,
+            fit_summary: None
         }
     }
-}
 
-impl Model<Vector<f64>, f64> for LinearRegressionModel {
-    // TODO: create Dataset type to ensure that all data have the same number of features
-    // TODO: move Dataset type to Generic Type for single fit function with either supervised or
-    // unsupervised??? (doing so will loose the availability to support both)
-    fn fit_supervised_dataset(&mut self, dataset: &Vec<(Vector<f64>, f64)>) {
-        let input_size = match dataset.first() {
-            None => 0,
-            Some(ref value) => value.0.size()
+    // Ridge regression, solved exactly through the QR decomposition.
+    pub fn ridge(alpha: f64) -> LinearRegressionModel {
+        LinearRegressionModel::with_solver(LinearRegressionSolver::Qr).with_penalty(Penalty::ridge(alpha))
+    }
+
+    pub fn lasso(alpha: f64) -> LinearRegressionModel {
+        LinearRegressionModel::elastic_net(alpha, 1.0)
+    }
+
+    pub fn elastic_net(alpha: f64, l1_ratio: f64) -> LinearRegressionModel {
+        let stopping_criteria = StoppingCriteria::new(10000).with_parameter_tolerance(1e-10);
+
+        LinearRegressionModel::with_solver(LinearRegressionSolver::CoordinateDescent(stopping_criteria))
+            .with_penalty(Penalty::elastic_net(alpha, l1_ratio))
+    }
+
+    // Penalty on the coefficients, the intercept being left unpenalized.
+    pub fn with_penalty(mut self, penalty: Penalty) -> LinearRegressionModel {
+        self.penalty = Some(penalty);
+        self
+    }
+
+    // Without intercept, the model goes through the origin (see LinearFunction::without_intercept()).
+    pub fn with_intercept(mut self, intercept: bool) -> LinearRegressionModel {
+        self.intercept = intercept;
+        self
+    }
+
+    pub fn solver(&self) -> &LinearRegressionSolver {
+        &self.solver
+    }
+
+    pub fn penalty(&self) -> Option<&Penalty> {
+        self.penalty.as_ref()
+    }
+
+    pub fn has_intercept(&self) -> bool {
+        self.intercept
+    }
+
+    // Intercept (if any) followed by the coefficients, once fitted.
+    pub fn parameters(&self) -> Option<&Vector<f64>> {
+        self.linear_function.as_ref().map(|function| function.parameters())
+    }
+
+    // Statistical summary of the fit on the dataset the model was fitted on. Penalized fits are
+    // biased, so their summary is not available.
+    pub fn summary(&self) -> Result<&RegressionSummary> {
+        if self.penalty.is_some() {
+            return Err(Error::InvalidSetting("the summary of a penalized fit is not available".to_string()));
+        }
+
+        match self.fit_summary {
+            None => Err(Error::NotFitted),
+            Some(Ok(ref summary)) => Ok(summary),
+            Some(Err(ref error)) => Err(error.clone())
+        }
+    }
+
+    // Prediction at the input with its confidence and prediction intervals at the confidence level
+    // (e.g. 0.95), from the parameter covariance of the fit. Only available for unpenalized fits on
+    // more samples than parameters.
+    pub fn predict_interval(&self, data: &Vector<f64>, confidence_level: f64) -> Result<PredictionInterval> {
+        if confidence_level <= 0.0 || confidence_level >= 1.0 {
+            return Err(Error::InvalidSetting(format!("the confidence level {} is not between 0 and 1", confidence_level)));
+        }
+
+        let function = match self.linear_function {
+            None => return Err(Error::NotFitted),
+            Some(ref function) => function
+        };
+
+        match self.fit_summary {
+            Some(Ok(ref summary)) => {
+                Ok(summary.prediction_interval(function.f(data)?, &function.parameter_gradients(data)?, confidence_level))
+            },
+            _ => Err(Error::InvalidSetting("prediction intervals need an unpenalized fit on more samples than parameters".to_string()))
+        }
+    }
+
+    // Fits the model for each alpha of the penalty in turn, every fit starting from the previous
+    // one. Returns the parameters fitted for each alpha, the model being left with the last ones.
+    pub fn regularization_path(&mut self, dataset: &SupervisedDataset<f64>, alphas: &[f64]) -> Result<Vec<Vector<f64>>> {
+        let penalty = match self.penalty {
+            None => return Err(Error::InvalidSetting("a regularization path needs a penalty".to_string())),
+            Some(penalty) => penalty
         };
 
-        if input_size > 0 {
-            let mut function = LinearFunction::new(input_size);
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let mut function = self.build_function(dataset.feature_count());
+        let mut path = Vec::with_capacity(alphas.len());
 
-            least_squares_fit(&mut function, dataset, self.learning_rate, self.max_iterations);
+        for &alpha in alphas {
+            self.fit_function(&mut function, dataset, Some(penalty.with_alpha(alpha)))?;
+            path.push(function.parameters().clone());
+        }
+
+        self.linear_function = Some(function);
+        self.fit_summary = None;
+
+        Ok(path)
+    }
 
-            self.linear_function = Some(function);
+    fn build_function(&self, input_size: usize) -> LinearFunction {
+        if self.intercept {
+            LinearFunction::new(input_size)
+        } else {
+            LinearFunction::without_intercept(input_size)
         }
     }
 
-    fn predict(&self, data: &Vector<f64>) -> f64 {
+    fn summarize(&self, function: &LinearFunction, dataset: &SupervisedDataset<f64>) -> Result<RegressionSummary> {
+        if self.intercept {
+            RegressionSummary::new(function, dataset)
+        } else {
+            RegressionSummary::without_intercept(function, dataset)
+        }
+    }
+
+    fn fit_function(&mut self, function: &mut LinearFunction, dataset: &SupervisedDataset<f64>, penalty: Option<Penalty>) -> Result<()> {
+        // the intercept stays unpenalized
+        let penalty = match penalty {
+            Some(penalty) if self.intercept => Some(penalty.with_unpenalized_parameters(penalty.unpenalized_parameters().max(1))),
+            penalty => penalty
+        };
+
+        match (&mut self.solver, penalty) {
+            (&mut LinearRegressionSolver::GradientDescent(ref mut settings), None) => {
+                least_squares_fit(function, dataset, settings)?;
+            },
+            (&mut LinearRegressionSolver::GradientDescent(ref mut settings), Some(penalty)) => {
+                least_squares_penalized_fit(function, dataset, &penalty, settings)?;
+            },
+            (&mut LinearRegressionSolver::Qr, None) => least_squares_qr_fit(function, dataset)?,
+            (&mut LinearRegressionSolver::Qr, Some(penalty)) => least_squares_penalized_qr_fit(function, dataset, &penalty)?,
+            (&mut LinearRegressionSolver::CoordinateDescent(ref stopping_criteria), penalty) => {
+                let penalty = penalty.unwrap_or_else(|| Penalty::ridge(0.0));
+
+                least_squares_coordinate_descent_fit(function, dataset, &penalty, stopping_criteria)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Model<Vector<f64>, f64> for LinearRegressionModel {
+    fn fit_supervised_dataset(&mut self, dataset: &SupervisedDataset<f64>) -> Result<()> {
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let mut function = self.build_function(dataset.feature_count());
+        let penalty = self.penalty;
+
+        self.fit_function(&mut function, dataset, penalty)?;
+        self.fit_summary = match penalty {
+            None => Some(self.summarize(&function, dataset)),
+            Some(_) => None
+        };
+        self.linear_function = Some(function);
+
+        Ok(())
+    }
+
+    fn predict(&self, data: &Vector<f64>) -> Result<f64> {
         match self.linear_function {
-            None => panic!("LinearRegressionModel: trying to predict before fitting."),
+            None => Err(Error::NotFitted),
             Some(ref function) => function.f(data)
         }
     }
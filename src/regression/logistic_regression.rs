@@ -0,0 +1,80 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use Model;
+use datasets::SupervisedDataset;
+use optimization::ParametricFunction;
+use optimization::LinearFunction;
+use optimization::GradientDescent;
+use optimization::StoppingCriteria;
+use optimization::optimizers::Sgd;
+use optimization::sigmoid;
+use optimization::cross_entropy_fit;
+use super::ClassWeights;
+
+// Binary classifier modelling the logit of the probability of the true class as a linear function.
+pub struct LogisticRegressionModel {
+    settings: GradientDescent,
+    class_weights: ClassWeights,
+    linear_function: Option<LinearFunction>
+}
+
+impl LogisticRegressionModel {
+    pub fn new(learning_rate: f64, max_iterations: u32) -> LogisticRegressionModel {
+        LogisticRegressionModel::with_settings(GradientDescent::new(Sgd::new(learning_rate), StoppingCriteria::new(max_iterations)))
+    }
+
+    pub fn with_settings(settings: GradientDescent) -> LogisticRegressionModel {
+        LogisticRegressionModel {
+            settings,
+            class_weights: ClassWeights::Uniform,
+            linear_function: None
+        }
+    }
+
+    // Weights of the false and true classes.
+    pub fn with_class_weights(mut self, class_weights: ClassWeights) -> LogisticRegressionModel {
+        self.class_weights = class_weights;
+        self
+    }
+
+    pub fn settings(&self) -> &GradientDescent {
+        &self.settings
+    }
+
+    // Intercept followed by the coefficients of the logit, once fitted.
+    pub fn parameters(&self) -> Option<&Vector<f64>> {
+        self.linear_function.as_ref().map(|function| function.parameters())
+    }
+
+    // Probability of the true class.
+    pub fn predict_proba(&self, data: &Vector<f64>) -> Result<f64> {
+        match self.linear_function {
+            None => Err(Error::NotFitted),
+            Some(ref function) => Ok(sigmoid(function.f(data)?))
+        }
+    }
+}
+
+impl Model<Vector<f64>, bool> for LogisticRegressionModel {
+    fn fit_supervised_dataset(&mut self, dataset: &SupervisedDataset<bool>) -> Result<()> {
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let classes: Vec<usize> = dataset.labels().iter().map(|&label| label as usize).collect();
+        let class_weights = self.class_weights.weights(&classes, 2)?;
+        let labels = classes.iter().map(|&class| class as f64).collect();
+        let dataset = dataset.clone().with_labels(labels)?;
+        let mut function = LinearFunction::new(dataset.feature_count());
+
+        cross_entropy_fit(&mut function, &dataset, &class_weights, &mut self.settings)?;
+        self.linear_function = Some(function);
+
+        Ok(())
+    }
+
+    fn predict(&self, data: &Vector<f64>) -> Result<bool> {
+        Ok(self.predict_proba(data)? >= 0.5)
+    }
+}
@@ -1,3 +1,18 @@
 mod linear_regression;
+mod parametric_regression;
+mod class_weights;
+mod logistic_regression;
+mod softmax_regression;
+mod distributions;
+mod regression_summary;
+mod prediction_interval;
 
 pub use self::linear_regression::LinearRegressionModel;
+pub use self::linear_regression::LinearRegressionSolver;
+pub use self::parametric_regression::ParametricRegressionModel;
+pub use self::parametric_regression::ParametricRegressionSolver;
+pub use self::regression_summary::RegressionSummary;
+pub use self::prediction_interval::PredictionInterval;
+pub use self::class_weights::ClassWeights;
+pub use self::logistic_regression::LogisticRegressionModel;
+pub use self::softmax_regression::SoftmaxRegressionModel;
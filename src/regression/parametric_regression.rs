@@ -0,0 +1,118 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use Model;
+use datasets::SupervisedDataset;
+use optimization::ParametricFunction;
+use optimization::GradientDescent;
+use optimization::least_squares_fit;
+use optimization::least_squares_qr_fit;
+use optimization::levenberg_marquardt_fit;
+
+pub enum ParametricRegressionSolver {
+    GradientDescent(GradientDescent),
+    // only exact for functions that are linear in their parameters
+    Qr,
+    // maximum number of iterations
+    LevenbergMarquardt(u32)
+}
+
+// Least squares regression of any function, e.g. a polynomial, a basis expansion or a nonlinear
+// model, built beforehand with its own settings. Every fit starts from the parameters the function
+// had when given to the model.
+pub struct ParametricRegressionModel {
+    solver: ParametricRegressionSolver,
+    function: Box<dyn ParametricFunction>,
+    initial_parameters: Vector<f64>,
+    fitted: bool
+}
+
+impl ParametricRegressionModel {
+    pub fn new(function: Box<dyn ParametricFunction>, solver: ParametricRegressionSolver) -> ParametricRegressionModel {
+        ParametricRegressionModel {
+            solver,
+            initial_parameters: function.parameters().clone(),
+            function,
+            fitted: false
+        }
+    }
+
+    pub fn solver(&self) -> &ParametricRegressionSolver {
+        &self.solver
+    }
+
+    pub fn function(&self) -> &dyn ParametricFunction {
+        &*self.function
+    }
+
+    pub fn parameters(&self) -> Option<&Vector<f64>> {
+        if self.fitted {
+            Some(self.function.parameters())
+        } else {
+            None
+        }
+    }
+}
+
+impl Model<Vector<f64>, f64> for ParametricRegressionModel {
+    fn fit_supervised_dataset(&mut self, dataset: &SupervisedDataset<f64>) -> Result<()> {
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        self.fitted = false;
+        self.function.set_parameters(self.initial_parameters.clone())?;
+
+        match self.solver {
+            ParametricRegressionSolver::GradientDescent(ref mut settings) => {
+                least_squares_fit(&mut *self.function, dataset, settings)?;
+            },
+            ParametricRegressionSolver::Qr => least_squares_qr_fit(&mut *self.function, dataset)?,
+            ParametricRegressionSolver::LevenbergMarquardt(max_iterations) => {
+                levenberg_marquardt_fit(&mut *self.function, dataset, max_iterations)?;
+            }
+        }
+
+        self.fitted = true;
+
+        Ok(())
+    }
+
+    fn predict(&self, data: &Vector<f64>) -> Result<f64> {
+        if !self.fitted {
+            return Err(Error::NotFitted);
+        }
+
+        self.function.f(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Error;
+    use Model;
+    use datasets::SupervisedDataset;
+    use optimization::PolynomialFunction;
+
+    use super::ParametricRegressionModel;
+    use super::ParametricRegressionSolver;
+
+    #[test]
+    fn fit_polynomial() {
+        let mut model = ParametricRegressionModel::new(Box::new(PolynomialFunction::with_degree(1, 2)),
+                                                       ParametricRegressionSolver::Qr);
+        // y = 1 - x + x^2
+        let dataset = SupervisedDataset::from_samples((0..5).map(|x| x as f64)
+                                                            .map(|x| (vector!(x), 1.0 - x + x * x))
+                                                            .collect()).unwrap();
+
+        assert_eq!(model.parameters(), None);
+        assert_eq!(model.predict(&vector!(1.0)), Err(Error::NotFitted));
+        assert_eq!(model.fit_supervised_dataset(&SupervisedDataset::from_samples(vec!()).unwrap()), Err(Error::EmptyDataset));
+
+        model.fit_supervised_dataset(&dataset).unwrap();
+
+        assert_eq!(model.function().parameters().size(), 3);
+        assert_relative_eq!(model.predict(&vector!(10.0)).unwrap(), 91.0, epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,33 @@
+// Point estimate of a regression at some input, with the confidence interval of the mean response
+// and the (wider) prediction interval of a new observation, at the same confidence level.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PredictionInterval {
+    estimate: f64,
+    confidence_level: f64,
+    confidence_interval: (f64, f64),
+    prediction_interval: (f64, f64)
+}
+
+impl PredictionInterval {
+    pub fn new(estimate: f64, confidence_level: f64, confidence_interval: (f64, f64), prediction_interval: (f64, f64)) -> PredictionInterval {
+        PredictionInterval { estimate, confidence_level, confidence_interval, prediction_interval }
+    }
+
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+
+    pub fn confidence_level(&self) -> f64 {
+        self.confidence_level
+    }
+
+    // Lower and upper bounds of the mean response.
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        self.confidence_interval
+    }
+
+    // Lower and upper bounds of a new observation.
+    pub fn prediction_interval(&self) -> (f64, f64) {
+        self.prediction_interval
+    }
+}
@@ -0,0 +1,323 @@
+use std::f64;
+
+use rulinalg::matrix::{Matrix, BaseMatrix};
+use rulinalg::vector::Vector;
+
+use Result;
+use datasets::SupervisedDataset;
+use optimization::ParametricFunction;
+use optimization::least_squares_parameter_covariance;
+use super::PredictionInterval;
+use super::distributions::{student_t_two_tailed, student_t_quantile, fisher_snedecor_survival};
+
+// Statistical inference on the parameters of a least squares fit, for functions linear in their
+// parameters whose first parameter is an intercept (e.g. LinearFunction, PolynomialFunction), or
+// that go through the origin (e.g. LinearFunction::without_intercept()). The R^2 and F-test of the
+// latter compare the fit to the null function rather than to the mean, as their total sum of
+// squares is not centered.
+//
+// The information criteria count the residual variance as a parameter, and use the maximum
+// likelihood of a Gaussian noise, -n / 2 (ln(2 pi RSS / n) + 1).
+//
+// Sample weights are taken as inverse noise variances (up to a common factor): the sums of squares
+// are weighted, the total one around the weighted mean, the likelihood gains 1/2 sum(ln w) and n
+// stays the number of samples. Samples weighing 0 take no part, in n as in the likelihood.
+#[derive(Clone, Debug)]
+pub struct RegressionSummary {
+    parameters: Vector<f64>,
+    covariance: Matrix<f64>,
+    standard_errors: Vector<f64>,
+    sample_count: usize,
+    residual_sum_of_squares: f64,
+    total_sum_of_squares: f64,
+    log_weight_sum: f64,
+    intercept: bool
+}
+
+impl RegressionSummary {
+    // Summary of the function, with an intercept, fitted on the dataset.
+    pub fn new<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<RegressionSummary>
+    where F: ParametricFunction + ?Sized {
+        RegressionSummary::with_intercept(function, dataset, true)
+    }
+
+    // Summary of the function, going through the origin, fitted on the dataset.
+    pub fn without_intercept<F>(function: &F, dataset: &SupervisedDataset<f64>) -> Result<RegressionSummary>
+    where F: ParametricFunction + ?Sized {
+        RegressionSummary::with_intercept(function, dataset, false)
+    }
+
+    fn with_intercept<F>(function: &F, dataset: &SupervisedDataset<f64>, intercept: bool) -> Result<RegressionSummary>
+    where F: ParametricFunction + ?Sized {
+        let covariance = least_squares_parameter_covariance(function, dataset)?;
+        let mean = if intercept {
+            dataset.weighted_iter().map(|(_, y, weight)| weight * y).sum::<f64>() / dataset.total_weight()
+        } else {
+            0.0
+        };
+        let residual_sum_of_squares = dataset.weighted_iter()
+                                             .map(|(x, y, weight)| function.f(&x).map(|prediction| weight * (y - prediction).powi(2)))
+                                             .sum::<Result<f64>>()?;
+
+        Ok(RegressionSummary {
+            parameters: function.parameters().clone(),
+            standard_errors: covariance.diag().map(|variance| variance.sqrt()).collect(),
+            covariance,
+            sample_count: dataset.positive_weight_count(),
+            residual_sum_of_squares,
+            total_sum_of_squares: dataset.weighted_iter().map(|(_, y, weight)| weight * (y - mean).powi(2)).sum(),
+            log_weight_sum: dataset.weights().map_or(0.0, |weights| {
+                weights.iter().filter(|&&weight| weight > 0.0).map(|weight| weight.ln()).sum()
+            }),
+            intercept
+        })
+    }
+
+    pub fn parameters(&self) -> &Vector<f64> {
+        &self.parameters
+    }
+
+    // Covariance matrix of the parameters.
+    pub fn covariance(&self) -> &Matrix<f64> {
+        &self.covariance
+    }
+
+    pub fn standard_errors(&self) -> &Vector<f64> {
+        &self.standard_errors
+    }
+
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    pub fn has_intercept(&self) -> bool {
+        self.intercept
+    }
+
+    // Residual degrees of freedom, n - p.
+    pub fn degrees_of_freedom(&self) -> usize {
+        self.sample_count - self.parameters.size()
+    }
+
+    pub fn residual_sum_of_squares(&self) -> f64 {
+        self.residual_sum_of_squares
+    }
+
+    pub fn residual_standard_deviation(&self) -> f64 {
+        (self.residual_sum_of_squares / self.degrees_of_freedom() as f64).sqrt()
+    }
+
+    pub fn t_statistics(&self) -> Vector<f64> {
+        self.parameters.elediv(&self.standard_errors)
+    }
+
+    // Two-tailed p-values of the t-tests of each parameter being 0.
+    pub fn p_values(&self) -> Vector<f64> {
+        let degrees_of_freedom = self.degrees_of_freedom() as f64;
+
+        self.t_statistics().iter().map(|&t| student_t_two_tailed(t, degrees_of_freedom)).collect()
+    }
+
+    // Lower and upper bounds of the confidence interval of each parameter, at a confidence level
+    // between 0 and 1 (e.g. 0.95).
+    pub fn confidence_intervals(&self, confidence_level: f64) -> Vec<(f64, f64)> {
+        let t = student_t_quantile((1.0 + confidence_level) / 2.0, self.degrees_of_freedom() as f64);
+
+        self.parameters
+            .iter()
+            .zip(self.standard_errors.iter())
+            .map(|(parameter, standard_error)| (parameter - t * standard_error, parameter + t * standard_error))
+            .collect()
+    }
+
+    // Intervals around the estimate of the function at an input, given the parameter gradients at
+    // that input (its row of the design matrix).
+    pub fn prediction_interval(&self, estimate: f64, parameter_gradients: &Vector<f64>, confidence_level: f64) -> PredictionInterval {
+        let t = student_t_quantile((1.0 + confidence_level) / 2.0, self.degrees_of_freedom() as f64);
+        let mean_variance = parameter_gradients.dot(&(&self.covariance * parameter_gradients));
+        let observation_variance = mean_variance + self.residual_standard_deviation().powi(2);
+        let confidence_margin = t * mean_variance.sqrt();
+        let prediction_margin = t * observation_variance.sqrt();
+
+        PredictionInterval::new(estimate,
+                                confidence_level,
+                                (estimate - confidence_margin, estimate + confidence_margin),
+                                (estimate - prediction_margin, estimate + prediction_margin))
+    }
+
+    pub fn r_squared(&self) -> f64 {
+        1.0 - self.residual_sum_of_squares / self.total_sum_of_squares
+    }
+
+    pub fn adjusted_r_squared(&self) -> f64 {
+        let total_degrees_of_freedom = (self.sample_count - self.intercept as usize) as f64;
+
+        1.0 - (1.0 - self.r_squared()) * total_degrees_of_freedom / self.degrees_of_freedom() as f64
+    }
+
+    // Number of parameters tested by the F-test, i.e. all of them but the intercept.
+    fn regression_degrees_of_freedom(&self) -> usize {
+        self.parameters.size() - self.intercept as usize
+    }
+
+    // F-statistic of the test of every parameter but the intercept being 0.
+    pub fn f_statistic(&self) -> f64 {
+        let regression_sum_of_squares = self.total_sum_of_squares - self.residual_sum_of_squares;
+
+        (regression_sum_of_squares / self.regression_degrees_of_freedom() as f64) /
+            (self.residual_sum_of_squares / self.degrees_of_freedom() as f64)
+    }
+
+    pub fn f_p_value(&self) -> f64 {
+        fisher_snedecor_survival(self.f_statistic(), self.regression_degrees_of_freedom() as f64, self.degrees_of_freedom() as f64)
+    }
+
+    pub fn log_likelihood(&self) -> f64 {
+        let n = self.sample_count as f64;
+
+        -n / 2.0 * ((2.0 * f64::consts::PI * self.residual_sum_of_squares / n).ln() + 1.0) + self.log_weight_sum / 2.0
+    }
+
+    pub fn aic(&self) -> f64 {
+        2.0 * (self.parameters.size() + 1) as f64 - 2.0 * self.log_likelihood()
+    }
+
+    pub fn bic(&self) -> f64 {
+        (self.parameters.size() + 1) as f64 * (self.sample_count as f64).ln() - 2.0 * self.log_likelihood()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    use super::RegressionSummary;
+    use datasets::SupervisedDataset;
+    use optimization::LinearFunction;
+    use optimization::least_squares_qr_fit;
+
+    #[test]
+    fn summary_of_line() {
+        let mut function = LinearFunction::new(1);
+        // y = 1 + 2x with residuals (-1, 2, -1, 0)
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0),
+                                                           (vector!(1.0), 5.0),
+                                                           (vector!(2.0), 4.0),
+                                                           (vector!(-1.0), -1.0))).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        let summary = RegressionSummary::new(&function, &dataset).unwrap();
+
+        assert_eq!(summary.degrees_of_freedom(), 2);
+        assert_relative_eq!(summary.residual_sum_of_squares(), 6.0, epsilon = 1e-12);
+        assert_relative_eq!(summary.residual_standard_deviation(), 3.0f64.sqrt(), epsilon = 1e-12);
+        // total sum of squares 26 around the mean 2
+        assert_relative_eq!(summary.r_squared(), 1.0 - 6.0 / 26.0, epsilon = 1e-12);
+        assert_relative_eq!(summary.adjusted_r_squared(), 1.0 - (6.0 / 26.0) * 3.0 / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(summary.f_statistic(), 20.0 / 3.0, epsilon = 1e-12);
+        // the F-test of a single coefficient is its squared t-test
+        assert_relative_eq!(summary.t_statistics()[1].powi(2), summary.f_statistic(), epsilon = 1e-12);
+        assert_relative_eq!(summary.p_values()[1], summary.f_p_value(), epsilon = 1e-12);
+
+        let log_likelihood = -2.0 * ((2.0 * f64::consts::PI * 1.5).ln() + 1.0);
+
+        assert_relative_eq!(summary.log_likelihood(), log_likelihood, epsilon = 1e-12);
+        assert_relative_eq!(summary.aic(), 6.0 - 2.0 * log_likelihood, epsilon = 1e-12);
+        assert_relative_eq!(summary.bic(), 3.0 * 4.0f64.ln() - 2.0 * log_likelihood, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn prediction_interval_of_line() {
+        let mut function = LinearFunction::new(1);
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 0.0),
+                                                           (vector!(1.0), 5.0),
+                                                           (vector!(2.0), 4.0),
+                                                           (vector!(-1.0), -1.0))).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        let summary = RegressionSummary::new(&function, &dataset).unwrap();
+        let interval = summary.prediction_interval(2.0, &vector!(1.0, 0.5), 0.95);
+        // t(0.975, 2) = (2p - 1) / sqrt(2p (1 - p)), with a mean variance of 3 (1 0.5) (X^T X)^-1 (1 0.5)^T = 0.75
+        // and a residual variance of 3
+        let t = 0.95 / (2.0 * 0.975 * 0.025f64).sqrt();
+
+        assert_eq!(interval.estimate(), 2.0);
+        assert_relative_eq!(interval.confidence_interval().0, 2.0 - t * 0.75f64.sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(interval.confidence_interval().1, 2.0 + t * 0.75f64.sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(interval.prediction_interval().0, 2.0 - t * 3.75f64.sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(interval.prediction_interval().1, 2.0 + t * 3.75f64.sqrt(), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn summary_of_weighted_line() {
+        let mut function = LinearFunction::new(1);
+        // y = 1.225 + 1.2x around the weighted mean 3.625
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0),
+                                                           (vector!(1.0), 3.0),
+                                                           (vector!(2.0), 2.0),
+                                                           (vector!(3.0), 5.0))).unwrap()
+                                         .with_weights(vec!(1.0, 2.0, 1.0, 4.0)).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        let summary = RegressionSummary::new(&function, &dataset).unwrap();
+
+        assert_eq!(summary.sample_count(), 4);
+        assert_relative_eq!(summary.residual_sum_of_squares(), 3.475, epsilon = 1e-12);
+        assert_relative_eq!(summary.r_squared(), 1.0 - 3.475 / 17.875, epsilon = 1e-12);
+        assert_relative_eq!(summary.standard_errors()[1], (139.0f64 / 800.0).sqrt(), epsilon = 1e-12);
+
+        let log_likelihood = -2.0 * ((2.0 * f64::consts::PI * 3.475 / 4.0).ln() + 1.0) + 8.0f64.ln() / 2.0;
+
+        assert_relative_eq!(summary.log_likelihood(), log_likelihood, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn summary_ignores_samples_weighing_zero() {
+        let mut function = LinearFunction::new(1);
+        let samples = vec!((vector!(0.0), 1.0), (vector!(1.0), 3.0), (vector!(2.0), 2.0), (vector!(3.0), 5.0));
+        let dataset = SupervisedDataset::from_samples(samples.clone()).unwrap()
+                                         .with_weights(vec!(1.0, 2.0, 1.0, 4.0)).unwrap();
+        let mut samples_with_outlier = samples;
+
+        samples_with_outlier.push((vector!(4.0), 100.0));
+
+        let dataset_with_outlier = SupervisedDataset::from_samples(samples_with_outlier).unwrap()
+                                                      .with_weights(vec!(1.0, 2.0, 1.0, 4.0, 0.0)).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset_with_outlier).unwrap();
+
+        let summary = RegressionSummary::new(&function, &dataset).unwrap();
+        let summary_with_outlier = RegressionSummary::new(&function, &dataset_with_outlier).unwrap();
+
+        assert_eq!(summary_with_outlier.sample_count(), 4);
+        assert_eq!(summary_with_outlier.degrees_of_freedom(), 2);
+        assert_relative_eq!(summary_with_outlier.standard_errors()[1], summary.standard_errors()[1], epsilon = 1e-12);
+        assert_relative_eq!(summary_with_outlier.log_likelihood(), summary.log_likelihood(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn summary_of_line_through_origin() {
+        let mut function = LinearFunction::without_intercept(1);
+        // y = 2.1x with residuals (1, 0.9, -0.3), and a total sum of squares 1 + 9 + 36 around 0
+        let dataset = SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0),
+                                                           (vector!(1.0), 3.0),
+                                                           (vector!(3.0), 6.0))).unwrap();
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        let summary = RegressionSummary::without_intercept(&function, &dataset).unwrap();
+
+        assert!(!summary.has_intercept());
+        assert_relative_eq!(summary.parameters()[0], 2.1, epsilon = 1e-12);
+        assert_eq!(summary.degrees_of_freedom(), 2);
+        assert_relative_eq!(summary.residual_sum_of_squares(), 1.9, epsilon = 1e-12);
+        assert_relative_eq!(summary.r_squared(), 1.0 - 1.9 / 46.0, epsilon = 1e-12);
+        assert_relative_eq!(summary.adjusted_r_squared(), 1.0 - (1.9 / 46.0) * 3.0 / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(summary.f_statistic(), 44.1 / (1.9 / 2.0), epsilon = 1e-12);
+        assert_relative_eq!(summary.t_statistics()[0].powi(2), summary.f_statistic(), epsilon = 1e-10);
+        assert_relative_eq!(summary.p_values()[0], summary.f_p_value(), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,79 @@
+use rulinalg::vector::Vector;
+
+use {Error, Result};
+use Model;
+use datasets::SupervisedDataset;
+use optimization::SoftmaxFunction;
+use optimization::GradientDescent;
+use optimization::StoppingCriteria;
+use optimization::optimizers::Sgd;
+use optimization::softmax_cross_entropy_fit;
+use super::ClassWeights;
+
+// Multinomial classifier over the class indices 0 to k - 1, k being one more than the largest label
+// of the dataset it is fitted on.
+pub struct SoftmaxRegressionModel {
+    settings: GradientDescent,
+    class_weights: ClassWeights,
+    softmax_function: Option<SoftmaxFunction>
+}
+
+impl SoftmaxRegressionModel {
+    pub fn new(learning_rate: f64, max_iterations: u32) -> SoftmaxRegressionModel {
+        SoftmaxRegressionModel::with_settings(GradientDescent::new(Sgd::new(learning_rate), StoppingCriteria::new(max_iterations)))
+    }
+
+    pub fn with_settings(settings: GradientDescent) -> SoftmaxRegressionModel {
+        SoftmaxRegressionModel {
+            settings,
+            class_weights: ClassWeights::Uniform,
+            softmax_function: None
+        }
+    }
+
+    pub fn with_class_weights(mut self, class_weights: ClassWeights) -> SoftmaxRegressionModel {
+        self.class_weights = class_weights;
+        self
+    }
+
+    pub fn settings(&self) -> &GradientDescent {
+        &self.settings
+    }
+
+    pub fn class_count(&self) -> Option<usize> {
+        self.softmax_function.as_ref().map(|function| function.class_count())
+    }
+
+    // Probability of each class.
+    pub fn predict_proba(&self, data: &Vector<f64>) -> Result<Vector<f64>> {
+        match self.softmax_function {
+            None => Err(Error::NotFitted),
+            Some(ref function) => function.probabilities(data)
+        }
+    }
+}
+
+impl Model<Vector<f64>, usize> for SoftmaxRegressionModel {
+    fn fit_supervised_dataset(&mut self, dataset: &SupervisedDataset<usize>) -> Result<()> {
+        if dataset.is_empty() {
+            return Err(Error::EmptyDataset);
+        }
+
+        let class_count = dataset.labels().iter().cloned().max().map_or(0, |label| label + 1);
+        let class_weights = self.class_weights.weights(dataset.labels(), class_count)?;
+        let labels = dataset.labels().iter().map(|&label| label as f64).collect();
+        let dataset = dataset.clone().with_labels(labels)?;
+        let mut function = SoftmaxFunction::with_class_count(dataset.feature_count(), class_count);
+
+        softmax_cross_entropy_fit(&mut function, &dataset, &class_weights, &mut self.settings)?;
+        self.softmax_function = Some(function);
+
+        Ok(())
+    }
+
+    fn predict(&self, data: &Vector<f64>) -> Result<usize> {
+        let probabilities = self.predict_proba(data)?;
+
+        Ok(probabilities.argmax().0)
+    }
+}
@@ -1,11 +1,15 @@
+use {Error, Result};
+use datasets::SupervisedDataset;
+use datasets::UnsupervisedDataset;
+
 pub trait Model<I, O> {
-    fn fit_supervised_dataset(&mut self, _dataset: &Vec<(I, O)>) {
-        unimplemented!();
+    fn fit_supervised_dataset(&mut self, _dataset: &SupervisedDataset<O>) -> Result<()> {
+        Err(Error::Unsupported)
     }
 
-    fn fit_unsupervied_dataset(&mut self, _dataset: &Vec<I>) {
-        unimplemented!();
+    fn fit_unsupervised_dataset(&mut self, _dataset: &UnsupervisedDataset) -> Result<()> {
+        Err(Error::Unsupported)
     }
 
-    fn predict(&self, data: &I) -> O;
+    fn predict(&self, data: &I) -> Result<O>;
 }
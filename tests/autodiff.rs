@@ -0,0 +1,85 @@
+extern crate omoikane;
+#[macro_use]
+extern crate approx;
+
+use omoikane::optimization::ParametricFunction;
+use omoikane::optimization::levenberg_marquardt_fit;
+use omoikane::optimization::assert_gradients;
+use omoikane::optimization::autodiff::{AutodiffFunction, DifferentiableModel, Scalar};
+use omoikane::datasets::nist_strd::nonlinear_regression::*;
+
+// y = b1 * (1 - exp(-b2 * x))
+struct AutodiffMisra1a;
+
+impl DifferentiableModel for AutodiffMisra1a {
+    fn parameters_size(&self) -> usize {
+        2
+    }
+
+    fn evaluate<S: Scalar>(&self, b: &[S], x: &[S]) -> S {
+        b[0].clone() * (S::constant(1.0) - (-(b[1].clone() * x[0].clone())).exp())
+    }
+}
+
+// y = b1 / ((1 + exp(b2 - b3 * x))^(1 / b4))
+struct AutodiffRat43;
+
+impl DifferentiableModel for AutodiffRat43 {
+    fn parameters_size(&self) -> usize {
+        4
+    }
+
+    // The exponent being a parameter, the power is written exp(ln(base) / b4).
+    fn evaluate<S: Scalar>(&self, b: &[S], x: &[S]) -> S {
+        let base = (b[1].clone() - b[2].clone() * x[0].clone()).exp() + 1.0;
+
+        b[0].clone() / (base.ln() / b[3].clone()).exp()
+    }
+}
+
+#[test]
+fn autodiff_gradients_match_handwritten_gradients() {
+    let mut autodiff_function = AutodiffFunction::with_model(AutodiffRat43, 1);
+    let mut function = NonlinearFunction::<Rat43>::new();
+
+    for parameters in rat43_starting_values() {
+        autodiff_function.set_parameters(parameters.clone()).unwrap();
+        function.set_parameters(parameters).unwrap();
+
+        for (x, _) in rat43().iter() {
+            let x = x.clone();
+
+            assert_relative_eq!(autodiff_function.f(&x).unwrap(), function.f(&x).unwrap(), max_relative = 1e-12);
+            assert_relative_eq!(autodiff_function.input_gradient(&x).unwrap()[0],
+                                function.input_gradient(&x).unwrap()[0],
+                                max_relative = 1e-10);
+
+            for (autodiff_gradient, gradient) in autodiff_function.parameter_gradients(&x).unwrap().iter()
+                                                                  .zip(function.parameter_gradients(&x).unwrap().iter()) {
+                assert_relative_eq!(autodiff_gradient, gradient, max_relative = 1e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn autodiff_gradients_match_finite_differences() {
+    let mut function = AutodiffFunction::with_model(AutodiffMisra1a, 1);
+    let inputs: Vec<_> = misra1a().iter().map(|(x, _)| x.clone()).collect();
+
+    function.set_parameters(misra1a_starting_values()[1].clone()).unwrap();
+
+    assert_gradients(&mut function, &inputs, 1e-6);
+}
+
+#[test]
+fn levenberg_marquardt_fit_of_autodiff_function_on_misra1a_dataset() {
+    let mut function = AutodiffFunction::with_model(AutodiffMisra1a, 1);
+
+    function.set_parameters(misra1a_starting_values()[0].clone()).unwrap();
+    levenberg_marquardt_fit(&mut function, &misra1a(), 1000).unwrap();
+
+    for (estimated, certified) in function.parameters().iter().zip(misra1a_certified_values().parameters.iter()) {
+        assert_relative_eq!(estimated, certified, max_relative = 1e-7);
+    }
+}
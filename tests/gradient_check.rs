@@ -0,0 +1,85 @@
+extern crate omoikane;
+#[macro_use]
+extern crate rulinalg;
+
+use rulinalg::vector::Vector;
+
+use omoikane::optimization::ParametricFunction;
+use omoikane::optimization::PolynomialFunction;
+use omoikane::optimization::BasisExpansionFunction;
+use omoikane::optimization::SoftmaxFunction;
+use omoikane::optimization::assert_gradients;
+use omoikane::optimization::check_parameter_gradients;
+use omoikane::optimization::check_input_gradient;
+use omoikane::optimization::basis;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::datasets::nist_strd::CertifiedValues;
+use omoikane::datasets::nist_strd::nonlinear_regression::*;
+
+// Checks the gradients of a NIST model at every input of its dataset, from each starting value and
+// from the certified values.
+fn assert_nist_gradients<M>(dataset: SupervisedDataset<f64>, starting_values: Vec<Vector<f64>>, certified_values: CertifiedValues)
+where M: NonlinearModel {
+    let mut function = NonlinearFunction::<M>::new();
+    let inputs: Vec<Vector<f64>> = dataset.iter().map(|(x, _)| x.clone()).collect();
+
+    for parameters in starting_values.into_iter().chain(Some(Vector::new(certified_values.parameters))) {
+        function.set_parameters(parameters).unwrap();
+        assert_gradients(&mut function, &inputs, 1e-5);
+    }
+}
+
+#[test]
+fn nist_nonlinear_models_gradients() {
+    assert_nist_gradients::<Misra1a>(misra1a(), misra1a_starting_values(), misra1a_certified_values());
+    assert_nist_gradients::<Misra1b>(misra1b(), misra1b_starting_values(), misra1b_certified_values());
+    assert_nist_gradients::<Chwirut2>(chwirut2(), chwirut2_starting_values(), chwirut2_certified_values());
+    assert_nist_gradients::<DanWood>(dan_wood(), dan_wood_starting_values(), dan_wood_certified_values());
+    assert_nist_gradients::<Mgh09>(mgh09(), mgh09_starting_values(), mgh09_certified_values());
+    assert_nist_gradients::<Thurber>(thurber(), thurber_starting_values(), thurber_certified_values());
+    assert_nist_gradients::<Eckerle4>(eckerle4(), eckerle4_starting_values(), eckerle4_certified_values());
+    assert_nist_gradients::<BoxBod>(box_bod(), box_bod_starting_values(), box_bod_certified_values());
+    assert_nist_gradients::<Rat42>(rat42(), rat42_starting_values(), rat42_certified_values());
+    assert_nist_gradients::<Rat43>(rat43(), rat43_starting_values(), rat43_certified_values());
+    assert_nist_gradients::<Mgh10>(mgh10(), mgh10_starting_values(), mgh10_certified_values());
+}
+
+#[test]
+fn polynomial_function_gradients() {
+    let mut function = PolynomialFunction::with_interactions_only(3, 2);
+
+    function.set_parameters(vector!(0.5, -1.0, 2.0, 0.25, 3.0, -0.75, 1.5)).unwrap();
+
+    assert_gradients(&mut function, &[vector!(1.0, 2.0, 3.0), vector!(-0.5, 0.0, 4.0)], 1e-6);
+}
+
+#[test]
+fn basis_expansion_function_gradients() {
+    let mut function = BasisExpansionFunction::new(2).with_basis(basis::constant()).unwrap()
+                                                     .with_basis(basis::rbf(vector!(0.0, 1.0), 0.5)).unwrap()
+                                                     .with_basis(basis::fourier_sine(1, 2.0, 3.0)).unwrap()
+                                                     .with_basis_function(|x| x[0] * x[1]);
+
+    function.set_parameters(vector!(1.0, -2.0, 0.5, 0.25)).unwrap();
+
+    for input in &[vector!(0.2, 0.7), vector!(-1.0, 2.5)] {
+        let check = check_input_gradient(&function, input, 1e-6).unwrap();
+
+        assert!(check.passes(1e-6), "{:?}", check);
+    }
+
+    assert_gradients(&mut function, &[vector!(0.2, 0.7), vector!(-1.0, 2.5)], 1e-6);
+}
+
+#[test]
+fn softmax_function_parameter_gradients() {
+    let mut function = SoftmaxFunction::with_class_count(2, 3);
+
+    function.set_parameters(vector!(0.1, -0.2, 0.3, 0.4, -0.5, 0.6, 0.7, -0.8, 0.9)).unwrap();
+
+    for class in 0..3 {
+        let check = check_parameter_gradients(&mut function, &vector!(1.5, -2.0, f64::from(class)), 1e-6).unwrap();
+
+        assert!(check.passes(1e-6), "{:?}", check);
+    }
+}
@@ -1,20 +1,36 @@
 extern crate omoikane;
+#[macro_use]
 extern crate rulinalg;
 #[macro_use]
 extern crate approx;
 
 use omoikane::optimization::ParametricFunction;
 use omoikane::optimization::LinearFunction;
+use omoikane::optimization::PolynomialFunction;
+use omoikane::optimization::BasisExpansionFunction;
+use omoikane::optimization::basis;
+use omoikane::optimization::GradientDescent;
+use omoikane::optimization::StoppingCriteria;
+use omoikane::optimization::LineSearch;
+use omoikane::optimization::optimizers::Sgd;
+use omoikane::optimization::optimizers::Adam;
 use omoikane::optimization::least_squares_fit;
+use omoikane::optimization::least_squares_qr_fit;
+use omoikane::Error;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::Model;
+use omoikane::regression::LinearRegressionModel;
+use omoikane::regression::LinearRegressionSolver;
+use omoikane::regression::RegressionSummary;
 use omoikane::datasets::nist_strd::linear_regression::norris;
 
 #[test]
 fn least_squares_fit_linear_function_on_norris_dataset() {
     let mut function = LinearFunction::new(1);
 
-    least_squares_fit(&mut function, &norris(), 0.000001, 200000);
+    least_squares_fit(&mut function, &norris(), &mut GradientDescent::new(Sgd::new(0.000001), StoppingCriteria::new(200000))).unwrap();
 
-    let ref parameters = function.parameters();
+    let parameters = function.parameters();
 
     assert_eq!(2, parameters.size());
     assert_relative_eq!(function.parameters().data().as_slice()[0],
@@ -24,3 +40,289 @@ fn least_squares_fit_linear_function_on_norris_dataset() {
                         1.00211681802045,
                         epsilon = 0.00429796848199937);
 }
+
+#[test]
+fn least_squares_fit_linear_function_with_adam_on_norris_dataset() {
+    let mut function = LinearFunction::new(1);
+
+    least_squares_fit(&mut function, &norris(), &mut GradientDescent::new(Adam::new(0.1), StoppingCriteria::new(5000))).unwrap();
+
+    let parameters = function.parameters();
+
+    assert_eq!(2, parameters.size());
+    assert_relative_eq!(function.parameters().data().as_slice()[0],
+                        -0.262323073774029,
+                        epsilon = 1e-6);
+    assert_relative_eq!(function.parameters().data().as_slice()[1],
+                        1.00211681802045,
+                        epsilon = 1e-8);
+}
+
+#[test]
+fn least_squares_fit_linear_function_with_line_search_on_norris_dataset() {
+    let mut function = LinearFunction::new(1);
+    let mut settings = GradientDescent::new(Sgd::new(1.0), StoppingCriteria::new(100)).with_line_search(LineSearch::strong_wolfe());
+
+    least_squares_fit(&mut function, &norris(), &mut settings).unwrap();
+
+    let parameters = function.parameters();
+
+    assert_eq!(2, parameters.size());
+    assert_relative_eq!(function.parameters().data().as_slice()[0],
+                        -0.262323073774029,
+                        epsilon = 0.232818234301152);
+    assert_relative_eq!(function.parameters().data().as_slice()[1],
+                        1.00211681802045,
+                        epsilon = 0.00429796848199937);
+}
+
+#[test]
+fn least_squares_qr_fit_linear_function_on_norris_dataset() {
+    let mut function = LinearFunction::new(1);
+
+    least_squares_qr_fit(&mut function, &norris()).unwrap();
+
+    let parameters = function.parameters();
+
+    assert_eq!(2, parameters.size());
+    assert_relative_eq!(function.parameters().data().as_slice()[0],
+                        -0.262323073774029,
+                        epsilon = 1e-12);
+    assert_relative_eq!(function.parameters().data().as_slice()[1],
+                        1.00211681802045,
+                        epsilon = 1e-14);
+}
+
+#[test]
+fn qr_linear_regression_model_on_norris_dataset() {
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    model.fit_supervised_dataset(&norris()).unwrap();
+
+    assert_relative_eq!(model.predict(&vector!(0.0)).unwrap(), -0.262323073774029, epsilon = 1e-12);
+    assert_relative_eq!(model.predict(&vector!(1.0)).unwrap(), -0.262323073774029 + 1.00211681802045, epsilon = 1e-12);
+}
+
+#[test]
+fn linear_regression_model_predict_before_fitting() {
+    let model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    assert_eq!(model.predict(&vector!(0.0)), Err(Error::NotFitted));
+}
+
+#[test]
+fn ridge_linear_regression_model_without_penalty_on_norris_dataset() {
+    let mut model = LinearRegressionModel::ridge(0.0);
+
+    model.fit_supervised_dataset(&norris()).unwrap();
+
+    assert_relative_eq!(model.parameters().unwrap()[0], -0.262323073774029, epsilon = 1e-12);
+    assert_relative_eq!(model.parameters().unwrap()[1], 1.00211681802045, epsilon = 1e-12);
+}
+
+#[test]
+fn lasso_linear_regression_model_regularization_path_on_norris_dataset() {
+    let mut model = LinearRegressionModel::lasso(0.0);
+    let path = model.regularization_path(&norris(), &[1e6, 1e4, 1e2, 1.0, 0.0]).unwrap();
+
+    assert_eq!(path.len(), 5);
+    assert_eq!(path[0][1], 0.0);
+    assert!(path.windows(2).all(|pair| pair[0][1] < pair[1][1]));
+    assert_relative_eq!(path[4][0], -0.262323073774029, epsilon = 1e-6);
+    assert_relative_eq!(path[4][1], 1.00211681802045, epsilon = 1e-8);
+    assert_eq!(model.parameters(), Some(&path[4]));
+}
+
+#[test]
+fn elastic_net_linear_regression_model_on_correlated_features() {
+    // y = 1 + 3 x1, x2 being almost x1 and x3 being noise
+    let samples = (0..50).map(|i| {
+                             let x1 = i as f64 / 10.0;
+
+                             (vector!(x1, x1 + 0.01 * (i as f64).sin(), (i as f64).cos()), 1.0 + 3.0 * x1)
+                         })
+                         .collect();
+    let dataset = SupervisedDataset::from_samples(samples).unwrap();
+    let mut model = LinearRegressionModel::elastic_net(0.1, 0.9);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let parameters = model.parameters().unwrap();
+
+    // the weight is spread over the correlated features, and the noise is dropped
+    assert_relative_eq!(parameters[1] + parameters[2], 3.0, epsilon = 0.1);
+    assert_eq!(parameters[3], 0.0);
+}
+
+#[test]
+fn regularization_path_without_penalty() {
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    assert_eq!(model.regularization_path(&norris(), &[1.0]),
+               Err(Error::InvalidSetting("a regularization path needs a penalty".to_string())));
+}
+
+#[test]
+fn least_squares_qr_fit_trait_objects() {
+    // y = 1 + 2x - x^2
+    let samples = (0..10).map(|i| (vector!(f64::from(i) / 2.0), 1.0 + f64::from(i) - (f64::from(i) / 2.0).powi(2))).collect();
+    let dataset = SupervisedDataset::from_samples(samples).unwrap();
+    let mut functions: Vec<Box<dyn ParametricFunction>> =
+        vec!(Box::new(LinearFunction::new(1)),
+             Box::new(PolynomialFunction::with_degree(1, 2)),
+             Box::new(BasisExpansionFunction::new(1).with_basis(basis::constant()).unwrap()
+                                                    .with_basis_function(|x| x[0])
+                                                    .with_basis_function(|x| x[0] * x[0])));
+
+    for function in &mut functions {
+        least_squares_qr_fit(&mut **function, &dataset).unwrap();
+    }
+
+    let residual_sums_of_squares: Vec<f64> = functions.iter()
+                                                      .map(|function| dataset.iter().map(|(x, y)| (y - function.f(&x).unwrap()).powi(2)).sum())
+                                                      .collect();
+
+    // a line cannot fit the parabola, unlike the polynomial and the basis expansion
+    assert!(residual_sums_of_squares[0] > 1.0);
+    assert_relative_eq!(residual_sums_of_squares[1], 0.0, epsilon = 1e-20);
+    assert_relative_eq!(residual_sums_of_squares[2], 0.0, epsilon = 1e-20);
+}
+
+#[test]
+fn linear_regression_summary_errors() {
+    let dataset = norris();
+    let mut model = LinearRegressionModel::ridge(1.0);
+
+    assert_eq!(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr).summary().err(), Some(Error::NotFitted));
+    model.fit_supervised_dataset(&dataset).unwrap();
+    assert_eq!(model.summary().err(),
+               Some(Error::InvalidSetting("the summary of a penalized fit is not available".to_string())));
+
+    // the summary kept from the fit tells why it is not available
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    model.fit_supervised_dataset(&SupervisedDataset::from_samples(vec!((vector!(0.0), 1.0), (vector!(1.0), 2.0))).unwrap()).unwrap();
+    assert_eq!(model.summary().err(),
+               Some(Error::InvalidSetting("2 samples cannot estimate the covariance of 2 parameters".to_string())));
+}
+
+#[test]
+fn linear_regression_predict_interval_on_norris_dataset() {
+    let dataset = norris();
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let summary = model.summary().unwrap();
+    // at x = 0, the mean response is the intercept
+    let interval = model.predict_interval(&vector!(0.0), 0.95).unwrap();
+
+    assert_relative_eq!(interval.estimate(), summary.parameters()[0], epsilon = 1e-12);
+    assert_relative_eq!(interval.confidence_interval().0, summary.confidence_intervals(0.95)[0].0, epsilon = 1e-12);
+    assert_relative_eq!(interval.confidence_interval().1, summary.confidence_intervals(0.95)[0].1, epsilon = 1e-12);
+
+    // the bands are the narrowest at the mean input and widen away from it
+    let mean_input = dataset.iter().map(|(x, _)| x[0]).sum::<f64>() / dataset.len() as f64;
+    let center = model.predict_interval(&vector!(mean_input), 0.95).unwrap();
+    let far = model.predict_interval(&vector!(mean_input + 500.0), 0.95).unwrap();
+    let width = |bounds: (f64, f64)| bounds.1 - bounds.0;
+
+    assert_eq!(center.estimate(), model.predict(&vector!(mean_input)).unwrap());
+    assert!(width(center.confidence_interval()) < width(far.confidence_interval()));
+    assert!(width(center.prediction_interval()) < width(far.prediction_interval()));
+    assert!(width(far.confidence_interval()) < width(far.prediction_interval()));
+    assert!(width(model.predict_interval(&vector!(mean_input), 0.99).unwrap().prediction_interval()) > width(center.prediction_interval()));
+}
+
+#[test]
+fn linear_regression_predict_interval_errors() {
+    let dataset = norris();
+    let mut model = LinearRegressionModel::ridge(1.0);
+
+    assert_eq!(model.predict_interval(&vector!(0.0), 0.95).err(), Some(Error::NotFitted));
+    assert_eq!(model.predict_interval(&vector!(0.0), 1.0).err(),
+               Some(Error::InvalidSetting("the confidence level 1 is not between 0 and 1".to_string())));
+    model.fit_supervised_dataset(&dataset).unwrap();
+    assert_eq!(model.predict_interval(&vector!(0.0), 0.95).err(),
+               Some(Error::InvalidSetting("prediction intervals need an unpenalized fit on more samples than parameters".to_string())));
+}
+
+#[test]
+fn weighted_linear_regression_model_on_norris_dataset() {
+    let dataset = norris();
+    // every sample weighs its index modulo 3, i.e. appears that many times in the repeated dataset
+    let weights: Vec<f64> = (0..dataset.len()).map(|i| (i % 3) as f64).collect();
+    let indices: Vec<usize> = (0..dataset.len()).flat_map(|i| vec![i; i % 3]).collect();
+    let weighted_dataset = dataset.clone().with_weights(weights).unwrap();
+    let repeated_dataset = dataset.select(&indices);
+    let mut weighted_model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+    let mut repeated_model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    weighted_model.fit_supervised_dataset(&weighted_dataset).unwrap();
+    repeated_model.fit_supervised_dataset(&repeated_dataset).unwrap();
+
+    let weighted_summary = weighted_model.summary().unwrap();
+    let repeated_summary = repeated_model.summary().unwrap();
+
+    assert_relative_eq!(weighted_model.parameters().unwrap()[0], repeated_model.parameters().unwrap()[0], epsilon = 1e-10);
+    assert_relative_eq!(weighted_model.parameters().unwrap()[1], repeated_model.parameters().unwrap()[1], epsilon = 1e-12);
+    assert_relative_eq!(weighted_summary.residual_sum_of_squares(), repeated_summary.residual_sum_of_squares(), epsilon = 1e-9);
+    assert_relative_eq!(weighted_summary.r_squared(), repeated_summary.r_squared(), epsilon = 1e-12);
+
+    // unweighted fit through the origin of sqrt(w) y on sqrt(w) (1, x), without the samples weighing
+    // 0: same standard errors, and a log-likelihood lower by 1/2 sum(ln w)
+    let scaled_samples = weighted_dataset.weighted_iter()
+                                         .filter(|&(_, _, weight)| weight > 0.0)
+                                         .map(|(x, y, weight)| (vector!(weight.sqrt(), weight.sqrt() * x[0]), weight.sqrt() * y))
+                                         .collect();
+    let scaled_dataset = SupervisedDataset::from_samples(scaled_samples).unwrap();
+    let log_weight_sum = weighted_dataset.weighted_iter()
+                                         .filter(|&(_, _, weight)| weight > 0.0)
+                                         .map(|(_, _, weight)| weight.ln())
+                                         .sum::<f64>();
+    let mut scaled_function = LinearFunction::without_intercept(2);
+
+    least_squares_qr_fit(&mut scaled_function, &scaled_dataset).unwrap();
+
+    let scaled_summary = RegressionSummary::without_intercept(&scaled_function, &scaled_dataset).unwrap();
+
+    assert_eq!(weighted_summary.sample_count(), 24);
+    assert_eq!(weighted_summary.degrees_of_freedom(), scaled_summary.degrees_of_freedom());
+    assert_relative_eq!(weighted_summary.standard_errors()[0], scaled_summary.standard_errors()[0], epsilon = 1e-10);
+    assert_relative_eq!(weighted_summary.standard_errors()[1], scaled_summary.standard_errors()[1], epsilon = 1e-13);
+    assert_relative_eq!(weighted_summary.aic(), scaled_summary.aic() - log_weight_sum, epsilon = 1e-10);
+}
+
+#[test]
+fn linear_regression_model_without_intercept() {
+    let dataset = SupervisedDataset::from_samples(vec!((vector!(1.0), 2.0), (vector!(2.0), 4.0), (vector!(3.0), 7.0))).unwrap();
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr).with_intercept(false);
+
+    assert!(!model.has_intercept());
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    // slope sum(x y) / sum(x^2) = 31 / 14
+    assert_eq!(model.parameters().unwrap().size(), 1);
+    assert_relative_eq!(model.parameters().unwrap()[0], 31.0 / 14.0, epsilon = 1e-14);
+    assert_eq!(model.predict(&vector!(0.0)), Ok(0.0));
+
+    // the prediction at the origin is certain
+    let interval = model.predict_interval(&vector!(0.0), 0.95).unwrap();
+
+    assert_eq!(interval.confidence_interval(), (0.0, 0.0));
+    assert!(interval.prediction_interval().1 > 0.0);
+
+    // the slope is penalized, sum(x y) / (sum(x^2) + n alpha)
+    let mut ridge_model = LinearRegressionModel::ridge(1.0).with_intercept(false);
+
+    ridge_model.fit_supervised_dataset(&dataset).unwrap();
+
+    assert_relative_eq!(ridge_model.parameters().unwrap()[0], 31.0 / 17.0, epsilon = 1e-14);
+
+    let mut lasso_model = LinearRegressionModel::lasso(1.0).with_intercept(false);
+
+    // soft_threshold(sum(x y) / n, alpha / 2) / (sum(x^2) / n)
+    lasso_model.fit_supervised_dataset(&dataset).unwrap();
+
+    assert_relative_eq!(lasso_model.parameters().unwrap()[0], (31.0 / 3.0 - 0.5) / (14.0 / 3.0), epsilon = 1e-10);
+}
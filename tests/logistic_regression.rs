@@ -0,0 +1,141 @@
+extern crate omoikane;
+#[macro_use]
+extern crate rulinalg;
+
+use rulinalg::vector::Vector;
+
+use omoikane::Error;
+use omoikane::Model;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::regression::ClassWeights;
+use omoikane::regression::LogisticRegressionModel;
+use omoikane::regression::SoftmaxRegressionModel;
+
+// Points on a grid, whose class is given by the side of the line x + y = 1 they fall on, but for
+// a few points flipped on each side.
+fn build_binary_dataset() -> SupervisedDataset<bool> {
+    let samples = (0..100).map(|i| {
+                              let x = (i % 10) as f64 / 5.0 - 0.5;
+                              let y = (i / 10) as f64 / 5.0 - 0.5;
+
+                              (vector!(x, y), (x + y > 1.0) != (i % 17 == 0))
+                          })
+                          .collect();
+
+    SupervisedDataset::from_samples(samples).unwrap()
+}
+
+// Three clusters around (0, 0), (3, 0) and (0, 3).
+fn build_multiclass_dataset() -> SupervisedDataset<usize> {
+    let centers = [(0.0, 0.0), (3.0, 0.0), (0.0, 3.0)];
+    let samples = (0..90).map(|i| {
+                             let class = i % 3;
+                             let (x, y) = centers[class];
+                             let angle = i as f64;
+
+                             (vector!(x + angle.cos(), y + angle.sin()), class)
+                         })
+                         .collect();
+
+    SupervisedDataset::from_samples(samples).unwrap()
+}
+
+fn accuracy<M, O>(model: &M, dataset: &SupervisedDataset<O>) -> f64
+where M: Model<Vector<f64>, O>,
+      O: PartialEq {
+    dataset.iter().filter(|&(ref x, y)| model.predict(x).unwrap() == *y).count() as f64 / dataset.len() as f64
+}
+
+#[test]
+fn logistic_regression_model_on_noisy_classes() {
+    let dataset = build_binary_dataset();
+    let mut model = LogisticRegressionModel::new(1.0, 2000);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let parameters = model.parameters().unwrap();
+
+    assert!(accuracy(&model, &dataset) >= 0.9);
+    // boundary close to x + y = 1
+    assert!(parameters[1] > 0.0 && parameters[2] > 0.0);
+    assert!((-parameters[0] / parameters[1] - 1.0).abs() < 0.2);
+    assert!(model.predict_proba(&vector!(2.0, 2.0)).unwrap() > 0.99);
+    assert!(model.predict_proba(&vector!(-1.0, -1.0)).unwrap() < 0.01);
+}
+
+#[test]
+fn logistic_regression_model_with_balanced_class_weights() {
+    // 1 true sample out of 10
+    let samples = (0..40).map(|i| (vector!(i as f64 / 10.0), i % 10 == 9 && i > 20)).collect();
+    let dataset = SupervisedDataset::from_samples(samples).unwrap();
+    let mut model = LogisticRegressionModel::new(1.0, 500);
+    let mut balanced_model = LogisticRegressionModel::new(1.0, 500).with_class_weights(ClassWeights::Balanced);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+    balanced_model.fit_supervised_dataset(&dataset).unwrap();
+
+    // the rare true class weighs more, which raises its probability
+    assert!(balanced_model.predict_proba(&vector!(3.9)).unwrap() > model.predict_proba(&vector!(3.9)).unwrap());
+    assert!(balanced_model.predict(&vector!(3.9)).unwrap());
+}
+
+#[test]
+fn weighted_classifiers_fit_like_repeated_samples() {
+    // every sample weighs its index modulo 3, i.e. appears that many times in the repeated dataset
+    let weights = |n: usize| (0..n).map(|i| (i % 3) as f64).collect::<Vec<f64>>();
+    let indices = |n: usize| (0..n).flat_map(|i| vec![i; i % 3]).collect::<Vec<usize>>();
+    let binary_dataset = build_binary_dataset();
+    let mut weighted_model = LogisticRegressionModel::new(1.0, 200);
+    let mut repeated_model = LogisticRegressionModel::new(1.0, 200);
+
+    weighted_model.fit_supervised_dataset(&binary_dataset.clone().with_weights(weights(100)).unwrap()).unwrap();
+    repeated_model.fit_supervised_dataset(&binary_dataset.select(&indices(100))).unwrap();
+
+    for (weighted, repeated) in weighted_model.parameters().unwrap().iter().zip(repeated_model.parameters().unwrap().iter()) {
+        assert!((weighted - repeated).abs() < 1e-9);
+    }
+
+    let multiclass_dataset = build_multiclass_dataset();
+    let mut weighted_model = SoftmaxRegressionModel::new(0.5, 200);
+    let mut repeated_model = SoftmaxRegressionModel::new(0.5, 200);
+
+    weighted_model.fit_supervised_dataset(&multiclass_dataset.clone().with_weights(weights(90)).unwrap()).unwrap();
+    repeated_model.fit_supervised_dataset(&multiclass_dataset.select(&indices(90))).unwrap();
+
+    let weighted_probabilities = weighted_model.predict_proba(&vector!(1.0, 1.0)).unwrap();
+    let repeated_probabilities = repeated_model.predict_proba(&vector!(1.0, 1.0)).unwrap();
+
+    for (weighted, repeated) in weighted_probabilities.iter().zip(repeated_probabilities.iter()) {
+        assert!((weighted - repeated).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn softmax_regression_model_on_clusters() {
+    let dataset = build_multiclass_dataset();
+    let mut model = SoftmaxRegressionModel::new(0.5, 1000);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let probabilities = model.predict_proba(&vector!(3.0, 0.0)).unwrap();
+
+    assert_eq!(model.class_count(), Some(3));
+    assert!(accuracy(&model, &dataset) >= 0.95);
+    assert!((probabilities.sum() - 1.0).abs() < 1e-12);
+    assert!(probabilities[1] > 0.9);
+    assert_eq!(model.predict(&vector!(0.0, 3.0)), Ok(2));
+}
+
+#[test]
+fn softmax_regression_model_with_wrong_number_of_class_weights() {
+    let mut model = SoftmaxRegressionModel::new(0.5, 10).with_class_weights(ClassWeights::Custom(vec!(1.0, 2.0)));
+
+    assert_eq!(model.fit_supervised_dataset(&build_multiclass_dataset()),
+               Err(Error::InvalidSetting("2 class weights given for 3 classes".to_string())));
+}
+
+#[test]
+fn classifiers_predict_before_fitting() {
+    assert_eq!(LogisticRegressionModel::new(1.0, 10).predict(&vector!(0.0)), Err(Error::NotFitted));
+    assert_eq!(SoftmaxRegressionModel::new(1.0, 10).predict(&vector!(0.0)), Err(Error::NotFitted));
+}
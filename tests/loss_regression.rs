@@ -0,0 +1,123 @@
+extern crate omoikane;
+#[macro_use]
+extern crate rulinalg;
+#[macro_use]
+extern crate approx;
+
+use omoikane::datasets::SupervisedDataset;
+use omoikane::optimization::ParametricFunction;
+use omoikane::optimization::LinearFunction;
+use omoikane::optimization::GradientDescent;
+use omoikane::optimization::StoppingCriteria;
+use omoikane::optimization::Lbfgs;
+use omoikane::optimization::Loss;
+use omoikane::optimization::losses::*;
+use omoikane::optimization::optimizers::Adam;
+use omoikane::optimization::loss_fit;
+use omoikane::optimization::lbfgs_fit;
+use omoikane::optimization::mean_loss;
+use omoikane::optimization::mean_loss_gradients;
+use omoikane::optimization::least_squares_qr_fit;
+
+// y = 1 + 2x, but for every fifth point shifted up by 50.
+fn build_dataset_with_outliers() -> SupervisedDataset<f64> {
+    let samples = (0..50).map(|i| {
+                             let x = i as f64 / 10.0;
+                             let outlier = if i % 5 == 0 { 50.0 } else { 0.0 };
+
+                             (vector!(x), 1.0 + 2.0 * x + outlier)
+                         })
+                         .collect();
+
+    SupervisedDataset::from_samples(samples).unwrap()
+}
+
+fn fit<L: Loss>(dataset: &SupervisedDataset<f64>, loss: &L) -> LinearFunction {
+    let mut function = LinearFunction::new(1);
+    let mut settings = GradientDescent::new(Adam::new(0.05), StoppingCriteria::new(10000));
+
+    loss_fit(&mut function, dataset, loss, &mut settings).unwrap();
+
+    function
+}
+
+#[test]
+fn least_squares_is_pulled_by_outliers() {
+    let mut function = LinearFunction::new(1);
+
+    least_squares_qr_fit(&mut function, &build_dataset_with_outliers()).unwrap();
+
+    assert!((function.parameters()[0] - 1.0).abs() > 5.0);
+}
+
+#[test]
+fn absolute_error_fit_ignores_outliers() {
+    let function = fit(&build_dataset_with_outliers(), &AbsoluteError);
+
+    assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 0.05);
+    assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 0.05);
+}
+
+// The outliers still shift the intercept of bounded influence losses, by about 10 / 40 for the
+// 40 inliers to balance the 10 outliers, against 10 for least squares.
+#[test]
+fn huber_fit_limits_outlier_influence() {
+    let function = fit(&build_dataset_with_outliers(), &Huber::new(1.0));
+
+    assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 0.5);
+    assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 0.1);
+}
+
+#[test]
+fn log_cosh_fit_with_lbfgs_limits_outlier_influence() {
+    let dataset = build_dataset_with_outliers();
+    let mut function = LinearFunction::new(1);
+    let mut settings = Lbfgs::new(5, StoppingCriteria::new(100).with_gradient_tolerance(1e-10));
+
+    lbfgs_fit(&dataset,
+              &mut function,
+              &|function: &LinearFunction, dataset: &SupervisedDataset<f64>| mean_loss(function, dataset, &LogCosh),
+              &|function: &LinearFunction, dataset: &SupervisedDataset<f64>| mean_loss_gradients(function, dataset, &LogCosh),
+              &mut settings).unwrap();
+
+    assert_relative_eq!(function.parameters()[0], 1.0, epsilon = 0.5);
+    assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 0.1);
+}
+
+#[test]
+fn quantile_fit_leaves_quantile_below() {
+    // 10 targets 0, 1, ..., 9 for each x, on top of y = 2x
+    let samples = (0..100).map(|i| (vector!((i / 10) as f64), 2.0 * (i / 10) as f64 + (i % 10) as f64)).collect();
+    let dataset = SupervisedDataset::from_samples(samples).unwrap();
+    let function = fit(&dataset, &Quantile::new(0.75));
+    let below = dataset.iter().filter(|&(ref x, &y)| y < function.f(x).unwrap()).count();
+
+    assert_relative_eq!(function.parameters()[1], 2.0, epsilon = 0.1);
+    assert!((70..=80).contains(&below), "{} targets below the fitted line", below);
+}
+
+#[test]
+fn poisson_deviance_fit_of_log_mean() {
+    // counts of mean e^(0.5 + 0.3x), equal to their mean rounded
+    let samples = (0..40).map(|i| {
+                             let x = i as f64 / 4.0;
+
+                             (vector!(x), (0.5 + 0.3 * x).exp().round())
+                         })
+                         .collect();
+    let dataset = SupervisedDataset::from_samples(samples).unwrap();
+    let function = fit(&dataset, &PoissonDeviance);
+
+    assert_relative_eq!(function.parameters()[0], 0.5, epsilon = 0.1);
+    assert_relative_eq!(function.parameters()[1], 0.3, epsilon = 0.02);
+}
+
+#[test]
+fn classification_losses_separate_classes() {
+    let samples = (0..20).map(|i| (vector!(i as f64 - 9.5), if i >= 10 { 1.0 } else { 0.0 })).collect();
+    let dataset = SupervisedDataset::from_samples(samples).unwrap();
+
+    for function in &[fit(&dataset, &BinaryCrossEntropy), fit(&dataset, &Hinge)] {
+        assert!(dataset.iter().all(|(x, &y)| (function.f(&x).unwrap() > 0.0) == (y == 1.0)));
+    }
+}
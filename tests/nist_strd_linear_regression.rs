@@ -0,0 +1,470 @@
+extern crate omoikane;
+extern crate rulinalg;
+
+use rulinalg::vector::Vector;
+
+use omoikane::optimization::ParametricFunction;
+use omoikane::optimization::LinearFunction;
+use omoikane::optimization::PolynomialFunction;
+use omoikane::optimization::BasisExpansionFunction;
+use omoikane::optimization::StoppingCriteria;
+use omoikane::optimization::GradientDescent;
+use omoikane::optimization::LineSearch;
+use omoikane::optimization::optimizers::Sgd;
+use omoikane::optimization::Lbfgs;
+use omoikane::optimization::ConjugateGradient;
+use omoikane::optimization::ConjugateGradientMethod;
+use omoikane::optimization::least_squares_fit;
+use omoikane::optimization::least_squares_qr_fit;
+use omoikane::optimization::least_squares_lbfgs_fit;
+use omoikane::optimization::least_squares_conjugate_gradient_fit;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::preprocessing::Pipeline;
+use omoikane::preprocessing::StandardScaler;
+use omoikane::preprocessing::Transformer;
+use omoikane::Model;
+use omoikane::regression::LinearRegressionModel;
+use omoikane::regression::LinearRegressionSolver;
+use omoikane::regression::RegressionSummary;
+use omoikane::datasets::nist_strd::CertifiedValues;
+use omoikane::datasets::nist_strd::linear_regression::*;
+
+// Expands x into (x, x^2, ..., x^degree) to fit polynomials with a LinearFunction.
+fn polynomial_dataset(dataset: SupervisedDataset<f64>, degree: i32) -> SupervisedDataset<f64> {
+    let samples = dataset.iter()
+                         .map(|(x, &y)| (Vector::new((1..(degree + 1)).map(|d| x[0].powi(d)).collect::<Vec<f64>>()), y))
+                         .collect();
+
+    SupervisedDataset::from_samples(samples).unwrap()
+}
+
+// Dataset with standardized inputs, and the pipeline mapping the parameters fitted on it back to
+// the original inputs.
+fn standardize(dataset: &SupervisedDataset<f64>) -> (SupervisedDataset<f64>, Pipeline<()>) {
+    let mut scaler = StandardScaler::new();
+    let inputs = scaler.fit_transform(dataset.unsupervised_dataset()).unwrap();
+
+    (dataset.clone().with_unsupervised_dataset(inputs).unwrap(), Pipeline::new(()).with_transformer(scaler))
+}
+
+// Steepest descent with strong Wolfe line searches, whose iterations grow with the condition number
+// of the inputs.
+fn gradient_descent_settings() -> GradientDescent {
+    GradientDescent::new(Sgd::new(1.0), StoppingCriteria::new(1000)).with_line_search(LineSearch::strong_wolfe())
+}
+
+// Number of correct significant digits, as reported by NIST (LRE).
+fn log_relative_error(estimated: f64, certified: f64) -> f64 {
+    let error = if certified == 0.0 {
+        (estimated - certified).abs()
+    } else {
+        ((estimated - certified) / certified).abs()
+    };
+
+    if error == 0.0 { 15.0 } else { (-error.log10()).min(15.0) }
+}
+
+fn assert_certified_values<F>(function: &F,
+                              dataset: &SupervisedDataset<f64>,
+                              certified_values: &CertifiedValues,
+                              minimum_parameters_digits: f64,
+                              minimum_residual_digits: f64)
+where F: ParametricFunction + ?Sized {
+    let parameters = function.parameters();
+    let degrees_of_freedom = (dataset.len() - parameters.size()) as f64;
+    let residual_sum_of_squares = dataset.iter()
+                                         .map(|(x, y)| (y - function.f(&x).unwrap()).powi(2))
+                                         .sum::<f64>();
+    let residual_standard_deviation = (residual_sum_of_squares / degrees_of_freedom).sqrt();
+
+    assert_eq!(certified_values.parameters.len(), parameters.size());
+
+    for (estimated, certified) in parameters.iter().zip(certified_values.parameters.iter()) {
+        let digits = log_relative_error(*estimated, *certified);
+
+        assert!(digits >= minimum_parameters_digits,
+                "parameter {} instead of {} ({:.1} correct digits)", estimated, certified, digits);
+    }
+
+    let digits = log_relative_error(residual_standard_deviation,
+                                    certified_values.residual_standard_deviation);
+
+    assert!(digits >= minimum_residual_digits,
+            "residual standard deviation {} instead of {} ({:.1} correct digits)",
+            residual_standard_deviation, certified_values.residual_standard_deviation, digits);
+}
+
+fn assert_certified_summary(summary: &RegressionSummary, certified_values: &CertifiedValues, minimum_digits: f64) {
+    let estimated_values = summary.parameters().iter().chain(summary.standard_errors().iter())
+                                  .chain(Some(summary.residual_standard_deviation()).iter())
+                                  .chain(Some(summary.r_squared()).iter())
+                                  .cloned()
+                                  .collect::<Vec<f64>>();
+    let certified = certified_values.parameters.iter().chain(certified_values.standard_deviations.iter())
+                                    .chain(Some(certified_values.residual_standard_deviation).iter())
+                                    .chain(certified_values.r_squared.iter())
+                                    .cloned()
+                                    .collect::<Vec<f64>>();
+
+    assert_eq!(estimated_values.len(), certified.len());
+
+    for (estimated, certified) in estimated_values.iter().zip(certified.iter()) {
+        let digits = log_relative_error(*estimated, *certified);
+
+        assert!(digits >= minimum_digits, "{} instead of {} ({:.1} correct digits)", estimated, certified, digits);
+    }
+}
+
+#[test]
+fn least_squares_qr_fit_on_norris_dataset() {
+    let dataset = norris();
+    let mut function = LinearFunction::new(1);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &norris_certified_values(), 13.0, 13.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_pontius_dataset() {
+    let dataset = polynomial_dataset(pontius(), 2);
+    let mut function = LinearFunction::new(2);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &pontius_certified_values(), 12.0, 12.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_no_int1_dataset() {
+    let dataset = no_int1();
+    let mut function = LinearFunction::without_intercept(1);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &no_int1_certified_values(), 14.0, 14.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_no_int2_dataset() {
+    let dataset = no_int2();
+    let mut function = LinearFunction::without_intercept(1);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &no_int2_certified_values(), 14.0, 14.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_filip_dataset() {
+    let dataset = polynomial_dataset(filip(), 10);
+    let mut function = LinearFunction::new(10);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &filip_certified_values(), 7.0, 9.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_longley_dataset() {
+    let dataset = longley();
+    let mut function = LinearFunction::new(6);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &longley_certified_values(), 12.0, 12.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_wampler1_dataset() {
+    let dataset = polynomial_dataset(wampler1(), 5);
+    let mut function = LinearFunction::new(5);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &wampler1_certified_values(), 9.0, 9.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_wampler2_dataset() {
+    let dataset = polynomial_dataset(wampler2(), 5);
+    let mut function = LinearFunction::new(5);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &wampler2_certified_values(), 12.0, 13.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_wampler3_dataset() {
+    let dataset = polynomial_dataset(wampler3(), 5);
+    let mut function = LinearFunction::new(5);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &wampler3_certified_values(), 9.0, 13.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_wampler4_dataset() {
+    let dataset = polynomial_dataset(wampler4(), 5);
+    let mut function = LinearFunction::new(5);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &wampler4_certified_values(), 7.0, 13.0);
+}
+
+#[test]
+fn least_squares_qr_fit_on_wampler5_dataset() {
+    let dataset = polynomial_dataset(wampler5(), 5);
+    let mut function = LinearFunction::new(5);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &wampler5_certified_values(), 5.0, 13.0);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_norris_dataset() {
+    let dataset = norris();
+    let mut function = LinearFunction::new(1);
+    let report = least_squares_lbfgs_fit(&mut function, &dataset, &mut Lbfgs::new(5, StoppingCriteria::new(30))).unwrap();
+
+    assert!(report.iterations() < 30);
+    assert_certified_values(&function, &dataset, &norris_certified_values(), 10.0, 13.0);
+}
+
+#[test]
+fn least_squares_fletcher_reeves_fit_on_norris_dataset() {
+    let dataset = norris();
+    let mut function = LinearFunction::new(1);
+    let mut settings = ConjugateGradient::new(ConjugateGradientMethod::FletcherReeves, StoppingCriteria::new(30));
+    let report = least_squares_conjugate_gradient_fit(&mut function, &dataset, &mut settings).unwrap();
+
+    assert!(report.iterations() < 30);
+    assert_certified_values(&function, &dataset, &norris_certified_values(), 10.0, 13.0);
+}
+
+#[test]
+fn least_squares_polak_ribiere_fit_on_norris_dataset() {
+    let dataset = norris();
+    let mut function = LinearFunction::new(1);
+    let mut settings = ConjugateGradient::new(ConjugateGradientMethod::PolakRibiere, StoppingCriteria::new(30));
+    let report = least_squares_conjugate_gradient_fit(&mut function, &dataset, &mut settings).unwrap();
+
+    assert!(report.iterations() < 30);
+    assert_certified_values(&function, &dataset, &norris_certified_values(), 10.0, 13.0);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_longley_dataset() {
+    // on the raw inputs the line searches fail before any parameter has a correct digit
+    let dataset = longley();
+    let (standardized_dataset, pipeline) = standardize(&dataset);
+    let mut function = LinearFunction::new(6);
+    let report = least_squares_lbfgs_fit(&mut function, &standardized_dataset, &mut Lbfgs::new(10, StoppingCriteria::new(1000))).unwrap();
+
+    function.set_parameters(pipeline.original_parameters(function.parameters()).unwrap()).unwrap();
+
+    assert!(report.iterations() < 1000);
+    assert_certified_values(&function, &dataset, &longley_certified_values(), 7.0, 12.0);
+}
+
+#[test]
+fn least_squares_conjugate_gradient_fit_on_longley_dataset() {
+    let dataset = longley();
+    let (standardized_dataset, pipeline) = standardize(&dataset);
+    let mut function = LinearFunction::new(6);
+    let mut settings = ConjugateGradient::new(ConjugateGradientMethod::PolakRibiere, StoppingCriteria::new(1000));
+    let report = least_squares_conjugate_gradient_fit(&mut function, &standardized_dataset, &mut settings).unwrap();
+
+    function.set_parameters(pipeline.original_parameters(function.parameters()).unwrap()).unwrap();
+
+    assert!(report.iterations() < 1000);
+    // the standardized inputs are still nearly collinear, which conjugate gradient handles worse
+    assert_certified_values(&function, &dataset, &longley_certified_values(), 4.0, 10.0);
+}
+
+#[test]
+fn least_squares_fit_on_standardized_datasets() {
+    // the Filip, Longley and Wampler inputs stay so ill-conditioned once standardized that 1000
+    // steepest descent iterations leave their parameters without any correct digit; L-BFGS fits
+    // them below
+    let datasets = vec!((norris(), norris_certified_values(), 11.0, 13.0),
+                        (polynomial_dataset(pontius(), 2), pontius_certified_values(), 5.0, 11.0));
+
+    for (dataset, certified_values, minimum_parameters_digits, minimum_residual_digits) in datasets {
+        let (standardized_dataset, pipeline) = standardize(&dataset);
+        let mut function = LinearFunction::new(dataset.feature_count());
+
+        least_squares_fit(&mut function, &standardized_dataset, &mut gradient_descent_settings()).unwrap();
+        function.set_parameters(pipeline.original_parameters(function.parameters()).unwrap()).unwrap();
+
+        assert_certified_values(&function, &dataset, &certified_values, minimum_parameters_digits, minimum_residual_digits);
+    }
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_standardized_polynomial_datasets() {
+    // Filip is left to QR: L-BFGS stops with parameters that have no correct digit
+    let datasets = vec!((polynomial_dataset(pontius(), 2), pontius_certified_values(), 11.0, 13.0),
+                        (polynomial_dataset(wampler1(), 5), wampler1_certified_values(), 9.0, 9.0),
+                        (polynomial_dataset(wampler2(), 5), wampler2_certified_values(), 12.0, 14.0),
+                        (polynomial_dataset(wampler3(), 5), wampler3_certified_values(), 4.0, 14.0),
+                        (polynomial_dataset(wampler4(), 5), wampler4_certified_values(), 2.0, 14.0),
+                        (polynomial_dataset(wampler5(), 5), wampler5_certified_values(), 2.0, 14.0));
+
+    for (dataset, certified_values, minimum_parameters_digits, minimum_residual_digits) in datasets {
+        let (standardized_dataset, pipeline) = standardize(&dataset);
+        let mut function = LinearFunction::new(dataset.feature_count());
+        let report = least_squares_lbfgs_fit(&mut function, &standardized_dataset, &mut Lbfgs::new(20, StoppingCriteria::new(1000))).unwrap();
+
+        function.set_parameters(pipeline.original_parameters(function.parameters()).unwrap()).unwrap();
+
+        assert!(report.iterations() < 1000);
+        assert_certified_values(&function, &dataset, &certified_values, minimum_parameters_digits, minimum_residual_digits);
+    }
+}
+
+#[test]
+fn least_squares_fit_on_no_int_datasets() {
+    let datasets = vec!((no_int1(), no_int1_certified_values(), 11.0, 14.0),
+                        (no_int2(), no_int2_certified_values(), 13.0, 14.0));
+
+    for (dataset, certified_values, minimum_parameters_digits, minimum_residual_digits) in datasets {
+        let mut function = LinearFunction::without_intercept(1);
+
+        least_squares_fit(&mut function, &dataset, &mut gradient_descent_settings()).unwrap();
+
+        assert_certified_values(&function, &dataset, &certified_values, minimum_parameters_digits, minimum_residual_digits);
+    }
+}
+
+#[test]
+fn least_squares_qr_fit_polynomial_on_pontius_dataset() {
+    let dataset = pontius();
+    let mut function = PolynomialFunction::with_degree(1, 2);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &pontius_certified_values(), 12.0, 12.0);
+}
+
+#[test]
+fn least_squares_qr_fit_polynomial_on_filip_dataset() {
+    let dataset = filip();
+    let mut function = PolynomialFunction::with_degree(1, 10);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &filip_certified_values(), 7.0, 9.0);
+}
+
+#[test]
+fn least_squares_qr_fit_polynomial_on_wampler_datasets() {
+    let datasets = vec!((wampler1(), wampler1_certified_values(), 9.0, 9.0),
+                        (wampler2(), wampler2_certified_values(), 12.0, 13.0),
+                        (wampler3(), wampler3_certified_values(), 9.0, 13.0),
+                        (wampler4(), wampler4_certified_values(), 7.0, 13.0),
+                        (wampler5(), wampler5_certified_values(), 5.0, 13.0));
+
+    for (dataset, certified_values, minimum_parameters_digits, minimum_residual_digits) in datasets {
+        let mut function = PolynomialFunction::with_degree(1, 5);
+
+        least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+        assert_certified_values(&function, &dataset, &certified_values, minimum_parameters_digits, minimum_residual_digits);
+    }
+}
+
+#[test]
+fn least_squares_qr_fit_basis_expansion_on_wampler1_dataset() {
+    let dataset = wampler1();
+    let mut function = (0..6).fold(BasisExpansionFunction::new(1), |function, degree| {
+        function.with_basis_function(move |x: &Vector<f64>| x[0].powi(degree))
+    });
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_values(&function, &dataset, &wampler1_certified_values(), 9.0, 9.0);
+}
+
+#[test]
+fn linear_regression_summary_on_norris_dataset() {
+    let dataset = norris();
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let summary = model.summary().unwrap();
+
+    assert_certified_summary(summary, &norris_certified_values(), 12.0);
+    assert_eq!(summary.degrees_of_freedom(), 34);
+    assert!(log_relative_error(summary.residual_sum_of_squares(), 26.6173985294224) >= 12.0);
+    assert!(log_relative_error(summary.f_statistic(), 5436385.54079785) >= 12.0);
+    assert!(summary.f_p_value() < 1e-15);
+    assert!(summary.p_values()[0] > 0.1);
+    assert!(summary.p_values()[1] < 1e-15);
+
+    // t(0.975, 34) = 2.032244509317718
+    let certified_values = norris_certified_values();
+
+    for (i, &(lower, upper)) in summary.confidence_intervals(0.95).iter().enumerate() {
+        let margin = 2.032244509317718 * certified_values.standard_deviations[i];
+
+        assert!(log_relative_error(lower, certified_values.parameters[i] - margin) >= 10.0);
+        assert!(log_relative_error(upper, certified_values.parameters[i] + margin) >= 10.0);
+    }
+}
+
+#[test]
+fn regression_summary_on_pontius_dataset() {
+    let dataset = pontius();
+    let mut function = PolynomialFunction::with_degree(1, 2);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_summary(&RegressionSummary::new(&function, &dataset).unwrap(), &pontius_certified_values(), 10.0);
+}
+
+#[test]
+fn regression_summary_on_longley_dataset() {
+    let dataset = longley();
+    let mut function = LinearFunction::new(6);
+
+    least_squares_qr_fit(&mut function, &dataset).unwrap();
+
+    assert_certified_summary(&RegressionSummary::new(&function, &dataset).unwrap(), &longley_certified_values(), 10.0);
+}
+
+#[test]
+fn linear_regression_summary_without_intercept_on_no_int1_dataset() {
+    let dataset = no_int1();
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr).with_intercept(false);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let summary = model.summary().unwrap();
+
+    // the certified R^2 is uncentered
+    assert_certified_summary(summary, &no_int1_certified_values(), 13.0);
+    assert_eq!(summary.degrees_of_freedom(), 10);
+    assert!(log_relative_error(summary.residual_sum_of_squares(), 127.272727272727) >= 13.0);
+    assert!(log_relative_error(summary.f_statistic(), 15750.25) >= 13.0);
+}
+
+#[test]
+fn linear_regression_summary_without_intercept_on_no_int2_dataset() {
+    let dataset = no_int2();
+    let mut model = LinearRegressionModel::with_solver(LinearRegressionSolver::Qr).with_intercept(false);
+
+    model.fit_supervised_dataset(&dataset).unwrap();
+
+    let summary = model.summary().unwrap();
+
+    assert_certified_summary(summary, &no_int2_certified_values(), 13.0);
+    assert_eq!(summary.degrees_of_freedom(), 2);
+    assert!(log_relative_error(summary.residual_sum_of_squares(), 0.272727272727273) >= 13.0);
+    assert!(log_relative_error(summary.f_statistic(), 298.666666666667) >= 13.0);
+}
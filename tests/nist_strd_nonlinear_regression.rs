@@ -0,0 +1,230 @@
+extern crate omoikane;
+extern crate rulinalg;
+
+use rulinalg::vector::Vector;
+
+use omoikane::optimization::ParametricFunction;
+use omoikane::optimization::StoppingCriteria;
+use omoikane::optimization::Lbfgs;
+use omoikane::optimization::ConjugateGradient;
+use omoikane::optimization::ConjugateGradientMethod;
+use omoikane::optimization::levenberg_marquardt_fit;
+use omoikane::optimization::least_squares_lbfgs_fit;
+use omoikane::optimization::least_squares_conjugate_gradient_fit;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::datasets::nist_strd::CertifiedValues;
+use omoikane::datasets::nist_strd::nonlinear_regression::*;
+
+// Number of correct significant digits, as reported by NIST (LRE).
+fn log_relative_error(estimated: f64, certified: f64) -> f64 {
+    let error = ((estimated - certified) / certified).abs();
+
+    if error == 0.0 { 15.0 } else { (-error.log10()).min(15.0) }
+}
+
+fn assert_certified_values<M>(dataset: SupervisedDataset<f64>,
+                              starting_values: Vector<f64>,
+                              certified_values: CertifiedValues,
+                              minimum_digits: f64)
+where M: NonlinearModel {
+    let mut function = NonlinearFunction::<M>::new();
+
+    function.set_parameters(starting_values).unwrap();
+    levenberg_marquardt_fit(&mut function, &dataset, 1000).unwrap();
+
+    assert_fitted_certified_values(&function, &dataset, &certified_values, minimum_digits);
+}
+
+fn assert_fitted_certified_values<M>(function: &NonlinearFunction<M>,
+                                     dataset: &SupervisedDataset<f64>,
+                                     certified_values: &CertifiedValues,
+                                     minimum_digits: f64)
+where M: NonlinearModel {
+    let parameters = function.parameters();
+    let degrees_of_freedom = (dataset.len() - parameters.size()) as f64;
+    let residual_sum_of_squares = dataset.iter()
+                                         .map(|(x, y)| (y - function.f(&x).unwrap()).powi(2))
+                                         .sum::<f64>();
+    let residual_standard_deviation = (residual_sum_of_squares / degrees_of_freedom).sqrt();
+
+    for (estimated, certified) in parameters.iter().zip(certified_values.parameters.iter()) {
+        let digits = log_relative_error(*estimated, *certified);
+
+        assert!(digits >= minimum_digits,
+                "parameter {} instead of {} ({:.1} correct digits)", estimated, certified, digits);
+    }
+
+    let digits = log_relative_error(residual_standard_deviation,
+                                    certified_values.residual_standard_deviation);
+
+    assert!(digits >= minimum_digits,
+            "residual standard deviation {} instead of {} ({:.1} correct digits)",
+            residual_standard_deviation, certified_values.residual_standard_deviation, digits);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_misra1a_dataset_from_first_starting_values() {
+    assert_certified_values::<Misra1a>(misra1a(), misra1a_starting_values()[0].clone(), misra1a_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_misra1a_dataset_from_second_starting_values() {
+    assert_certified_values::<Misra1a>(misra1a(), misra1a_starting_values()[1].clone(), misra1a_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_misra1b_dataset_from_first_starting_values() {
+    assert_certified_values::<Misra1b>(misra1b(), misra1b_starting_values()[0].clone(), misra1b_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_misra1b_dataset_from_second_starting_values() {
+    assert_certified_values::<Misra1b>(misra1b(), misra1b_starting_values()[1].clone(), misra1b_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_chwirut2_dataset_from_first_starting_values() {
+    assert_certified_values::<Chwirut2>(chwirut2(), chwirut2_starting_values()[0].clone(), chwirut2_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_chwirut2_dataset_from_second_starting_values() {
+    assert_certified_values::<Chwirut2>(chwirut2(), chwirut2_starting_values()[1].clone(), chwirut2_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_dan_wood_dataset_from_first_starting_values() {
+    assert_certified_values::<DanWood>(dan_wood(), dan_wood_starting_values()[0].clone(), dan_wood_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_dan_wood_dataset_from_second_starting_values() {
+    assert_certified_values::<DanWood>(dan_wood(), dan_wood_starting_values()[1].clone(), dan_wood_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_mgh09_dataset_from_first_starting_values() {
+    assert_certified_values::<Mgh09>(mgh09(), mgh09_starting_values()[0].clone(), mgh09_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_mgh09_dataset_from_second_starting_values() {
+    assert_certified_values::<Mgh09>(mgh09(), mgh09_starting_values()[1].clone(), mgh09_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_thurber_dataset_from_first_starting_values() {
+    assert_certified_values::<Thurber>(thurber(), thurber_starting_values()[0].clone(), thurber_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_thurber_dataset_from_second_starting_values() {
+    assert_certified_values::<Thurber>(thurber(), thurber_starting_values()[1].clone(), thurber_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_eckerle4_dataset_from_first_starting_values() {
+    assert_certified_values::<Eckerle4>(eckerle4(), eckerle4_starting_values()[0].clone(), eckerle4_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_eckerle4_dataset_from_second_starting_values() {
+    assert_certified_values::<Eckerle4>(eckerle4(), eckerle4_starting_values()[1].clone(), eckerle4_certified_values(), 7.0);
+}
+
+// Converges to the b2 -> infinity plateau where f(x) is the average of y (local minimum).
+#[test]
+#[ignore]
+fn levenberg_marquardt_fit_on_box_bod_dataset_from_first_starting_values() {
+    assert_certified_values::<BoxBod>(box_bod(), box_bod_starting_values()[0].clone(), box_bod_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_box_bod_dataset_from_second_starting_values() {
+    assert_certified_values::<BoxBod>(box_bod(), box_bod_starting_values()[1].clone(), box_bod_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_rat42_dataset_from_first_starting_values() {
+    assert_certified_values::<Rat42>(rat42(), rat42_starting_values()[0].clone(), rat42_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_rat42_dataset_from_second_starting_values() {
+    assert_certified_values::<Rat42>(rat42(), rat42_starting_values()[1].clone(), rat42_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_rat43_dataset_from_first_starting_values() {
+    assert_certified_values::<Rat43>(rat43(), rat43_starting_values()[0].clone(), rat43_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_rat43_dataset_from_second_starting_values() {
+    assert_certified_values::<Rat43>(rat43(), rat43_starting_values()[1].clone(), rat43_certified_values(), 7.0);
+}
+
+// Drifts towards b1 -> 0 where exp(b2 / (x + b3)) overflows.
+#[test]
+#[ignore]
+fn levenberg_marquardt_fit_on_mgh10_dataset_from_first_starting_values() {
+    assert_certified_values::<Mgh10>(mgh10(), mgh10_starting_values()[0].clone(), mgh10_certified_values(), 7.0);
+}
+
+#[test]
+fn levenberg_marquardt_fit_on_mgh10_dataset_from_second_starting_values() {
+    assert_certified_values::<Mgh10>(mgh10(), mgh10_starting_values()[1].clone(), mgh10_certified_values(), 7.0);
+}
+
+// L-BFGS from the second starting values, which should converge in a few dozen iterations.
+fn assert_lbfgs_certified_values<M>(dataset: SupervisedDataset<f64>,
+                                    starting_values: Vector<f64>,
+                                    certified_values: CertifiedValues,
+                                    minimum_digits: f64)
+where M: NonlinearModel {
+    let mut function = NonlinearFunction::<M>::new();
+    let mut settings = Lbfgs::new(10, StoppingCriteria::new(50).with_gradient_tolerance(1e-12));
+
+    function.set_parameters(starting_values).unwrap();
+    least_squares_lbfgs_fit(&mut function, &dataset, &mut settings).unwrap();
+
+    assert_fitted_certified_values(&function, &dataset, &certified_values, minimum_digits);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_misra1a_dataset() {
+    assert_lbfgs_certified_values::<Misra1a>(misra1a(), misra1a_starting_values()[1].clone(), misra1a_certified_values(), 7.0);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_chwirut2_dataset() {
+    assert_lbfgs_certified_values::<Chwirut2>(chwirut2(), chwirut2_starting_values()[1].clone(), chwirut2_certified_values(), 7.0);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_dan_wood_dataset() {
+    assert_lbfgs_certified_values::<DanWood>(dan_wood(), dan_wood_starting_values()[1].clone(), dan_wood_certified_values(), 7.0);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_mgh09_dataset() {
+    assert_lbfgs_certified_values::<Mgh09>(mgh09(), mgh09_starting_values()[1].clone(), mgh09_certified_values(), 7.0);
+}
+
+#[test]
+fn least_squares_lbfgs_fit_on_rat42_dataset() {
+    assert_lbfgs_certified_values::<Rat42>(rat42(), rat42_starting_values()[1].clone(), rat42_certified_values(), 7.0);
+}
+
+#[test]
+fn least_squares_conjugate_gradient_fit_on_dan_wood_dataset() {
+    let dataset = dan_wood();
+    let mut function = NonlinearFunction::<DanWood>::new();
+    let mut settings = ConjugateGradient::new(ConjugateGradientMethod::PolakRibiere,
+                                              StoppingCriteria::new(50).with_gradient_tolerance(1e-12));
+
+    function.set_parameters(dan_wood_starting_values()[1].clone()).unwrap();
+    least_squares_conjugate_gradient_fit(&mut function, &dataset, &mut settings).unwrap();
+
+    assert_fitted_certified_values(&function, &dataset, &dan_wood_certified_values(), 7.0);
+}
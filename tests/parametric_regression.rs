@@ -0,0 +1,46 @@
+extern crate omoikane;
+#[macro_use]
+extern crate rulinalg;
+#[macro_use]
+extern crate approx;
+
+use omoikane::Model;
+use omoikane::optimization::ParametricFunction;
+use omoikane::optimization::PolynomialFunction;
+use omoikane::regression::ParametricRegressionModel;
+use omoikane::regression::ParametricRegressionSolver;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::datasets::nist_strd::CertifiedValues;
+use omoikane::datasets::nist_strd::linear_regression::{pontius, pontius_certified_values};
+use omoikane::datasets::nist_strd::nonlinear_regression::*;
+
+#[test]
+fn parametric_regression_models_of_different_functions() {
+    let mut misra1a_function = NonlinearFunction::<Misra1a>::new();
+
+    misra1a_function.set_parameters(misra1a_starting_values()[0].clone()).unwrap();
+
+    let models: Vec<(ParametricRegressionModel, SupervisedDataset<f64>, CertifiedValues)> = vec!(
+        (ParametricRegressionModel::new(Box::new(PolynomialFunction::with_degree(1, 2)), ParametricRegressionSolver::Qr),
+         pontius(),
+         pontius_certified_values()),
+        (ParametricRegressionModel::new(Box::new(misra1a_function), ParametricRegressionSolver::LevenbergMarquardt(1000)),
+         misra1a(),
+         misra1a_certified_values())
+    );
+
+    for (mut model, dataset, certified_values) in models {
+        model.fit_supervised_dataset(&dataset).unwrap();
+
+        for (estimated, certified) in model.parameters().unwrap().iter().zip(certified_values.parameters.iter()) {
+            assert_relative_eq!(estimated / certified, 1.0, epsilon = 1e-7);
+        }
+
+        // refitting starts over from the same parameters
+        let parameters = model.parameters().unwrap().clone();
+
+        model.fit_supervised_dataset(&dataset).unwrap();
+        assert_eq!(model.parameters(), Some(&parameters));
+        assert_eq!(model.predict(&vector!(100.0)), model.function().f(&vector!(100.0)));
+    }
+}
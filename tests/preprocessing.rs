@@ -0,0 +1,78 @@
+extern crate omoikane;
+#[macro_use]
+extern crate rulinalg;
+#[macro_use]
+extern crate approx;
+
+use omoikane::Error;
+use omoikane::Model;
+use omoikane::datasets::SupervisedDataset;
+use omoikane::preprocessing::Pipeline;
+use omoikane::preprocessing::StandardScaler;
+use omoikane::preprocessing::MinMaxScaler;
+use omoikane::preprocessing::RobustScaler;
+use omoikane::preprocessing::MaxAbsScaler;
+use omoikane::preprocessing::OneHotEncoder;
+use omoikane::regression::LinearRegressionModel;
+use omoikane::regression::LinearRegressionSolver;
+use omoikane::datasets::nist_strd::linear_regression::{norris, norris_certified_values};
+
+#[test]
+fn gradient_descent_on_standardized_norris_dataset() {
+    // without scaling, the learning rate has to be 1e-6 and the fit takes 200000 iterations
+    let mut pipeline = Pipeline::new(LinearRegressionModel::new(0.1, 1000)).with_transformer(StandardScaler::new());
+
+    pipeline.fit_supervised_dataset(&norris()).unwrap();
+
+    let parameters = pipeline.original_parameters(pipeline.model().parameters().unwrap()).unwrap();
+    let certified_values = norris_certified_values();
+
+    assert_relative_eq!(parameters[0], certified_values.parameters[0], epsilon = 1e-9);
+    assert_relative_eq!(parameters[1], certified_values.parameters[1], epsilon = 1e-12);
+    assert_relative_eq!(pipeline.predict(&vector!(500.0)).unwrap(),
+                        certified_values.parameters[0] + 500.0 * certified_values.parameters[1],
+                        epsilon = 1e-9);
+}
+
+#[test]
+fn every_scaler_gives_the_same_least_squares_fit() {
+    let certified_values = norris_certified_values();
+    let qr_pipeline = || Pipeline::new(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr));
+    let mut pipelines = [qr_pipeline().with_transformer(StandardScaler::new()),
+                         qr_pipeline().with_transformer(MinMaxScaler::new()),
+                         qr_pipeline().with_transformer(RobustScaler::new()),
+                         qr_pipeline().with_transformer(MaxAbsScaler::new())];
+
+    for pipeline in pipelines.iter_mut() {
+        pipeline.fit_supervised_dataset(&norris()).unwrap();
+
+        let parameters = pipeline.original_parameters(pipeline.model().parameters().unwrap()).unwrap();
+
+        assert_relative_eq!(parameters[0], certified_values.parameters[0], epsilon = 1e-12);
+        assert_relative_eq!(parameters[1], certified_values.parameters[1], epsilon = 1e-14);
+    }
+}
+
+#[test]
+fn linear_regression_on_one_hot_encoded_categories() {
+    // y = 1 + 2x + (0, 3, -1) for the categories (1, 2, 5)
+    let dataset = SupervisedDataset::from_samples(vec!((vector!(1.0, 0.0), 1.0),
+                                                       (vector!(2.0, 1.0), 6.0),
+                                                       (vector!(5.0, 2.0), 4.0),
+                                                       (vector!(1.0, 3.0), 7.0),
+                                                       (vector!(5.0, 4.0), 8.0))).unwrap();
+    let mut pipeline = Pipeline::new(LinearRegressionModel::with_solver(LinearRegressionSolver::Qr))
+        .with_transformer(OneHotEncoder::new(vec!(0)).with_drop_first(true));
+
+    pipeline.fit_supervised_dataset(&dataset).unwrap();
+
+    // intercept, indicators of the categories 2 and 5, then x
+    let parameters = pipeline.model().parameters().unwrap();
+
+    assert_relative_eq!(parameters[0], 1.0, epsilon = 1e-12);
+    assert_relative_eq!(parameters[1], 3.0, epsilon = 1e-12);
+    assert_relative_eq!(parameters[2], -1.0, epsilon = 1e-12);
+    assert_relative_eq!(parameters[3], 2.0, epsilon = 1e-12);
+    assert_relative_eq!(pipeline.predict(&vector!(2.0, 10.0)).unwrap(), 24.0, epsilon = 1e-12);
+    assert_eq!(pipeline.predict(&vector!(3.0, 10.0)), Err(Error::UnknownCategory { feature: 0, value: 3.0 }));
+}